@@ -0,0 +1,290 @@
+//! `nomos-da` command line tool for debugging DA shares without going through Nim
+//!
+//! Share files hold a single full share (column, combined column proof and row
+//! commitments) in the node wire format, the same bytes `nomos_da_share_to_bytes`
+//! produces. Shares are reconstructed as they are, since shares taken from
+//! nodes carry no length header; the padding `encode` adds is only stripped when
+//! its manifest says so or `--unpad` is given.
+
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use ark_serialize::CanonicalSerialize;
+use clap::{Parser, Subcommand};
+use logos_blockchain_core::da::{blob::Share as _, DaEncoder as _};
+use logos_blockchain_kzgrs_backend::{
+    common::share::DaShare,
+    encoder::{DaEncoder, DaEncoderParams},
+    kzg_keys::VERIFICATION_KEY,
+    verifier::DaVerifier,
+};
+use nomos_da_ffi::padding::{pad, unpad};
+use nomos_da_ffi::reconstruction::reconstruct_with_missing_data;
+use nomos_da_ffi::{verify_light_share, wire_deserialize, wire_serialize, BLOB_ID_SIZE};
+use serde::{Deserialize, Serialize};
+
+/// Written next to the share files by `encode`, in the node wire format
+const MANIFEST_FILE: &str = "blob.manifest";
+const SHARE_EXTENSION: &str = "share";
+
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+/// What `reconstruct` needs to know about shares written by `encode`
+#[derive(Debug, Serialize, Deserialize)]
+struct EncodeManifest {
+    blob_id: [u8; BLOB_ID_SIZE],
+    column_count: u64,
+    /// Whether the payload was framed with `pad` before encoding
+    padded: bool,
+    /// Length of the payload before padding
+    payload_len: u64,
+}
+
+#[derive(Parser)]
+#[command(
+    name = "nomos-da",
+    version,
+    about = "Encode, verify and inspect nomos-da shares"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Encode a file of any length into one share file per column
+    Encode {
+        file: PathBuf,
+        /// Number of columns of the extended data
+        #[arg(long)]
+        columns: usize,
+        /// Directory the share files and manifest are written to
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Verify a share file with the built-in verification key
+    Verify {
+        share: PathBuf,
+        /// Rows domain size, normally the column count
+        #[arg(long)]
+        domain: usize,
+    },
+    /// Rebuild the original file from the share files in a directory
+    Reconstruct {
+        dir: PathBuf,
+        /// Column count, read from the manifest written by `encode` when omitted
+        #[arg(long)]
+        columns: Option<usize>,
+        /// Strip the length header `encode` adds, even without a manifest saying
+        /// the data was padded
+        #[arg(long)]
+        unpad: bool,
+        /// Output file, the payload is written to stdout when omitted
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Print the blob id of one or more share files
+    BlobId {
+        #[arg(required = true)]
+        shares: Vec<PathBuf>,
+    },
+    /// Print the index, dimensions, proof and commitments of a share file
+    Inspect { share: PathBuf },
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn compressed_hex<T: CanonicalSerialize>(point: &T) -> CliResult<String> {
+    let mut bytes = Vec::new();
+    point.serialize_compressed(&mut bytes)?;
+    Ok(hex(&bytes))
+}
+
+fn read_share(path: &Path) -> CliResult<DaShare> {
+    let bytes = fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    wire_deserialize(&bytes)
+        .map_err(|e| format!("{}: not a serialized share: {}", path.display(), e).into())
+}
+
+fn share_paths(dir: &Path) -> CliResult<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|e| format!("{}: {}", dir.display(), e))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == SHARE_EXTENSION) {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Reads the manifest `encode` left in `dir`, if there is one
+fn read_manifest(dir: &Path) -> CliResult<Option<EncodeManifest>> {
+    let path = dir.join(MANIFEST_FILE);
+    let bytes = match fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("{}: {}", path.display(), e).into()),
+    };
+    wire_deserialize(&bytes)
+        .map(Some)
+        .map_err(|e| format!("{}: not a manifest: {}", path.display(), e).into())
+}
+
+fn encode(file: &Path, columns: usize, out: &Path) -> CliResult<()> {
+    let data = fs::read(file).map_err(|e| format!("{}: {}", file.display(), e))?;
+    let encoder = DaEncoder::new(DaEncoderParams::default_with(columns));
+    let encoded = encoder
+        .encode(&pad(&data))
+        .map_err(|e| format!("Encoding failed: {:?}", e))?;
+
+    fs::create_dir_all(out)?;
+    let share_count = encoded.combined_column_proofs.len();
+    let mut blob_id = None;
+    for index in 0..share_count {
+        let share = encoded
+            .to_da_share(index)
+            .ok_or_else(|| format!("Share {} missing from encoded data", index))?;
+        blob_id.get_or_insert_with(|| share.blob_id());
+        let path = out.join(format!("{:05}.{}", index, SHARE_EXTENSION));
+        fs::write(&path, wire_serialize(&share)?)?;
+    }
+
+    let manifest = EncodeManifest {
+        blob_id: blob_id.ok_or("Encoding produced no shares")?,
+        column_count: columns as u64,
+        padded: true,
+        payload_len: data.len() as u64,
+    };
+    fs::write(out.join(MANIFEST_FILE), wire_serialize(&manifest)?)?;
+    println!(
+        "{} {} shares written to {}",
+        hex(&manifest.blob_id),
+        share_count,
+        out.display()
+    );
+    Ok(())
+}
+
+fn verify(path: &Path, domain: usize) -> CliResult<bool> {
+    let share = read_share(path)?;
+    let verifier = DaVerifier::new(VERIFICATION_KEY.clone());
+    let (light_share, commitments) = share.into_share_and_commitments();
+    match verify_light_share(&verifier, &light_share, &commitments, domain) {
+        Ok(()) => {
+            println!(
+                "{}: valid (share_idx: {})",
+                path.display(),
+                light_share.share_idx
+            );
+            Ok(true)
+        }
+        Err((code, message)) => {
+            println!("{}: invalid, {:?}: {}", path.display(), code, message);
+            Ok(false)
+        }
+    }
+}
+
+fn reconstruct(
+    dir: &Path,
+    columns: Option<usize>,
+    unpad_payload: bool,
+    out: Option<&Path>,
+) -> CliResult<()> {
+    let manifest = read_manifest(dir)?;
+    let column_count = match (columns, &manifest) {
+        (Some(columns), _) => columns,
+        (None, Some(manifest)) => usize::try_from(manifest.column_count)?,
+        (None, None) => {
+            return Err(format!(
+                "{}: no manifest, pass --columns to reconstruct without one",
+                dir.join(MANIFEST_FILE).display()
+            )
+            .into())
+        }
+    };
+    let unpad_payload = unpad_payload || manifest.as_ref().is_some_and(|manifest| manifest.padded);
+    let shares = share_paths(dir)?
+        .iter()
+        .map(|path| read_share(path))
+        .collect::<CliResult<Vec<_>>>()?;
+    let share_refs: Vec<&DaShare> = shares.iter().collect();
+
+    let reconstructed = reconstruct_with_missing_data(&share_refs, column_count)?;
+    let payload = if unpad_payload {
+        unpad(&reconstructed)?
+    } else {
+        &reconstructed[..]
+    };
+    match out {
+        Some(path) => fs::write(path, payload)?,
+        None => std::io::Write::write_all(&mut std::io::stdout().lock(), payload)?,
+    }
+    eprintln!(
+        "Reconstructed {} bytes from {} shares (column_count: {})",
+        payload.len(),
+        shares.len(),
+        column_count
+    );
+    Ok(())
+}
+
+fn blob_id(paths: &[PathBuf]) -> CliResult<()> {
+    for path in paths {
+        println!("{} {}", hex(&read_share(path)?.blob_id()), path.display());
+    }
+    Ok(())
+}
+
+fn inspect(path: &Path) -> CliResult<()> {
+    let share = read_share(path)?;
+    let chunks = &share.column.0;
+    let min_chunk = chunks.iter().map(|chunk| chunk.0.len()).min().unwrap_or(0);
+    let max_chunk = chunks.iter().map(|chunk| chunk.0.len()).max().unwrap_or(0);
+
+    println!("file:            {}", path.display());
+    println!("blob_id:         {}", hex(&share.blob_id()));
+    println!("share_idx:       {}", share.share_idx);
+    println!("rows:            {}", chunks.len());
+    println!("chunk_bytes:     {}..={}", min_chunk, max_chunk);
+    println!(
+        "combined_proof:  {}",
+        compressed_hex(&share.combined_column_proof)?
+    );
+    println!("row_commitments: {}", share.rows_commitments.len());
+    for (row, commitment) in share.rows_commitments.iter().enumerate() {
+        println!("  [{}] {}", row, compressed_hex(commitment)?);
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Command::Encode { file, columns, out } => encode(file, *columns, out).map(|()| true),
+        Command::Verify { share, domain } => verify(share, *domain),
+        Command::Reconstruct {
+            dir,
+            columns,
+            unpad,
+            out,
+        } => reconstruct(dir, *columns, *unpad, out.as_deref()).map(|()| true),
+        Command::BlobId { shares } => blob_id(shares).map(|()| true),
+        Command::Inspect { share } => inspect(share).map(|()| true),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("nomos-da: {}", e);
+            ExitCode::from(2)
+        }
+    }
+}
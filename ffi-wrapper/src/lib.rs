@@ -1,44 +1,419 @@
 //! FFI wrapper for nomos-da Rust library
 
-use std::ffi::CString;
+pub mod padding;
+pub mod params;
+pub mod reconstruction;
+pub mod sampling;
+pub mod simulation;
+pub mod splitting;
+pub mod store;
+pub mod streaming;
+pub mod with_error;
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
 use std::ptr;
-use std::sync::Mutex;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
-use logos_blockchain_kzgrs::KzgRsError;
+use ark_poly::EvaluationDomain;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use logos_blockchain_core::{
+    da::{blob::Share as _, DaEncoder as _},
+    wire,
+};
+use logos_blockchain_kzgrs::{
+    Commitment, GlobalParameters, KzgRsError, PolynomialEvaluationDomain, VerificationKey,
+    BYTES_PER_FIELD_ELEMENT,
+};
 use logos_blockchain_kzgrs_backend::{
-    common::share::{DaShare, DaSharesCommitments},
+    common::{
+        build_blob_id,
+        share::{DaLightShare, DaShare, DaSharesCommitments},
+    },
     encoder::{DaEncoder, DaEncoderParams, EncodedData},
-    kzg_keys::VERIFICATION_KEY,
+    kzg_keys::{GLOBAL_PARAMETERS, VERIFICATION_KEY},
     reconstruction::reconstruct_without_missing_data,
     verifier::DaVerifier,
 };
-use logos_blockchain_core::{
-    da::{blob::Share as _, DaEncoder as _},
+use padding::{pad, unpad};
+use params::ParamsError;
+use rayon::prelude::*;
+use reconstruction::{
+    assemble_encoded_data, reconstruct_and_verify, reconstruct_with_missing_data,
+    ReconstructionError,
 };
+use sampling::{sampling_confidence, select_sample_columns, SamplingError};
+use serde::{de::DeserializeOwned, Serialize};
+use simulation::{SimulationConfig, SimulationError, SimulationReport, Simulator};
+use splitting::{blob_count, split_payload, BlobManifest, BlobManifestEntry, SplitError};
+use store::{ShareStore, StoreError};
+use streaming::{EncoderSession, StreamingError};
 
 pub type CSizeT = usize;
 
+/// Size of a blob id, the hash over the row commitments of a blob
+pub const BLOB_ID_SIZE: usize = 32;
+
+/// Size of a compressed BLS12-381 G1 point, the encoding used for row commitments
+pub const COMMITMENT_SIZE: usize = 48;
+
+/// Size of a compressed combined column proof (also a BLS12-381 G1 point)
+pub const PROOF_SIZE: usize = 48;
+
+/// Capacity of `NomosDaError::message`, including the NUL terminator
+pub const ERROR_MESSAGE_SIZE: usize = 256;
+
+// Each thread only ever sees the outcome of its own calls, so no locking is needed
 thread_local! {
-    static LAST_ERROR: Mutex<Option<String>> = Mutex::new(None);
+    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+    static LAST_ERROR_CODE: Cell<NomosDaResult> = const { Cell::new(NomosDaResult::Success) };
+}
+
+/// Records the kind and message of the most recent failure on this thread
+fn set_error(code: NomosDaResult, err: String) {
+    LAST_ERROR_CODE.with(|c| c.set(code));
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(err));
 }
 
-fn set_error(err: String) {
-    LAST_ERROR.with(|e| *e.lock().unwrap() = Some(err));
+fn clear_error() {
+    LAST_ERROR_CODE.with(|c| c.set(NomosDaResult::Success));
+    LAST_ERROR.with(|e| *e.borrow_mut() = None);
 }
 
+/// Takes the recorded message and resets the code with it, so the two never
+/// disagree about whether an error is pending
 fn take_error() -> Option<String> {
-    LAST_ERROR.with(|e| e.lock().unwrap().take())
+    LAST_ERROR_CODE.with(|c| c.set(NomosDaResult::Success));
+    LAST_ERROR.with(|e| e.borrow_mut().take())
+}
+
+/// Copies `message` into `buffer` as a NUL-terminated string, cut at a character
+/// boundary if it does not fit. Returns false when the message was truncated.
+fn copy_message(message: &str, buffer: &mut [c_char]) -> bool {
+    let Some(capacity) = buffer.len().checked_sub(1) else {
+        return false;
+    };
+    let mut len = message.len().min(capacity);
+    while !message.is_char_boundary(len) {
+        len -= 1;
+    }
+    for (dst, &src) in buffer.iter_mut().zip(&message.as_bytes()[..len]) {
+        *dst = src as c_char;
+    }
+    buffer[len] = 0;
+    len == message.len()
+}
+
+/// Outcome of a call, filled in by the `_with_error` variants of the entry points
+#[repr(C)]
+pub struct NomosDaError {
+    pub code: NomosDaResult,
+    /// NUL-terminated message, truncated to fit; empty when the call succeeded
+    pub message: [c_char; ERROR_MESSAGE_SIZE],
+}
+
+/// Copies the outcome of the most recent call on this thread into `out_error`;
+/// a null `out_error` is ignored
+unsafe fn write_last_error(out_error: *mut NomosDaError) {
+    let Some(out_error) = out_error.as_mut() else {
+        return;
+    };
+    out_error.code = LAST_ERROR_CODE.with(Cell::get);
+    LAST_ERROR.with(|e| copy_message(e.borrow().as_deref().unwrap_or(""), &mut out_error.message));
+}
+
+/// Value handed back to the caller when an entry point panics
+trait PanicFallback {
+    fn panic_fallback() -> Self;
+}
+
+impl PanicFallback for NomosDaResult {
+    fn panic_fallback() -> Self {
+        NomosDaResult::ErrorPanic
+    }
+}
+
+impl<T> PanicFallback for *mut T {
+    fn panic_fallback() -> Self {
+        ptr::null_mut()
+    }
+}
+
+impl PanicFallback for bool {
+    fn panic_fallback() -> Self {
+        false
+    }
+}
+
+impl PanicFallback for CSizeT {
+    fn panic_fallback() -> Self {
+        0
+    }
+}
+
+impl PanicFallback for u16 {
+    fn panic_fallback() -> Self {
+        0
+    }
+}
+
+impl PanicFallback for u64 {
+    fn panic_fallback() -> Self {
+        0
+    }
+}
+
+impl PanicFallback for () {
+    fn panic_fallback() -> Self {}
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic payload")
+}
+
+/// Runs the body of an entry point, turning a panic into the type's fallback
+/// value and recording the panic message as the last error. Unwinding across
+/// the C ABI is undefined behavior, so every `extern "C"` function goes through
+/// here. The last error is cleared first, so after a fallible call it describes
+/// that call and nothing earlier.
+fn ffi_boundary<T: PanicFallback>(body: impl FnOnce() -> T) -> T {
+    clear_error();
+    ffi_boundary_keep_error(body)
+}
+
+/// `ffi_boundary` for entry points that cannot fail and leave the last error in
+/// place: reading the error itself and releasing handles or buffers, which
+/// callers commonly do between a failure and reporting it
+fn ffi_boundary_keep_error<T: PanicFallback>(body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        set_error(
+            NomosDaResult::ErrorPanic,
+            format!("Internal panic: {}", panic_message(payload.as_ref())),
+        );
+        T::panic_fallback()
+    })
+}
+
+/// Serializes `item` in the node wire format, using the node's own serializer
+/// so the format cannot drift from what nodes send
+pub fn wire_serialize<T: Serialize>(item: &T) -> Result<Vec<u8>, wire::Error> {
+    wire::serialize(item)
+}
+
+/// Deserializes an item encoded in the node wire format
+pub fn wire_deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, wire::Error> {
+    wire::deserialize(bytes)
+}
+
+/// Reads a NUL-terminated UTF-8 path passed across the FFI boundary
+unsafe fn path_from_c_str<'a>(path: *const c_char) -> Result<&'a Path, NomosDaResult> {
+    if path.is_null() {
+        set_error(
+            NomosDaResult::ErrorNullHandle,
+            "Path pointer is null".to_string(),
+        );
+        return Err(NomosDaResult::ErrorNullHandle);
+    }
+    match CStr::from_ptr(path).to_str() {
+        Ok(path) => Ok(Path::new(path)),
+        Err(e) => {
+            set_error(
+                NomosDaResult::ErrorInvalidInput,
+                format!("Path is not valid UTF-8: {}", e),
+            );
+            Err(NomosDaResult::ErrorInvalidInput)
+        }
+    }
+}
+
+/// Borrows the shares behind an array of share handles, rejecting null entries
+unsafe fn collect_shares<'a>(
+    shares: *const *mut ShareHandle,
+    share_count: CSizeT,
+) -> Result<Vec<&'a DaShare>, NomosDaResult> {
+    let shares_slice = std::slice::from_raw_parts(shares, share_count);
+    let mut da_shares = Vec::with_capacity(share_count);
+
+    for (i, share_handle) in shares_slice.iter().enumerate() {
+        if share_handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                format!(
+                    "Share handle at index {} is null (share_count: {})",
+                    i, share_count
+                ),
+            );
+            return Err(NomosDaResult::ErrorNullHandle);
+        }
+        da_shares.push(&(**share_handle).share);
+    }
+
+    Ok(da_shares)
+}
+
+/// Hands a reference-counted handle to the caller, holding one reference
+fn into_shared<T>(value: T) -> *mut T {
+    Arc::into_raw(Arc::new(value)) as *mut T
+}
+
+/// Adds a reference to a handle created by `into_shared`; null is passed through
+unsafe fn retain_shared<T>(handle: *mut T) -> *mut T {
+    if !handle.is_null() {
+        Arc::increment_strong_count(handle as *const T);
+    }
+    handle
+}
+
+/// Drops a reference to a handle created by `into_shared`; null is ignored
+unsafe fn release_shared<T>(handle: *mut T) {
+    if !handle.is_null() {
+        drop(Arc::from_raw(handle as *const T));
+    }
+}
+
+/// Hands a Rust-owned byte buffer to the caller; release it with `nomos_da_bytes_free`
+unsafe fn write_bytes_out(bytes: Vec<u8>, out_data: *mut *mut u8, out_len: *mut CSizeT) {
+    let len = bytes.len();
+    let boxed = bytes.into_boxed_slice();
+    *out_data = Box::into_raw(boxed) as *mut u8;
+    *out_len = len;
+}
+
+/// Verifies a light share against its row commitments, checking the shape of the
+/// share before the combined column proof so that malformed input is rejected
+/// with a specific code instead of reaching the kzgrs verifier
+pub fn verify_light_share(
+    verifier: &DaVerifier,
+    light_share: &DaLightShare,
+    commitments: &DaSharesCommitments,
+    rows_domain_size: usize,
+) -> Result<(), (NomosDaResult, String)> {
+    let share_idx = light_share.share_idx;
+    let chunks = &light_share.column.0;
+    let rows_commitments = &commitments.rows_commitments;
+
+    let domain_is_valid = PolynomialEvaluationDomain::new(rows_domain_size)
+        .is_some_and(|domain| domain.size() == rows_domain_size);
+    if !domain_is_valid {
+        return Err((
+            NomosDaResult::ErrorDomainSizeMismatch,
+            format!(
+                "Rows domain size must be a non-zero power of two, got {} (share_idx: {})",
+                rows_domain_size, share_idx
+            ),
+        ));
+    }
+
+    if share_idx as usize >= rows_domain_size {
+        return Err((
+            NomosDaResult::ErrorIndexOutOfRange,
+            format!(
+                "Share index {} is out of range for rows domain size {}",
+                share_idx, rows_domain_size
+            ),
+        ));
+    }
+
+    if chunks.is_empty() {
+        return Err((
+            NomosDaResult::ErrorColumnLengthMismatch,
+            format!("Column holds no chunks (share_idx: {})", share_idx),
+        ));
+    }
+
+    if let Some(row) = chunks
+        .iter()
+        .position(|chunk| chunk.0.len() > BYTES_PER_FIELD_ELEMENT)
+    {
+        return Err((
+            NomosDaResult::ErrorColumnLengthMismatch,
+            format!(
+                "Chunk at row {} is {} bytes, wider than a field element (share_idx: {}, field_element_size: {})",
+                row,
+                chunks[row].0.len(),
+                share_idx,
+                BYTES_PER_FIELD_ELEMENT
+            ),
+        ));
+    }
+
+    if rows_commitments.len() != chunks.len() {
+        return Err((
+            NomosDaResult::ErrorRowCommitmentCount,
+            format!(
+                "Column has {} chunks but {} row commitments were supplied (share_idx: {})",
+                chunks.len(),
+                rows_commitments.len(),
+                share_idx
+            ),
+        ));
+    }
+
+    if !verifier.verify(light_share, commitments, rows_domain_size) {
+        return Err((
+            NomosDaResult::ErrorProofInvalid,
+            format!(
+                "Combined column proof check failed (share_idx: {}, rows_domain_size: {})",
+                share_idx, rows_domain_size
+            ),
+        ));
+    }
+
+    Ok(())
 }
 
 /// Result code for FFI operations
+///
+/// The code of the most recent failure on the calling thread is also available
+/// through `nomos_da_get_last_error_code`, including for functions that signal
+/// failure with a null pointer or `false`.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NomosDaResult {
     Success = 0,
+    /// Input rejected for a reason not covered by a more specific code
     ErrorInvalidInput = -1,
     ErrorInternal = -2,
     ErrorAllocation = -3,
+    /// A panic was caught at the FFI boundary
+    ErrorPanic = -4,
+    /// A required handle or pointer argument is null
+    ErrorNullHandle = -5,
+    /// A buffer or count is empty, too small or not a multiple of the unit size
+    ErrorBadLength = -6,
+    /// A row, column or share index is outside the encoded data
+    ErrorIndexOutOfRange = -7,
+    /// A domain size or column count does not fit the data
+    ErrorDomainSizeMismatch = -8,
+    /// Shares or commitments that must belong to the same blob differ
+    ErrorCommitmentMismatch = -9,
+    /// A share failed proof verification
+    ErrorProofInvalid = -10,
+    /// Bytes could not be decoded into the requested type
+    ErrorDeserialization = -11,
+    /// Too few distinct shares to reconstruct the data
+    ErrorInsufficientShares = -12,
+    /// Reconstructed data does not carry valid canonical padding
+    ErrorInvalidPadding = -13,
+    /// The number of row commitments differs from the number of chunks in the column
+    ErrorRowCommitmentCount = -14,
+    /// The column is empty or holds a chunk wider than a field element
+    ErrorColumnLengthMismatch = -15,
+    /// A file could not be read or written
+    ErrorIo = -16,
+    /// The requested share is not held by the store
+    ErrorNotFound = -17,
+    /// Re-encoding reconstructed data gave row commitments other than the shares'
+    ErrorReencodingMismatch = -18,
 }
 
 impl From<Result<(), KzgRsError>> for NomosDaResult {
@@ -46,61 +421,343 @@ impl From<Result<(), KzgRsError>> for NomosDaResult {
         match result {
             Ok(_) => NomosDaResult::Success,
             Err(e) => {
-                set_error(format!("{:?}", e));
+                set_error(NomosDaResult::ErrorInternal, format!("{:?}", e));
                 NomosDaResult::ErrorInternal
             }
         }
     }
 }
 
+impl From<&ReconstructionError> for NomosDaResult {
+    fn from(err: &ReconstructionError) -> Self {
+        match err {
+            ReconstructionError::NoShares => NomosDaResult::ErrorBadLength,
+            ReconstructionError::InvalidColumnCount(_) => NomosDaResult::ErrorDomainSizeMismatch,
+            ReconstructionError::IndexOutOfRange { .. } => NomosDaResult::ErrorIndexOutOfRange,
+            ReconstructionError::CommitmentsMismatch { .. } => {
+                NomosDaResult::ErrorCommitmentMismatch
+            }
+            ReconstructionError::ColumnLengthMismatch { .. } => NomosDaResult::ErrorBadLength,
+            ReconstructionError::InsufficientShares { .. } => {
+                NomosDaResult::ErrorInsufficientShares
+            }
+            ReconstructionError::DuplicateIndex { .. } => NomosDaResult::ErrorInvalidInput,
+            ReconstructionError::Encoding(_) => NomosDaResult::ErrorInternal,
+            ReconstructionError::ReencodingMismatch { .. } => {
+                NomosDaResult::ErrorReencodingMismatch
+            }
+        }
+    }
+}
+
+impl From<&ParamsError> for NomosDaResult {
+    fn from(err: &ParamsError) -> Self {
+        match err {
+            ParamsError::Empty => NomosDaResult::ErrorBadLength,
+            ParamsError::Io { .. } => NomosDaResult::ErrorIo,
+            ParamsError::Deserialization(_) | ParamsError::TrailingBytes { .. } => {
+                NomosDaResult::ErrorDeserialization
+            }
+        }
+    }
+}
+
+impl From<&SamplingError> for NomosDaResult {
+    fn from(err: &SamplingError) -> Self {
+        match err {
+            SamplingError::InvalidColumnCount(_) => NomosDaResult::ErrorDomainSizeMismatch,
+            SamplingError::TooManySamples { .. } => NomosDaResult::ErrorBadLength,
+        }
+    }
+}
+
+impl From<&SimulationError> for NomosDaResult {
+    fn from(err: &SimulationError) -> Self {
+        match err {
+            SimulationError::InvalidConfig(_) => NomosDaResult::ErrorInvalidInput,
+            SimulationError::Sampling(e) => NomosDaResult::from(e),
+            SimulationError::Encoding(_) => NomosDaResult::ErrorInternal,
+        }
+    }
+}
+
+impl From<&StreamingError> for NomosDaResult {
+    fn from(err: &StreamingError) -> Self {
+        match err {
+            StreamingError::Finished => NomosDaResult::ErrorInvalidInput,
+            _ => NomosDaResult::ErrorBadLength,
+        }
+    }
+}
+
+impl From<&SplitError> for NomosDaResult {
+    fn from(err: &SplitError) -> Self {
+        match err {
+            SplitError::InvalidMaxBlobSize(_) => NomosDaResult::ErrorInvalidInput,
+            SplitError::BlobCountMismatch { .. } => NomosDaResult::ErrorBadLength,
+            SplitError::BlobLengthMismatch { .. } => NomosDaResult::ErrorInvalidInput,
+            SplitError::Padding { .. } => NomosDaResult::ErrorInvalidPadding,
+            SplitError::PayloadLengthMismatch { .. } => NomosDaResult::ErrorInvalidInput,
+            SplitError::EntryTooLarge { .. } => NomosDaResult::ErrorInvalidInput,
+            SplitError::InconsistentManifest { .. } => NomosDaResult::ErrorInvalidInput,
+        }
+    }
+}
+
+impl From<&StoreError> for NomosDaResult {
+    fn from(err: &StoreError) -> Self {
+        match err {
+            StoreError::Io { .. } => NomosDaResult::ErrorIo,
+            StoreError::Serialization(_) => NomosDaResult::ErrorInternal,
+            StoreError::Corrupt { .. } => NomosDaResult::ErrorDeserialization,
+        }
+    }
+}
+
 /// Opaque handle for an encoder
+///
+/// Reference-counted, `Send` and `Sync`: one encoder may be shared by any number
+/// of threads encoding concurrently, each holding its own reference.
 #[repr(C)]
 pub struct EncoderHandle {
     encoder: DaEncoder,
+    /// Number of columns the encoder produces
+    column_count: usize,
+    /// Parameters the encoder was built with, `None` for the built-in ones
+    global_parameters: Option<GlobalParameters>,
+    /// Dedicated pool for encoding, `None` to use the global rayon pool
+    thread_pool: Option<rayon::ThreadPool>,
+    /// Whether the Toeplitz cache of the trusted setup was precomputed
+    with_cache: bool,
+}
+
+impl EncoderHandle {
+    fn new(
+        encoder: DaEncoder,
+        column_count: usize,
+        global_parameters: Option<GlobalParameters>,
+    ) -> Self {
+        Self {
+            encoder,
+            column_count,
+            global_parameters,
+            thread_pool: None,
+            with_cache: false,
+        }
+    }
+
+    /// Encodes on the dedicated thread pool when one was configured
+    fn encode(&self, data: &[u8]) -> Result<EncodedData, KzgRsError> {
+        match &self.thread_pool {
+            Some(pool) => pool.install(|| self.encoder.encode(data)),
+            None => self.encoder.encode(data),
+        }
+    }
+}
+
+/// Opaque handle for a streaming encoding session
+///
+/// `Send` but not meant to be shared: pushes to one session must not overlap.
+/// The session holds a reference to its encoder, which therefore stays alive
+/// until the session is freed.
+#[repr(C)]
+pub struct EncoderSessionHandle {
+    encoder: Arc<EncoderHandle>,
+    session: EncoderSession,
+}
+
+/// Options for `nomos_da_encoder_new_with_options`
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct NomosDaEncoderOptions {
+    /// Number of columns of the extended data
+    pub column_count: CSizeT,
+    /// Precompute the Toeplitz cache of the trusted setup once, so that per-blob
+    /// proof computation skips it, at the cost of keeping the cache in memory
+    pub with_cache: bool,
+    /// Threads the backend fans encoding out to; 0 shares the global rayon pool
+    pub thread_count: CSizeT,
+}
+
+/// Rejects a column count no encoder can be built for, recording the error
+fn check_encoder_column_count(column_count: CSizeT) -> bool {
+    if column_count == 0 {
+        set_error(
+            NomosDaResult::ErrorInvalidInput,
+            "Column count must be greater than 0".to_string(),
+        );
+        return false;
+    }
+    true
 }
 
 /// Opaque handle for a verifier
+///
+/// Reference-counted, `Send` and `Sync`: one verifier may be shared by any number
+/// of threads verifying concurrently, each holding its own reference.
 #[repr(C)]
 pub struct VerifierHandle {
     verifier: DaVerifier,
+    /// Key the verifier was built with, `None` for the built-in one
+    verification_key: Option<VerificationKey>,
 }
 
 /// Opaque handle for encoded data
+///
+/// Owned by a single caller and freed with `nomos_da_encoded_data_free`; it is
+/// `Send` and `Sync`, so it may be read from several threads while alive.
 #[repr(C)]
 pub struct EncodedDataHandle {
     pub data: EncodedData,
+    /// Contiguous copies backing the borrowed views of parts that are not
+    /// contiguous in `data`, built on first use and kept until the handle is freed
+    column_views: Vec<OnceLock<Vec<u8>>>,
+    proof_views: OnceLock<Vec<u8>>,
+}
+
+impl EncodedDataHandle {
+    fn new(data: EncodedData) -> Self {
+        let column_count = data.combined_column_proofs.len();
+        Self {
+            data,
+            column_views: (0..column_count).map(|_| OnceLock::new()).collect(),
+            proof_views: OnceLock::new(),
+        }
+    }
+
+    /// Column `column` of the extended matrix, chunks concatenated row by row
+    fn column_view(&self, column: usize) -> Option<&[u8]> {
+        let view = self.column_views.get(column)?.get_or_init(|| {
+            self.data
+                .extended_data
+                .0
+                .iter()
+                .flat_map(|row| row.0[column].0.iter().copied())
+                .collect()
+        });
+        Some(view)
+    }
+
+    /// Every combined column proof, compressed and concatenated in column order
+    fn proof_views(&self) -> Result<&[u8], ark_serialize::SerializationError> {
+        if let Some(views) = self.proof_views.get() {
+            return Ok(views);
+        }
+        let proofs = &self.data.combined_column_proofs;
+        let mut bytes = Vec::with_capacity(proofs.len() * PROOF_SIZE);
+        for proof in proofs {
+            proof.serialize_compressed(&mut bytes)?;
+        }
+        Ok(self.proof_views.get_or_init(|| bytes))
+    }
 }
 
 /// Opaque handle for a share
+///
+/// Reference-counted, `Send` and `Sync`; the share is immutable once created.
 #[repr(C)]
 pub struct ShareHandle {
     pub share: DaShare,
 }
 
+/// Opaque handle for a light share (column and proof without the row commitments)
+#[repr(C)]
+pub struct LightShareHandle {
+    pub light_share: DaLightShare,
+}
+
 /// Opaque handle for share commitments
+///
+/// Reference-counted, `Send` and `Sync`; the commitments are immutable once created.
 #[repr(C)]
 pub struct CommitmentsHandle {
     pub commitments: DaSharesCommitments,
 }
 
+/// Opaque handle for the manifest of a payload split over several blobs
+#[repr(C)]
+pub struct BlobManifestHandle {
+    pub manifest: BlobManifest,
+}
+
+/// Opaque handle for an on-disk share store
+#[repr(C)]
+pub struct ShareStoreHandle {
+    pub store: ShareStore,
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn nomos_da_free_string(s: *mut c_char) {
-    if !s.is_null() {
-        let _ = CString::from_raw(s);
-    }
+    ffi_boundary_keep_error(|| {
+        if !s.is_null() {
+            let _ = CString::from_raw(s);
+        }
+    })
 }
 
+/// Returns the message of the most recent call on the calling thread, or null
+/// if it succeeded, and clears the error: the code reads `Success` afterwards.
+/// Free it with `nomos_da_free_string`, or use `nomos_da_get_last_error_message`
+/// to avoid the allocation.
 #[no_mangle]
 pub unsafe extern "C" fn nomos_da_get_last_error() -> *mut c_char {
-    take_error()
-        .and_then(|err| CString::new(err).ok())
-        .map(|s| s.into_raw())
-        .unwrap_or(ptr::null_mut())
+    ffi_boundary_keep_error(|| {
+        take_error()
+            .and_then(|err| CString::new(err).ok())
+            .map(|s| s.into_raw())
+            .unwrap_or(ptr::null_mut())
+    })
+}
+
+/// Returns the code of the most recent call on the calling thread, `Success`
+/// if it succeeded. Unlike `nomos_da_get_last_error`, this does not clear the
+/// recorded error.
+#[no_mangle]
+pub extern "C" fn nomos_da_get_last_error_code() -> NomosDaResult {
+    ffi_boundary_keep_error(|| LAST_ERROR_CODE.with(Cell::get))
+}
+
+/// Copies the message of the most recent call on the calling thread into
+/// `buffer` as a NUL-terminated string without allocating; the message is empty
+/// if the call succeeded. The full message length, without the terminator, is
+/// written to `out_len` when it is not null. Returns `ErrorBadLength` when the
+/// message was truncated to fit `buffer_len`, so a caller may pass a null
+/// buffer of length 0 to query the length first. The recorded error is kept.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_get_last_error_message(
+    buffer: *mut c_char,
+    buffer_len: CSizeT,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary_keep_error(|| {
+        // Failing here must not overwrite the error being read, so nothing is recorded
+        if buffer.is_null() && buffer_len > 0 {
+            return NomosDaResult::ErrorNullHandle;
+        }
+        let buffer: &mut [c_char] = if buffer.is_null() {
+            &mut []
+        } else {
+            std::slice::from_raw_parts_mut(buffer, buffer_len)
+        };
+
+        LAST_ERROR.with(|e| {
+            let last_error = e.borrow();
+            let message = last_error.as_deref().unwrap_or("");
+            if !out_len.is_null() {
+                *out_len = message.len();
+            }
+            if copy_message(message, buffer) {
+                NomosDaResult::Success
+            } else {
+                NomosDaResult::ErrorBadLength
+            }
+        })
+    })
 }
 
 #[no_mangle]
 pub extern "C" fn nomos_da_init() -> NomosDaResult {
-    NomosDaResult::Success
+    ffi_boundary(|| NomosDaResult::Success)
 }
 
 #[no_mangle]
@@ -108,307 +765,3221 @@ pub extern "C" fn nomos_da_cleanup() {}
 
 #[no_mangle]
 pub unsafe extern "C" fn nomos_da_encoder_new(column_count: CSizeT) -> *mut EncoderHandle {
-    let encoder = DaEncoder::new(DaEncoderParams::default_with(column_count));
-    Box::into_raw(Box::new(EncoderHandle { encoder }))
+    ffi_boundary(|| {
+        if !check_encoder_column_count(column_count) {
+            return ptr::null_mut();
+        }
+        let encoder = DaEncoder::new(DaEncoderParams::default_with(column_count));
+        into_shared(EncoderHandle::new(encoder, column_count, None))
+    })
 }
 
+/// Creates an encoder with the precomputed cache and thread count chosen in
+/// `options`, using the built-in global parameters
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_encoder_free(handle: *mut EncoderHandle) {
-    if !handle.is_null() {
-        let _ = Box::from_raw(handle);
-    }
+pub unsafe extern "C" fn nomos_da_encoder_new_with_options(
+    options: *const NomosDaEncoderOptions,
+) -> *mut EncoderHandle {
+    ffi_boundary(|| {
+        if options.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Encoder options pointer is null".to_string(),
+            );
+            return ptr::null_mut();
+        }
+
+        let options = *options;
+        if options.column_count == 0 {
+            set_error(
+                NomosDaResult::ErrorInvalidInput,
+                format!(
+                    "Column count must be greater than 0 (with_cache: {}, thread_count: {})",
+                    options.with_cache, options.thread_count
+                ),
+            );
+            return ptr::null_mut();
+        }
+
+        let thread_pool = if options.thread_count > 0 {
+            match rayon::ThreadPoolBuilder::new()
+                .num_threads(options.thread_count)
+                .thread_name(|i| format!("nomos-da-encoder-{}", i))
+                .build()
+            {
+                Ok(pool) => Some(pool),
+                Err(e) => {
+                    set_error(
+                        NomosDaResult::ErrorInternal,
+                        format!(
+                            "Failed to start encoder thread pool: {} (thread_count: {})",
+                            e, options.thread_count
+                        ),
+                    );
+                    return ptr::null_mut();
+                }
+            }
+        } else {
+            None
+        };
+
+        let params = DaEncoderParams::new(
+            options.column_count,
+            options.with_cache,
+            GLOBAL_PARAMETERS.clone(),
+        );
+        // Building the cache is the expensive part, so it runs on the pool too
+        let encoder = match &thread_pool {
+            Some(pool) => pool.install(|| DaEncoder::new(params)),
+            None => DaEncoder::new(params),
+        };
+
+        into_shared(EncoderHandle {
+            encoder,
+            column_count: options.column_count,
+            global_parameters: None,
+            thread_pool,
+            with_cache: options.with_cache,
+        })
+    })
 }
 
+/// Returns whether the encoder precomputed the Toeplitz cache of the trusted
+/// setup; false for a null handle
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_encoder_encode(
-    encoder: *mut EncoderHandle,
-    data: *const u8,
-    data_len: CSizeT,
-    out_handle: *mut *mut EncodedDataHandle,
-) -> NomosDaResult {
-    if encoder.is_null() || data.is_null() || out_handle.is_null() {
-        if encoder.is_null() {
-            set_error(format!("Encoder handle is null (data_len: {})", data_len));
-        } else if data.is_null() {
-            set_error(format!("Data pointer is null (data_len: {})", data_len));
-        } else {
-            set_error(format!("Output handle is null (data_len: {})", data_len));
+pub unsafe extern "C" fn nomos_da_encoder_has_cache(handle: *mut EncoderHandle) -> bool {
+    ffi_boundary(|| {
+        if handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Encoder handle is null".to_string(),
+            );
+            return false;
         }
-        return NomosDaResult::ErrorInvalidInput;
-    }
+        (*handle).with_cache
+    })
+}
 
-    let chunk_size = DaEncoderParams::MAX_BLS12_381_ENCODING_CHUNK_SIZE;
-    
-    // Validate that data length is a multiple of chunk size
-    if data_len == 0 {
-        set_error(format!(
-            "Data length must be greater than 0 (chunk_size: {})",
-            chunk_size
-        ));
-        return NomosDaResult::ErrorInvalidInput;
-    }
-    
-    if data_len % chunk_size != 0 {
-        set_error(format!(
-            "Data length must be a multiple of chunk size (data_len: {}, chunk_size: {})",
-            data_len, chunk_size
-        ));
-        return NomosDaResult::ErrorInvalidInput;
-    }
+/// Returns the number of threads the encoder fans encoding out to: the size of
+/// its dedicated pool, or of the global rayon pool when it has none
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoder_thread_count(handle: *mut EncoderHandle) -> CSizeT {
+    ffi_boundary(|| {
+        if handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Encoder handle is null".to_string(),
+            );
+            return 0;
+        }
+        match &(*handle).thread_pool {
+            Some(pool) => pool.current_num_threads(),
+            None => rayon::current_num_threads(),
+        }
+    })
+}
 
-    let data_slice = std::slice::from_raw_parts(data, data_len);
-    let result = match (*encoder).encoder.encode(data_slice) {
-        Ok(encoded) => {
-            *out_handle = Box::into_raw(Box::new(EncodedDataHandle { data: encoded }));
-            NomosDaResult::Success
+fn encoder_with_params(
+    column_count: CSizeT,
+    loaded: Result<GlobalParameters, ParamsError>,
+) -> *mut EncoderHandle {
+    if !check_encoder_column_count(column_count) {
+        return ptr::null_mut();
+    }
+    match loaded {
+        Ok(global_parameters) => {
+            let params = DaEncoderParams::new(column_count, false, global_parameters.clone());
+            into_shared(EncoderHandle::new(
+                DaEncoder::new(params),
+                column_count,
+                Some(global_parameters),
+            ))
         }
         Err(e) => {
-            set_error(format!(
-                "Encoding error: {:?} (data_len: {}, chunk_size: {})",
-                e, data_len, chunk_size
-            ));
-            NomosDaResult::ErrorInternal
+            set_error(
+                NomosDaResult::from(&e),
+                format!(
+                    "Failed to load global parameters: {} (column_count: {})",
+                    e, column_count
+                ),
+            );
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Creates an encoder from global parameters (trusted setup) in compressed
+/// ark-serialize form, as produced by `nomos_da_encoder_export_params`
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoder_new_with_params(
+    column_count: CSizeT,
+    params_data: *const u8,
+    params_len: CSizeT,
+) -> *mut EncoderHandle {
+    ffi_boundary(|| {
+        if params_data.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                format!(
+                    "Parameters data pointer is null (params_len: {})",
+                    params_len
+                ),
+            );
+            return ptr::null_mut();
         }
-    };
 
-    result
+        let bytes = std::slice::from_raw_parts(params_data, params_len);
+        encoder_with_params(column_count, params::from_bytes(bytes))
+    })
 }
 
+/// Creates an encoder from global parameters stored in a file
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_encoded_data_free(handle: *mut EncodedDataHandle) {
-    if !handle.is_null() {
-        let _ = Box::from_raw(handle);
-    }
+pub unsafe extern "C" fn nomos_da_encoder_new_with_params_file(
+    column_count: CSizeT,
+    path: *const c_char,
+) -> *mut EncoderHandle {
+    ffi_boundary(|| match path_from_c_str(path) {
+        Ok(path) => encoder_with_params(column_count, params::from_file(path)),
+        Err(_) => ptr::null_mut(),
+    })
 }
 
+/// Exports the global parameters the encoder was built with
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_encoded_data_get_data(
-    handle: *mut EncodedDataHandle,
-    out_data: *mut u8,
+pub unsafe extern "C" fn nomos_da_encoder_export_params(
+    handle: *mut EncoderHandle,
+    out_data: *mut *mut u8,
     out_len: *mut CSizeT,
 ) -> NomosDaResult {
-    if handle.is_null() || out_data.is_null() || out_len.is_null() {
-        return NomosDaResult::ErrorInvalidInput;
-    }
-
-    let data = &(*handle).data.data;
-    let len = data.len();
-
-    if *out_len < len {
-        *out_len = len;
-        return NomosDaResult::ErrorInvalidInput;
-    }
+    ffi_boundary(|| {
+        if handle.is_null() || out_data.is_null() || out_len.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Encoder handle is null".to_string(),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output data pointer is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output length pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
 
-    ptr::copy_nonoverlapping(data.as_ptr(), out_data, len);
-    *out_len = len;
-    NomosDaResult::Success
+        let global_parameters = (*handle)
+            .global_parameters
+            .as_ref()
+            .unwrap_or(&*GLOBAL_PARAMETERS);
+        match params::to_bytes(global_parameters) {
+            Ok(bytes) => {
+                write_bytes_out(bytes, out_data, out_len);
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorInternal,
+                    format!("Global parameters serialization error: {:?}", e),
+                );
+                NomosDaResult::ErrorInternal
+            }
+        }
+    })
 }
 
+/// Same as `nomos_da_encoder_release`
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_verifier_new() -> *mut VerifierHandle {
-    Box::into_raw(Box::new(VerifierHandle {
-        verifier: DaVerifier::new(VERIFICATION_KEY.clone()),
-    }))
+pub unsafe extern "C" fn nomos_da_encoder_free(handle: *mut EncoderHandle) {
+    ffi_boundary_keep_error(|| release_shared(handle))
 }
 
+/// Adds a reference to the encoder and returns `handle`, which must then be
+/// released once more
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_verifier_free(handle: *mut VerifierHandle) {
-    if !handle.is_null() {
-        let _ = Box::from_raw(handle);
-    }
+pub unsafe extern "C" fn nomos_da_encoder_retain(handle: *mut EncoderHandle) -> *mut EncoderHandle {
+    ffi_boundary_keep_error(|| retain_shared(handle))
 }
 
+/// Drops a reference to the encoder, freeing it when no references remain
 #[no_mangle]
-pub extern "C" fn nomos_da_chunk_size() -> CSizeT {
-    DaEncoderParams::MAX_BLS12_381_ENCODING_CHUNK_SIZE
+pub unsafe extern "C" fn nomos_da_encoder_release(handle: *mut EncoderHandle) {
+    ffi_boundary_keep_error(|| release_shared(handle))
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_encoded_data_get_share_count(
-    handle: *mut EncodedDataHandle,
-) -> CSizeT {
-    if handle.is_null() {
-        return 0;
-    }
-    (*handle).data.combined_column_proofs.len()
+pub unsafe extern "C" fn nomos_da_encoder_encode(
+    encoder: *mut EncoderHandle,
+    data: *const u8,
+    data_len: CSizeT,
+    out_handle: *mut *mut EncodedDataHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if encoder.is_null() || data.is_null() || out_handle.is_null() {
+            if encoder.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Encoder handle is null (data_len: {})", data_len),
+                );
+            } else if data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Data pointer is null (data_len: {})", data_len),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output handle is null (data_len: {})", data_len),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let chunk_size = DaEncoderParams::MAX_BLS12_381_ENCODING_CHUNK_SIZE;
+
+        // Validate that data length is a multiple of chunk size
+        if data_len == 0 {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                format!(
+                    "Data length must be greater than 0 (chunk_size: {})",
+                    chunk_size
+                ),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        if data_len % chunk_size != 0 {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                format!(
+                    "Data length must be a multiple of chunk size (data_len: {}, chunk_size: {})",
+                    data_len, chunk_size
+                ),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        let data_slice = std::slice::from_raw_parts(data, data_len);
+        match (*encoder).encode(data_slice) {
+            Ok(encoded) => {
+                *out_handle = Box::into_raw(Box::new(EncodedDataHandle::new(encoded)));
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorInternal,
+                    format!(
+                        "Encoding error: {:?} (data_len: {}, chunk_size: {})",
+                        e, data_len, chunk_size
+                    ),
+                );
+                NomosDaResult::ErrorInternal
+            }
+        }
+    })
 }
 
+/// Writes the 32-byte blob id of the encoded data into `out_blob_id`
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_encoded_data_get_share(
+pub unsafe extern "C" fn nomos_da_encoded_data_blob_id(
     handle: *mut EncodedDataHandle,
-    index: CSizeT,
-    out_share_handle: *mut *mut ShareHandle,
+    out_blob_id: *mut u8,
 ) -> NomosDaResult {
-    if handle.is_null() || out_share_handle.is_null() {
-        if handle.is_null() {
-            set_error(format!("EncodedData handle is null (share_index: {})", index));
+    ffi_boundary(|| {
+        if handle.is_null() || out_blob_id.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "EncodedData handle is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output blob id pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let blob_id = build_blob_id(&(*handle).data.row_commitments);
+        ptr::copy_nonoverlapping(blob_id.as_ptr(), out_blob_id, BLOB_ID_SIZE);
+        NomosDaResult::Success
+    })
+}
+
+/// Encodes a payload of any length (including zero) after applying the
+/// canonical padding from the `padding` module. Use
+/// `nomos_da_reconstruct_padded` to get the exact payload back.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoder_encode_padded(
+    encoder: *mut EncoderHandle,
+    data: *const u8,
+    data_len: CSizeT,
+    out_handle: *mut *mut EncodedDataHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if encoder.is_null() || (data.is_null() && data_len > 0) || out_handle.is_null() {
+            if encoder.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Encoder handle is null (data_len: {})", data_len),
+                );
+            } else if out_handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output handle is null (data_len: {})", data_len),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Data pointer is null (data_len: {})", data_len),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let data_slice = if data_len == 0 {
+            &[][..]
         } else {
-            set_error(format!("Output share handle is null (share_index: {})", index));
+            std::slice::from_raw_parts(data, data_len)
+        };
+        let padded = pad(data_slice);
+
+        match (*encoder).encode(&padded) {
+            Ok(encoded) => {
+                *out_handle = Box::into_raw(Box::new(EncodedDataHandle::new(encoded)));
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorInternal,
+                    format!(
+                        "Encoding error: {:?} (data_len: {}, padded_len: {})",
+                        e,
+                        data_len,
+                        padded.len()
+                    ),
+                );
+                NomosDaResult::ErrorInternal
+            }
         }
-        return NomosDaResult::ErrorInvalidInput;
-    }
+    })
+}
 
-    match (*handle).data.to_da_share(index) {
-        Some(share) => {
-            *out_share_handle = Box::into_raw(Box::new(ShareHandle { share }));
-            NomosDaResult::Success
+/// Starts a session that receives the data of one encoding in slices.
+/// `expected_len` is the total data length if known, or 0; when given it must be
+/// a multiple of the chunk size and the session rejects data beyond it.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoder_session_begin(
+    encoder: *mut EncoderHandle,
+    expected_len: CSizeT,
+    out_session: *mut *mut EncoderSessionHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if encoder.is_null() || out_session.is_null() {
+            if encoder.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Encoder handle is null (expected_len: {})", expected_len),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Output session handle is null (expected_len: {})",
+                        expected_len
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
         }
-        None => {
-            let share_count = (*handle).data.combined_column_proofs.len();
-            set_error(format!(
-                "Share index {} is out of bounds. Valid range: 0..{} (share_count: {})",
-                index, share_count, share_count
-            ));
-            NomosDaResult::ErrorInvalidInput
+
+        match EncoderSession::new((expected_len > 0).then_some(expected_len)) {
+            Ok(session) => {
+                // The session keeps its own reference to the encoder
+                let encoder = Arc::from_raw(retain_shared(encoder) as *const EncoderHandle);
+                *out_session = Box::into_raw(Box::new(EncoderSessionHandle { encoder, session }));
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(code, e.to_string());
+                code
+            }
         }
-    }
+    })
 }
 
+/// Appends `data_len` bytes to the session. Slices need not be aligned to the
+/// chunk size, since the next slice may complete a chunk. When the session was
+/// begun with an expected length, a slice that would take the total past it is
+/// rejected with `ErrorBadLength` by this call and nothing is appended; the
+/// alignment of the total is checked when the session is finished.
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_share_free(handle: *mut ShareHandle) {
-    if !handle.is_null() {
-        let _ = Box::from_raw(handle);
-    }
+pub unsafe extern "C" fn nomos_da_encoder_session_push(
+    session: *mut EncoderSessionHandle,
+    data: *const u8,
+    data_len: CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if session.is_null() || (data.is_null() && data_len > 0) {
+            if session.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Session handle is null (data_len: {})", data_len),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Data pointer is null (data_len: {})", data_len),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let data_slice = if data_len == 0 {
+            &[][..]
+        } else {
+            std::slice::from_raw_parts(data, data_len)
+        };
+        match (*session).session.push(data_slice) {
+            Ok(()) => NomosDaResult::Success,
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(code, e.to_string());
+                code
+            }
+        }
+    })
 }
 
+/// Encodes everything pushed to the session, producing the same encoded data as
+/// `nomos_da_encoder_encode` over the concatenated slices. The session accepts
+/// nothing afterwards, whatever the outcome, but must still be freed.
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_share_get_index(share_handle: *mut ShareHandle) -> u16 {
-    if share_handle.is_null() {
-        set_error("Share handle is null".to_string());
-        return 0;
-    }
-    (*share_handle).share.share_idx
+pub unsafe extern "C" fn nomos_da_encoder_session_finish(
+    session: *mut EncoderSessionHandle,
+    out_handle: *mut *mut EncodedDataHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if session.is_null() || out_handle.is_null() {
+            if session.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Session handle is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output handle is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let session = &mut *session;
+        let data = match session.session.finish() {
+            Ok(data) => data,
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(code, e.to_string());
+                return code;
+            }
+        };
+
+        match session.encoder.encode(&data) {
+            Ok(encoded) => {
+                *out_handle = Box::into_raw(Box::new(EncodedDataHandle::new(encoded)));
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorInternal,
+                    format!("Encoding error: {:?} (data_len: {})", e, data.len()),
+                );
+                NomosDaResult::ErrorInternal
+            }
+        }
+    })
 }
 
+/// Bytes pushed to the session so far, 0 if `session` is null
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_share_get_commitments(
-    share_handle: *mut ShareHandle,
-    out_commitments_handle: *mut *mut CommitmentsHandle,
+pub unsafe extern "C" fn nomos_da_encoder_session_received(
+    session: *mut EncoderSessionHandle,
+) -> CSizeT {
+    ffi_boundary(|| {
+        if session.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Session handle is null".to_string(),
+            );
+            return 0;
+        }
+        (*session).session.received()
+    })
+}
+
+/// Frees the session, abandoning its data if it was not finished, and drops
+/// its reference to the encoder
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoder_session_free(session: *mut EncoderSessionHandle) {
+    ffi_boundary_keep_error(|| {
+        if !session.is_null() {
+            let _ = Box::from_raw(session);
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoded_data_free(handle: *mut EncodedDataHandle) {
+    ffi_boundary_keep_error(|| {
+        if !handle.is_null() {
+            let _ = Box::from_raw(handle);
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoded_data_get_data(
+    handle: *mut EncodedDataHandle,
+    out_data: *mut u8,
+    out_len: *mut CSizeT,
 ) -> NomosDaResult {
-    if share_handle.is_null() || out_commitments_handle.is_null() {
-        if share_handle.is_null() {
-            set_error("Share handle is null".to_string());
-        } else {
-            set_error("Output commitments handle pointer is null".to_string());
+    ffi_boundary(|| {
+        if handle.is_null() || out_data.is_null() || out_len.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "EncodedData handle is null".to_string(),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output data pointer is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output length pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
         }
-        return NomosDaResult::ErrorInvalidInput;
-    }
 
-    let (_, commitments) = (*share_handle).share.clone().into_share_and_commitments();
-    *out_commitments_handle = Box::into_raw(Box::new(CommitmentsHandle { commitments }));
-    NomosDaResult::Success
+        let data = &(*handle).data.data;
+        let len = data.len();
+
+        if *out_len < len {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                format!(
+                    "Output buffer is too small (buffer_len: {}, data_len: {})",
+                    *out_len, len
+                ),
+            );
+            *out_len = len;
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        ptr::copy_nonoverlapping(data.as_ptr(), out_data, len);
+        *out_len = len;
+        NomosDaResult::Success
+    })
 }
 
+/// Points `out_data` at the original data held by the handle, without copying.
+/// The view stays valid until the handle is freed.
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_verifier_verify(
-    verifier: *mut VerifierHandle,
-    share_handle: *mut ShareHandle,
-    rows_domain_size: CSizeT,
-) -> bool {
-    if verifier.is_null() || share_handle.is_null() {
-        if verifier.is_null() {
-            set_error(format!(
-                "Verifier handle is null (rows_domain_size: {})",
-                rows_domain_size
-            ));
-        } else {
-            set_error(format!(
-                "Share handle is null (rows_domain_size: {})",
-                rows_domain_size
-            ));
+pub unsafe extern "C" fn nomos_da_encoded_data_data_view(
+    handle: *mut EncodedDataHandle,
+    out_data: *mut *const u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_data.is_null() || out_len.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "EncodedData handle is null".to_string(),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output data pointer is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output length pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
         }
-        return false;
-    }
 
-    if rows_domain_size == 0 {
-        set_error(format!(
-            "Rows domain size must be greater than 0, got {}",
-            rows_domain_size
-        ));
-        return false;
-    }
+        let data = &(*handle).data.data;
+        *out_data = data.as_ptr();
+        *out_len = data.len();
+        NomosDaResult::Success
+    })
+}
 
-    let share = &(*share_handle).share;
-    let (light_share, commitments) = share.clone().into_share_and_commitments();
-    
-    let is_valid = (*verifier).verifier.verify(&light_share, &commitments, rows_domain_size);
-    
-    if !is_valid {
-        set_error(format!(
-            "Share verification failed (share_idx: {}, rows_domain_size: {})",
-            light_share.share_idx, rows_domain_size
-        ));
-    }
-    
-    is_valid
+/// Points `out_data` at one column of the extended matrix, laid out like
+/// `nomos_da_encoded_data_get_column`. The column is made contiguous once on
+/// first access; the view stays valid until the handle is freed.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoded_data_column_view(
+    handle: *mut EncodedDataHandle,
+    column: CSizeT,
+    out_data: *mut *const u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_data.is_null() || out_len.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("EncodedData handle is null (column: {})", column),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output data pointer is null (column: {})", column),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output length pointer is null (column: {})", column),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let Some(view) = (*handle).column_view(column) else {
+            set_error(
+                NomosDaResult::ErrorIndexOutOfRange,
+                format!(
+                    "Column index {} is out of bounds. Valid range: 0..{}",
+                    column,
+                    (*handle).column_views.len()
+                ),
+            );
+            return NomosDaResult::ErrorIndexOutOfRange;
+        };
+        *out_data = view.as_ptr();
+        *out_len = view.len();
+        NomosDaResult::Success
+    })
 }
 
+/// Points `out_proof` at the compressed 48-byte combined proof of `column`.
+/// All proofs are compressed once on first access; the view stays valid until
+/// the handle is freed.
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_commitments_free(handle: *mut CommitmentsHandle) {
-    if !handle.is_null() {
-        let _ = Box::from_raw(handle);
-    }
+pub unsafe extern "C" fn nomos_da_encoded_data_proof_view(
+    handle: *mut EncodedDataHandle,
+    column: CSizeT,
+    out_proof: *mut *const u8,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_proof.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("EncodedData handle is null (column: {})", column),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output proof pointer is null (column: {})", column),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let column_count = (*handle).data.combined_column_proofs.len();
+        if column >= column_count {
+            set_error(
+                NomosDaResult::ErrorIndexOutOfRange,
+                format!(
+                    "Column index {} is out of bounds. Valid range: 0..{}",
+                    column, column_count
+                ),
+            );
+            return NomosDaResult::ErrorIndexOutOfRange;
+        }
+
+        match (*handle).proof_views() {
+            Ok(views) => {
+                *out_proof = views[column * PROOF_SIZE..].as_ptr();
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorInternal,
+                    format!("Proof serialization error: {:?} (column: {})", e, column),
+                );
+                NomosDaResult::ErrorInternal
+            }
+        }
+    })
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_reconstruct(
-    shares: *const *mut ShareHandle,
-    share_count: CSizeT,
+pub unsafe extern "C" fn nomos_da_encoded_data_get_row_count(
+    handle: *mut EncodedDataHandle,
+) -> CSizeT {
+    ffi_boundary(|| {
+        if handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "EncodedData handle is null".to_string(),
+            );
+            return 0;
+        }
+        (*handle).data.extended_data.0.len()
+    })
+}
+
+/// Number of columns of the Reed-Solomon extended matrix
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoded_data_get_column_count(
+    handle: *mut EncodedDataHandle,
+) -> CSizeT {
+    ffi_boundary(|| {
+        if handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "EncodedData handle is null".to_string(),
+            );
+            return 0;
+        }
+        (*handle).data.combined_column_proofs.len()
+    })
+}
+
+/// Copies one column of the extended matrix, as the concatenation of its chunks
+/// from the first row to the last. The output must be released with
+/// `nomos_da_bytes_free`.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoded_data_get_column(
+    handle: *mut EncodedDataHandle,
+    column: CSizeT,
     out_data: *mut *mut u8,
     out_len: *mut CSizeT,
 ) -> NomosDaResult {
-    if shares.is_null() || out_data.is_null() || out_len.is_null() {
-        if shares.is_null() {
-            set_error(format!("Shares array pointer is null (share_count: {})", share_count));
-        } else if out_data.is_null() {
-            set_error(format!("Output data pointer is null (share_count: {})", share_count));
-        } else {
-            set_error(format!("Output length pointer is null (share_count: {})", share_count));
+    ffi_boundary(|| {
+        if handle.is_null() || out_data.is_null() || out_len.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("EncodedData handle is null (column: {})", column),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output data pointer is null (column: {})", column),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output length pointer is null (column: {})", column),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
         }
-        return NomosDaResult::ErrorInvalidInput;
-    }
 
-    if share_count == 0 {
-        set_error(format!("Share count must be greater than 0, got {}", share_count));
-        return NomosDaResult::ErrorInvalidInput;
-    }
+        let encoded = &(*handle).data;
+        let column_count = encoded.combined_column_proofs.len();
+        if column >= column_count {
+            set_error(
+                NomosDaResult::ErrorIndexOutOfRange,
+                format!(
+                    "Column index {} is out of bounds. Valid range: 0..{}",
+                    column, column_count
+                ),
+            );
+            return NomosDaResult::ErrorIndexOutOfRange;
+        }
 
-    let shares_slice = std::slice::from_raw_parts(shares, share_count);
-    let mut da_shares = Vec::with_capacity(share_count);
+        let bytes: Vec<u8> = encoded
+            .extended_data
+            .0
+            .iter()
+            .flat_map(|row| row.0[column].0.iter().copied())
+            .collect();
+        write_bytes_out(bytes, out_data, out_len);
+        NomosDaResult::Success
+    })
+}
 
-    for (i, share_handle_ptr) in shares_slice.iter().enumerate() {
-        if share_handle_ptr.is_null() {
-            set_error(format!("Share handle pointer at index {} is null (share_count: {})", i, share_count));
-            return NomosDaResult::ErrorInvalidInput;
+/// Copies the chunk at (`row`, `column`) of the extended matrix. The output must
+/// be released with `nomos_da_bytes_free`.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoded_data_get_chunk(
+    handle: *mut EncodedDataHandle,
+    row: CSizeT,
+    column: CSizeT,
+    out_data: *mut *mut u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_data.is_null() || out_len.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "EncodedData handle is null (row: {}, column: {})",
+                        row, column
+                    ),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Output data pointer is null (row: {}, column: {})",
+                        row, column
+                    ),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Output length pointer is null (row: {}, column: {})",
+                        row, column
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
         }
-        let share_handle = *share_handle_ptr;
-        if share_handle.is_null() {
-            set_error(format!("Share handle at index {} is null (share_count: {})", i, share_count));
-            return NomosDaResult::ErrorInvalidInput;
+
+        let extended_data = &(*handle).data.extended_data;
+        match extended_data.0.get(row).and_then(|r| r.0.get(column)) {
+            Some(chunk) => {
+                write_bytes_out(chunk.0.clone(), out_data, out_len);
+                NomosDaResult::Success
+            }
+            None => {
+                set_error(
+                    NomosDaResult::ErrorIndexOutOfRange,
+                    format!(
+                        "Chunk ({}, {}) is out of bounds (row_count: {}, column_count: {})",
+                        row,
+                        column,
+                        extended_data.0.len(),
+                        extended_data.0.first().map_or(0, |r| r.0.len())
+                    ),
+                );
+                NomosDaResult::ErrorIndexOutOfRange
+            }
         }
-        da_shares.push((*share_handle).share.clone());
-    }
+    })
+}
 
-    let reconstructed_data = reconstruct_without_missing_data(&da_shares);
-    
-    if reconstructed_data.is_empty() {
-        set_error(format!("Reconstructed data is empty (share_count: {})", share_count));
-        return NomosDaResult::ErrorInternal;
-    }
+/// Writes the compressed 48-byte commitment of `row` into `out_commitment`
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoded_data_get_row_commitment(
+    handle: *mut EncodedDataHandle,
+    row: CSizeT,
+    out_commitment: *mut u8,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_commitment.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("EncodedData handle is null (row: {})", row),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output commitment pointer is null (row: {})", row),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
 
-    let len = reconstructed_data.len();
-    let boxed = reconstructed_data.into_boxed_slice();
-    let ptr = Box::into_raw(boxed) as *mut u8;
-    *out_data = ptr;
-    *out_len = len;
+        let row_commitments = &(*handle).data.row_commitments;
+        let Some(commitment) = row_commitments.get(row) else {
+            set_error(
+                NomosDaResult::ErrorIndexOutOfRange,
+                format!(
+                    "Row index {} is out of bounds. Valid range: 0..{}",
+                    row,
+                    row_commitments.len()
+                ),
+            );
+            return NomosDaResult::ErrorIndexOutOfRange;
+        };
 
-    NomosDaResult::Success
+        let out = std::slice::from_raw_parts_mut(out_commitment, COMMITMENT_SIZE);
+        if let Err(e) = commitment.serialize_compressed(out) {
+            set_error(
+                NomosDaResult::ErrorInternal,
+                format!("Commitment serialization error: {:?} (row: {})", e, row),
+            );
+            return NomosDaResult::ErrorInternal;
+        }
+        NomosDaResult::Success
+    })
 }
 
+/// Writes the compressed 48-byte combined proof of `column` into `out_proof`
 #[no_mangle]
-pub unsafe extern "C" fn nomos_da_reconstruct_free(data: *mut u8, len: CSizeT) {
-    if !data.is_null() && len > 0 {
-        let slice_ptr: *mut [u8] = ptr::slice_from_raw_parts_mut(data, len);
-        let _ = Box::from_raw(slice_ptr);
+pub unsafe extern "C" fn nomos_da_encoded_data_get_column_proof(
+    handle: *mut EncodedDataHandle,
+    column: CSizeT,
+    out_proof: *mut u8,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_proof.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("EncodedData handle is null (column: {})", column),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output proof pointer is null (column: {})", column),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let proofs = &(*handle).data.combined_column_proofs;
+        let Some(proof) = proofs.get(column) else {
+            set_error(
+                NomosDaResult::ErrorIndexOutOfRange,
+                format!(
+                    "Column index {} is out of bounds. Valid range: 0..{}",
+                    column,
+                    proofs.len()
+                ),
+            );
+            return NomosDaResult::ErrorIndexOutOfRange;
+        };
+
+        let out = std::slice::from_raw_parts_mut(out_proof, PROOF_SIZE);
+        if let Err(e) = proof.serialize_compressed(out) {
+            set_error(
+                NomosDaResult::ErrorInternal,
+                format!("Proof serialization error: {:?} (column: {})", e, column),
+            );
+            return NomosDaResult::ErrorInternal;
+        }
+        NomosDaResult::Success
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_verifier_new() -> *mut VerifierHandle {
+    ffi_boundary(|| {
+        into_shared(VerifierHandle {
+            verifier: DaVerifier::new(VERIFICATION_KEY.clone()),
+            verification_key: None,
+        })
+    })
+}
+
+fn verifier_with_key(loaded: Result<VerificationKey, ParamsError>) -> *mut VerifierHandle {
+    match loaded {
+        Ok(verification_key) => into_shared(VerifierHandle {
+            verifier: DaVerifier::new(verification_key.clone()),
+            verification_key: Some(verification_key),
+        }),
+        Err(e) => {
+            set_error(
+                NomosDaResult::from(&e),
+                format!("Failed to load verification key: {}", e),
+            );
+            ptr::null_mut()
+        }
     }
 }
+
+/// Creates a verifier from a verification key in compressed ark-serialize form,
+/// as produced by `nomos_da_verifier_export_key`
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_verifier_new_with_key(
+    key_data: *const u8,
+    key_len: CSizeT,
+) -> *mut VerifierHandle {
+    ffi_boundary(|| {
+        if key_data.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                format!(
+                    "Verification key data pointer is null (key_len: {})",
+                    key_len
+                ),
+            );
+            return ptr::null_mut();
+        }
+
+        let bytes = std::slice::from_raw_parts(key_data, key_len);
+        verifier_with_key(params::from_bytes(bytes))
+    })
+}
+
+/// Creates a verifier from a verification key stored in a file
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_verifier_new_with_key_file(
+    path: *const c_char,
+) -> *mut VerifierHandle {
+    ffi_boundary(|| match path_from_c_str(path) {
+        Ok(path) => verifier_with_key(params::from_file(path)),
+        Err(_) => ptr::null_mut(),
+    })
+}
+
+/// Exports the verification key the verifier was built with
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_verifier_export_key(
+    handle: *mut VerifierHandle,
+    out_data: *mut *mut u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_data.is_null() || out_len.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Verifier handle is null".to_string(),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output data pointer is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output length pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let verification_key = (*handle)
+            .verification_key
+            .as_ref()
+            .unwrap_or(&*VERIFICATION_KEY);
+        match params::to_bytes(verification_key) {
+            Ok(bytes) => {
+                write_bytes_out(bytes, out_data, out_len);
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorInternal,
+                    format!("Verification key serialization error: {:?}", e),
+                );
+                NomosDaResult::ErrorInternal
+            }
+        }
+    })
+}
+
+/// Same as `nomos_da_verifier_release`
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_verifier_free(handle: *mut VerifierHandle) {
+    ffi_boundary_keep_error(|| release_shared(handle))
+}
+
+/// Adds a reference to the verifier and returns `handle`, which must then be
+/// released once more
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_verifier_retain(
+    handle: *mut VerifierHandle,
+) -> *mut VerifierHandle {
+    ffi_boundary_keep_error(|| retain_shared(handle))
+}
+
+/// Drops a reference to the verifier, freeing it when no references remain
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_verifier_release(handle: *mut VerifierHandle) {
+    ffi_boundary_keep_error(|| release_shared(handle))
+}
+
+#[no_mangle]
+pub extern "C" fn nomos_da_chunk_size() -> CSizeT {
+    ffi_boundary(|| DaEncoderParams::MAX_BLS12_381_ENCODING_CHUNK_SIZE)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoded_data_get_share_count(
+    handle: *mut EncodedDataHandle,
+) -> CSizeT {
+    ffi_boundary(|| {
+        if handle.is_null() {
+            return 0;
+        }
+        (*handle).data.combined_column_proofs.len()
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoded_data_get_share(
+    handle: *mut EncodedDataHandle,
+    index: CSizeT,
+    out_share_handle: *mut *mut ShareHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_share_handle.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("EncodedData handle is null (share_index: {})", index),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output share handle is null (share_index: {})", index),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        match (*handle).data.to_da_share(index) {
+            Some(share) => {
+                *out_share_handle = into_shared(ShareHandle { share });
+                NomosDaResult::Success
+            }
+            None => {
+                let share_count = (*handle).data.combined_column_proofs.len();
+                set_error(
+                    NomosDaResult::ErrorIndexOutOfRange,
+                    format!(
+                        "Share index {} is out of bounds. Valid range: 0..{} (share_count: {})",
+                        index, share_count, share_count
+                    ),
+                );
+                NomosDaResult::ErrorIndexOutOfRange
+            }
+        }
+    })
+}
+
+/// Same as `nomos_da_share_release`
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_share_free(handle: *mut ShareHandle) {
+    ffi_boundary_keep_error(|| release_shared(handle))
+}
+
+/// Adds a reference to the share and returns `handle`, which must then be
+/// released once more
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_share_retain(handle: *mut ShareHandle) -> *mut ShareHandle {
+    ffi_boundary_keep_error(|| retain_shared(handle))
+}
+
+/// Drops a reference to the share, freeing it when no references remain
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_share_release(handle: *mut ShareHandle) {
+    ffi_boundary_keep_error(|| release_shared(handle))
+}
+
+/// Returns an independent copy of the share, or null if `share_handle` is null
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_share_clone(share_handle: *mut ShareHandle) -> *mut ShareHandle {
+    ffi_boundary(|| {
+        if share_handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Share handle is null".to_string(),
+            );
+            return ptr::null_mut();
+        }
+        into_shared(ShareHandle {
+            share: (*share_handle).share.clone(),
+        })
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_share_get_index(share_handle: *mut ShareHandle) -> u16 {
+    ffi_boundary(|| {
+        if share_handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Share handle is null".to_string(),
+            );
+            return 0;
+        }
+        (*share_handle).share.share_idx
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_share_get_commitments(
+    share_handle: *mut ShareHandle,
+    out_commitments_handle: *mut *mut CommitmentsHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if share_handle.is_null() || out_commitments_handle.is_null() {
+            if share_handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Share handle is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output commitments handle pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let (_, commitments) = (*share_handle).share.clone().into_share_and_commitments();
+        *out_commitments_handle = into_shared(CommitmentsHandle { commitments });
+        NomosDaResult::Success
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_share_to_bytes(
+    share_handle: *mut ShareHandle,
+    out_data: *mut *mut u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if share_handle.is_null() || out_data.is_null() || out_len.is_null() {
+            if share_handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Share handle is null".to_string(),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output data pointer is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output length pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let share = &(*share_handle).share;
+        match wire_serialize(share) {
+            Ok(bytes) => {
+                write_bytes_out(bytes, out_data, out_len);
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorInternal,
+                    format!(
+                        "Share serialization error: {:?} (share_idx: {})",
+                        e, share.share_idx
+                    ),
+                );
+                NomosDaResult::ErrorInternal
+            }
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_share_from_bytes(
+    data: *const u8,
+    data_len: CSizeT,
+    out_share_handle: *mut *mut ShareHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if data.is_null() || out_share_handle.is_null() {
+            if data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Data pointer is null (data_len: {})", data_len),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output share handle is null (data_len: {})", data_len),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        if data_len == 0 {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                "Data length must be greater than 0".to_string(),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        let bytes = std::slice::from_raw_parts(data, data_len);
+        match wire_deserialize::<DaShare>(bytes) {
+            Ok(share) => {
+                *out_share_handle = into_shared(ShareHandle { share });
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorDeserialization,
+                    format!(
+                        "Share deserialization error: {:?} (data_len: {})",
+                        e, data_len
+                    ),
+                );
+                NomosDaResult::ErrorDeserialization
+            }
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_bytes_free(data: *mut u8, len: CSizeT) {
+    ffi_boundary_keep_error(|| {
+        if !data.is_null() && len > 0 {
+            let slice_ptr: *mut [u8] = ptr::slice_from_raw_parts_mut(data, len);
+            let _ = Box::from_raw(slice_ptr);
+        }
+    })
+}
+
+/// Verifies a full share, returning `Success` when it is valid or a code naming
+/// the reason it was rejected
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_verifier_verify(
+    verifier: *mut VerifierHandle,
+    share_handle: *mut ShareHandle,
+    rows_domain_size: CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if verifier.is_null() || share_handle.is_null() {
+            if verifier.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Verifier handle is null (rows_domain_size: {})",
+                        rows_domain_size
+                    ),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Share handle is null (rows_domain_size: {})",
+                        rows_domain_size
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let share = &(*share_handle).share;
+        let (light_share, commitments) = share.clone().into_share_and_commitments();
+
+        match verify_light_share(
+            &(*verifier).verifier,
+            &light_share,
+            &commitments,
+            rows_domain_size,
+        ) {
+            Ok(()) => NomosDaResult::Success,
+            Err((code, message)) => {
+                set_error(code, format!("Share verification failed: {}", message));
+                code
+            }
+        }
+    })
+}
+
+/// Verifies a batch of shares in parallel, writing one result code per share
+/// into `out_results` (which must hold `share_count` entries). Each share goes
+/// through the same checks as `nomos_da_verifier_verify`, so its code is
+/// `Success` or the reason it was rejected; null share handles are reported as
+/// `ErrorNullHandle`. Returns `Success` once every share has been checked,
+/// whether or not all of them are valid.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_verifier_verify_batch(
+    verifier: *mut VerifierHandle,
+    shares: *const *mut ShareHandle,
+    share_count: CSizeT,
+    rows_domain_size: CSizeT,
+    out_results: *mut NomosDaResult,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if verifier.is_null() || shares.is_null() || out_results.is_null() {
+            if verifier.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Verifier handle is null (share_count: {})", share_count),
+                );
+            } else if shares.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Shares array pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Output results pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        if share_count == 0 {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                format!("Share count must be greater than 0, got {}", share_count),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        if rows_domain_size == 0 {
+            set_error(
+                NomosDaResult::ErrorDomainSizeMismatch,
+                format!(
+                    "Rows domain size must be greater than 0, got {}",
+                    rows_domain_size
+                ),
+            );
+            return NomosDaResult::ErrorDomainSizeMismatch;
+        }
+
+        // Raw pointers are not `Send`, so borrow the shares before fanning out
+        let da_shares: Vec<Option<&DaShare>> = std::slice::from_raw_parts(shares, share_count)
+            .iter()
+            .map(|share_handle| (*share_handle).as_ref().map(|handle| &handle.share))
+            .collect();
+        let da_verifier = &(*verifier).verifier;
+
+        let results: Vec<Result<(), (NomosDaResult, String)>> = da_shares
+            .par_iter()
+            .map(|share| {
+                let share = share.ok_or_else(|| {
+                    (
+                        NomosDaResult::ErrorNullHandle,
+                        "Share handle is null".to_string(),
+                    )
+                })?;
+                let (light_share, commitments) = share.clone().into_share_and_commitments();
+                verify_light_share(da_verifier, &light_share, &commitments, rows_domain_size)
+            })
+            .collect();
+
+        let out_results = std::slice::from_raw_parts_mut(out_results, share_count);
+        for (out_result, result) in out_results.iter_mut().zip(&results) {
+            *out_result = match result {
+                Ok(()) => NomosDaResult::Success,
+                Err((code, _)) => *code,
+            };
+        }
+
+        let mut failures = results
+            .iter()
+            .enumerate()
+            .filter_map(|(i, result)| result.as_ref().err().map(|failure| (i, failure)));
+        if let Some((position, (code, message))) = failures.next() {
+            set_error(
+                *code,
+                format!(
+                    "Share verification failed for {} of {} shares; first at position {}: {}",
+                    failures.count() + 1,
+                    share_count,
+                    position,
+                    message
+                ),
+            );
+        }
+
+        NomosDaResult::Success
+    })
+}
+
+/// Verifies a light share against row commitments that were received separately
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_verifier_verify_light(
+    verifier: *mut VerifierHandle,
+    light_share_handle: *mut LightShareHandle,
+    commitments_handle: *mut CommitmentsHandle,
+    rows_domain_size: CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if verifier.is_null() || light_share_handle.is_null() || commitments_handle.is_null() {
+            if verifier.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Verifier handle is null (rows_domain_size: {})",
+                        rows_domain_size
+                    ),
+                );
+            } else if light_share_handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Light share handle is null (rows_domain_size: {})",
+                        rows_domain_size
+                    ),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Commitments handle is null (rows_domain_size: {})",
+                        rows_domain_size
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let light_share = &(*light_share_handle).light_share;
+        let commitments = &(*commitments_handle).commitments;
+
+        match verify_light_share(
+            &(*verifier).verifier,
+            light_share,
+            commitments,
+            rows_domain_size,
+        ) {
+            Ok(()) => NomosDaResult::Success,
+            Err((code, message)) => {
+                set_error(
+                    code,
+                    format!("Light share verification failed: {}", message),
+                );
+                code
+            }
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_light_share_from_share(
+    share_handle: *mut ShareHandle,
+    out_light_share_handle: *mut *mut LightShareHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if share_handle.is_null() || out_light_share_handle.is_null() {
+            if share_handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Share handle is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output light share handle pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let (light_share, _) = (*share_handle).share.clone().into_share_and_commitments();
+        *out_light_share_handle = Box::into_raw(Box::new(LightShareHandle { light_share }));
+        NomosDaResult::Success
+    })
+}
+
+/// Serializes a light share using the bincode wire format of logos-blockchain nodes.
+/// The output must be released with `nomos_da_bytes_free`.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_light_share_to_bytes(
+    light_share_handle: *mut LightShareHandle,
+    out_data: *mut *mut u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if light_share_handle.is_null() || out_data.is_null() || out_len.is_null() {
+            if light_share_handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Light share handle is null".to_string(),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output data pointer is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output length pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let light_share = &(*light_share_handle).light_share;
+        match wire_serialize(light_share) {
+            Ok(bytes) => {
+                write_bytes_out(bytes, out_data, out_len);
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorInternal,
+                    format!(
+                        "Light share serialization error: {:?} (share_idx: {})",
+                        e, light_share.share_idx
+                    ),
+                );
+                NomosDaResult::ErrorInternal
+            }
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_light_share_from_bytes(
+    data: *const u8,
+    data_len: CSizeT,
+    out_light_share_handle: *mut *mut LightShareHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if data.is_null() || out_light_share_handle.is_null() {
+            if data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Data pointer is null (data_len: {})", data_len),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output light share handle is null (data_len: {})", data_len),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        if data_len == 0 {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                "Data length must be greater than 0".to_string(),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        let bytes = std::slice::from_raw_parts(data, data_len);
+        match wire_deserialize::<DaLightShare>(bytes) {
+            Ok(light_share) => {
+                *out_light_share_handle = Box::into_raw(Box::new(LightShareHandle { light_share }));
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorDeserialization,
+                    format!(
+                        "Light share deserialization error: {:?} (data_len: {})",
+                        e, data_len
+                    ),
+                );
+                NomosDaResult::ErrorDeserialization
+            }
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_light_share_get_index(
+    light_share_handle: *mut LightShareHandle,
+) -> u16 {
+    ffi_boundary(|| {
+        if light_share_handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Light share handle is null".to_string(),
+            );
+            return 0;
+        }
+        (*light_share_handle).light_share.share_idx
+    })
+}
+
+/// Returns an independent copy of the light share, or null if the handle is null
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_light_share_clone(
+    light_share_handle: *mut LightShareHandle,
+) -> *mut LightShareHandle {
+    ffi_boundary(|| {
+        if light_share_handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Light share handle is null".to_string(),
+            );
+            return ptr::null_mut();
+        }
+        Box::into_raw(Box::new(LightShareHandle {
+            light_share: (*light_share_handle).light_share.clone(),
+        }))
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_light_share_free(handle: *mut LightShareHandle) {
+    ffi_boundary_keep_error(|| {
+        if !handle.is_null() {
+            let _ = Box::from_raw(handle);
+        }
+    })
+}
+
+/// Returns an independent copy of the commitments, or null if the handle is null
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_commitments_clone(
+    handle: *mut CommitmentsHandle,
+) -> *mut CommitmentsHandle {
+    ffi_boundary(|| {
+        if handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Commitments handle is null".to_string(),
+            );
+            return ptr::null_mut();
+        }
+        into_shared(CommitmentsHandle {
+            commitments: (*handle).commitments.clone(),
+        })
+    })
+}
+
+/// Same as `nomos_da_commitments_release`
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_commitments_free(handle: *mut CommitmentsHandle) {
+    ffi_boundary_keep_error(|| release_shared(handle))
+}
+
+/// Adds a reference to the commitments and returns `handle`, which must then be
+/// released once more
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_commitments_retain(
+    handle: *mut CommitmentsHandle,
+) -> *mut CommitmentsHandle {
+    ffi_boundary_keep_error(|| retain_shared(handle))
+}
+
+/// Drops a reference to the commitments, freeing it when no references remain
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_commitments_release(handle: *mut CommitmentsHandle) {
+    ffi_boundary_keep_error(|| release_shared(handle))
+}
+
+#[no_mangle]
+pub extern "C" fn nomos_da_commitment_size() -> CSizeT {
+    ffi_boundary(|| COMMITMENT_SIZE)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_commitments_row_count(handle: *mut CommitmentsHandle) -> CSizeT {
+    ffi_boundary(|| {
+        if handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Commitments handle is null".to_string(),
+            );
+            return 0;
+        }
+        (*handle).commitments.rows_commitments.len()
+    })
+}
+
+/// Exports the row commitments as consecutive compressed 48-byte G1 points.
+/// The output must be released with `nomos_da_bytes_free`.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_commitments_to_bytes(
+    handle: *mut CommitmentsHandle,
+    out_data: *mut *mut u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_data.is_null() || out_len.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Commitments handle is null".to_string(),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output data pointer is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output length pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let rows_commitments = &(*handle).commitments.rows_commitments;
+        let mut bytes = Vec::with_capacity(rows_commitments.len() * COMMITMENT_SIZE);
+        for (row, commitment) in rows_commitments.iter().enumerate() {
+            if let Err(e) = commitment.serialize_compressed(&mut bytes) {
+                set_error(
+                    NomosDaResult::ErrorInternal,
+                    format!(
+                        "Commitment serialization error: {:?} (row: {}, row_count: {})",
+                        e,
+                        row,
+                        rows_commitments.len()
+                    ),
+                );
+                return NomosDaResult::ErrorInternal;
+            }
+        }
+
+        write_bytes_out(bytes, out_data, out_len);
+        NomosDaResult::Success
+    })
+}
+
+/// Rebuilds a commitments handle from consecutive compressed 48-byte G1 points
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_commitments_from_bytes(
+    data: *const u8,
+    data_len: CSizeT,
+    out_commitments_handle: *mut *mut CommitmentsHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if data.is_null() || out_commitments_handle.is_null() {
+            if data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Data pointer is null (data_len: {})", data_len),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output commitments handle is null (data_len: {})", data_len),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        if data_len == 0 || data_len % COMMITMENT_SIZE != 0 {
+            set_error(NomosDaResult::ErrorBadLength, format!(
+                "Data length must be a non-zero multiple of the commitment size (data_len: {}, commitment_size: {})",
+                data_len, COMMITMENT_SIZE
+            ));
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        let bytes = std::slice::from_raw_parts(data, data_len);
+        let mut rows_commitments = Vec::with_capacity(data_len / COMMITMENT_SIZE);
+        for (row, point) in bytes.chunks_exact(COMMITMENT_SIZE).enumerate() {
+            match Commitment::deserialize_compressed(point) {
+                Ok(commitment) => rows_commitments.push(commitment),
+                Err(e) => {
+                    set_error(
+                        NomosDaResult::ErrorDeserialization,
+                        format!(
+                            "Commitment deserialization error: {:?} (row: {}, data_len: {})",
+                            e, row, data_len
+                        ),
+                    );
+                    return NomosDaResult::ErrorDeserialization;
+                }
+            }
+        }
+
+        *out_commitments_handle = into_shared(CommitmentsHandle {
+            commitments: DaSharesCommitments { rows_commitments },
+        });
+        NomosDaResult::Success
+    })
+}
+
+/// Writes the 32-byte blob id of the row commitments into `out_blob_id`
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_commitments_blob_id(
+    handle: *mut CommitmentsHandle,
+    out_blob_id: *mut u8,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_blob_id.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Commitments handle is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output blob id pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let blob_id = build_blob_id(&(*handle).commitments.rows_commitments);
+        ptr::copy_nonoverlapping(blob_id.as_ptr(), out_blob_id, BLOB_ID_SIZE);
+        NomosDaResult::Success
+    })
+}
+
+/// Writes the 32-byte blob id of the blob the share belongs to into `out_blob_id`
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_share_blob_id(
+    share_handle: *mut ShareHandle,
+    out_blob_id: *mut u8,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if share_handle.is_null() || out_blob_id.is_null() {
+            if share_handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Share handle is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output blob id pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let blob_id = (*share_handle).share.blob_id();
+        ptr::copy_nonoverlapping(blob_id.as_ptr(), out_blob_id, BLOB_ID_SIZE);
+        NomosDaResult::Success
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_commitments_equal(
+    lhs: *mut CommitmentsHandle,
+    rhs: *mut CommitmentsHandle,
+) -> bool {
+    ffi_boundary(|| {
+        if lhs.is_null() || rhs.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Commitments handle is null".to_string(),
+            );
+            return false;
+        }
+        (*lhs).commitments.rows_commitments == (*rhs).commitments.rows_commitments
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_reconstruct(
+    shares: *const *mut ShareHandle,
+    share_count: CSizeT,
+    out_data: *mut *mut u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if shares.is_null() || out_data.is_null() || out_len.is_null() {
+            if shares.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Shares array pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output data pointer is null (share_count: {})", share_count),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Output length pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        if share_count == 0 {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                format!("Share count must be greater than 0, got {}", share_count),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        let da_shares: Vec<DaShare> = match collect_shares(shares, share_count) {
+            Ok(refs) => refs.into_iter().cloned().collect(),
+            Err(result) => return result,
+        };
+
+        let reconstructed_data = reconstruct_without_missing_data(&da_shares);
+
+        if reconstructed_data.is_empty() {
+            set_error(
+                NomosDaResult::ErrorInternal,
+                format!("Reconstructed data is empty (share_count: {})", share_count),
+            );
+            return NomosDaResult::ErrorInternal;
+        }
+
+        let len = reconstructed_data.len();
+        let boxed = reconstructed_data.into_boxed_slice();
+        let ptr = Box::into_raw(boxed) as *mut u8;
+        *out_data = ptr;
+        *out_len = len;
+
+        NomosDaResult::Success
+    })
+}
+
+/// Reconstructs the original data from any subset of shares holding at least
+/// `column_count / 2` distinct indices. The output must be released with
+/// `nomos_da_reconstruct_free`.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_reconstruct_with_missing_data(
+    shares: *const *mut ShareHandle,
+    share_count: CSizeT,
+    column_count: CSizeT,
+    out_data: *mut *mut u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if shares.is_null() || out_data.is_null() || out_len.is_null() {
+            if shares.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Shares array pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output data pointer is null (share_count: {})", share_count),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Output length pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        if share_count == 0 {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                format!("Share count must be greater than 0, got {}", share_count),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        let da_shares = match collect_shares(shares, share_count) {
+            Ok(refs) => refs,
+            Err(result) => return result,
+        };
+
+        match reconstruct_with_missing_data(&da_shares, column_count) {
+            Ok(reconstructed_data) => {
+                write_bytes_out(reconstructed_data, out_data, out_len);
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(
+                    code,
+                    format!(
+                        "Reconstruction failed: {} (share_count: {}, column_count: {})",
+                        e, share_count, column_count
+                    ),
+                );
+                code
+            }
+        }
+    })
+}
+
+/// Reconstructs like `nomos_da_reconstruct_with_missing_data`, then re-encodes
+/// the data with `encoder` and checks the result against the row commitments
+/// (and so the blob id) of the shares. Returns `ErrorReencodingMismatch` when
+/// they differ, which is also the outcome when `encoder` was built with other
+/// global parameters than the blob. `column_count` must be the column count
+/// `encoder` was created with, otherwise `ErrorInvalidInput` is returned before
+/// anything is reconstructed. The output must be released with
+/// `nomos_da_reconstruct_free`.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_reconstruct_and_verify(
+    encoder: *mut EncoderHandle,
+    shares: *const *mut ShareHandle,
+    share_count: CSizeT,
+    column_count: CSizeT,
+    out_data: *mut *mut u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if encoder.is_null() || shares.is_null() || out_data.is_null() || out_len.is_null() {
+            if encoder.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Encoder handle is null (share_count: {})", share_count),
+                );
+            } else if shares.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Shares array pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output data pointer is null (share_count: {})", share_count),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Output length pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        if share_count == 0 {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                format!("Share count must be greater than 0, got {}", share_count),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        let encoder = &*encoder;
+        if column_count != encoder.column_count {
+            set_error(
+                NomosDaResult::ErrorInvalidInput,
+                format!(
+                    "Column count {} does not match the encoder's {} (share_count: {})",
+                    column_count, encoder.column_count, share_count
+                ),
+            );
+            return NomosDaResult::ErrorInvalidInput;
+        }
+
+        let da_shares = match collect_shares(shares, share_count) {
+            Ok(refs) => refs,
+            Err(result) => return result,
+        };
+
+        let verified = match &encoder.thread_pool {
+            Some(pool) => {
+                pool.install(|| reconstruct_and_verify(&encoder.encoder, &da_shares, column_count))
+            }
+            None => reconstruct_and_verify(&encoder.encoder, &da_shares, column_count),
+        };
+        match verified {
+            Ok(reconstructed_data) => {
+                write_bytes_out(reconstructed_data, out_data, out_len);
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(
+                    code,
+                    format!(
+                        "Verified reconstruction failed: {} (share_count: {}, column_count: {})",
+                        e, share_count, column_count
+                    ),
+                );
+                code
+            }
+        }
+    })
+}
+
+/// Reconstructs a payload encoded with `nomos_da_encoder_encode_padded` from any
+/// sufficient subset of shares and strips the canonical padding, so the output
+/// is byte-identical to the original input. The output must be released with
+/// `nomos_da_reconstruct_free`.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_reconstruct_padded(
+    shares: *const *mut ShareHandle,
+    share_count: CSizeT,
+    column_count: CSizeT,
+    out_data: *mut *mut u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if shares.is_null() || out_data.is_null() || out_len.is_null() {
+            if shares.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Shares array pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            } else if out_data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output data pointer is null (share_count: {})", share_count),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Output length pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        if share_count == 0 {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                format!("Share count must be greater than 0, got {}", share_count),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        let da_shares = match collect_shares(shares, share_count) {
+            Ok(refs) => refs,
+            Err(result) => return result,
+        };
+
+        let reconstructed_data = match reconstruct_with_missing_data(&da_shares, column_count) {
+            Ok(reconstructed_data) => reconstructed_data,
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(
+                    code,
+                    format!(
+                        "Reconstruction failed: {} (share_count: {}, column_count: {})",
+                        e, share_count, column_count
+                    ),
+                );
+                return code;
+            }
+        };
+
+        match unpad(&reconstructed_data) {
+            Ok(payload) => {
+                write_bytes_out(payload.to_vec(), out_data, out_len);
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorInvalidPadding,
+                    format!(
+                        "Invalid padding in reconstructed data: {} (reconstructed_len: {})",
+                        e,
+                        reconstructed_data.len()
+                    ),
+                );
+                NomosDaResult::ErrorInvalidPadding
+            }
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_reconstruct_free(data: *mut u8, len: CSizeT) {
+    ffi_boundary_keep_error(|| {
+        if !data.is_null() && len > 0 {
+            let slice_ptr: *mut [u8] = ptr::slice_from_raw_parts_mut(data, len);
+            let _ = Box::from_raw(slice_ptr);
+        }
+    })
+}
+
+/// Rebuilds encoded data from one share per column of a blob, so every
+/// `nomos_da_encoded_data_*` accessor can be used on shares collected from the
+/// network. Fails if the shares carry different row commitments, repeat an
+/// index or do not cover all `column_count` columns.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoded_data_from_shares(
+    shares: *const *mut ShareHandle,
+    share_count: CSizeT,
+    column_count: CSizeT,
+    out_handle: *mut *mut EncodedDataHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if shares.is_null() || out_handle.is_null() {
+            if shares.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Shares array pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Output handle pointer is null (share_count: {})",
+                        share_count
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        if share_count == 0 {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                format!("Share count must be greater than 0, got {}", share_count),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        let da_shares = match collect_shares(shares, share_count) {
+            Ok(refs) => refs,
+            Err(result) => return result,
+        };
+
+        match assemble_encoded_data(&da_shares, column_count) {
+            Ok(data) => {
+                *out_handle = Box::into_raw(Box::new(EncodedDataHandle::new(data)));
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(
+                    code,
+                    format!(
+                        "Share assembly failed: {} (share_count: {}, column_count: {})",
+                        e, share_count, column_count
+                    ),
+                );
+                code
+            }
+        }
+    })
+}
+
+/// Writes the number of blobs a payload of `data_len` bytes is split into under
+/// `max_blob_size`, the length of the array `nomos_da_encoder_encode_split` fills
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_split_blob_count(
+    data_len: CSizeT,
+    max_blob_size: CSizeT,
+    out_count: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if out_count.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Output count pointer is null".to_string(),
+            );
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        match blob_count(data_len, max_blob_size) {
+            Ok(count) => {
+                *out_count = count;
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(code, e.to_string());
+                code
+            }
+        }
+    })
+}
+
+/// Splits a payload of any length into blobs of at most `max_blob_size` encoded
+/// bytes (a multiple of the chunk size), pads and encodes each one, and writes
+/// one encoded data handle per blob into `out_blobs`, which must hold exactly
+/// `blob_count` entries as given by `nomos_da_split_blob_count`. Nothing is
+/// written unless every blob encodes.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_encoder_encode_split(
+    encoder: *mut EncoderHandle,
+    data: *const u8,
+    data_len: CSizeT,
+    max_blob_size: CSizeT,
+    out_blobs: *mut *mut EncodedDataHandle,
+    blob_count: CSizeT,
+    out_manifest: *mut *mut BlobManifestHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if encoder.is_null()
+            || (data.is_null() && data_len > 0)
+            || out_blobs.is_null()
+            || out_manifest.is_null()
+        {
+            if encoder.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Encoder handle is null (data_len: {})", data_len),
+                );
+            } else if out_blobs.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output blobs pointer is null (blob_count: {})", blob_count),
+                );
+            } else if out_manifest.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output manifest handle is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Data pointer is null (data_len: {})", data_len),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let data_slice = if data_len == 0 {
+            &[][..]
+        } else {
+            std::slice::from_raw_parts(data, data_len)
+        };
+        let pieces = match split_payload(data_slice, max_blob_size) {
+            Ok(pieces) => pieces,
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(code, e.to_string());
+                return code;
+            }
+        };
+        if pieces.len() != blob_count {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                format!(
+                "Payload splits into {} blobs, output holds {} (data_len: {}, max_blob_size: {})",
+                pieces.len(),
+                blob_count,
+                data_len,
+                max_blob_size
+            ),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        let mut encoded_blobs = Vec::with_capacity(pieces.len());
+        let mut entries = Vec::with_capacity(pieces.len());
+        for (index, piece) in pieces.iter().enumerate() {
+            match (*encoder).encode(&pad(piece)) {
+                Ok(encoded) => {
+                    entries.push(BlobManifestEntry::new(
+                        build_blob_id(&encoded.row_commitments),
+                        piece.len(),
+                    ));
+                    encoded_blobs.push(encoded);
+                }
+                Err(e) => {
+                    set_error(
+                        NomosDaResult::ErrorInternal,
+                        format!(
+                            "Encoding error: {:?} (blob: {}, blob_count: {}, piece_len: {})",
+                            e,
+                            index,
+                            blob_count,
+                            piece.len()
+                        ),
+                    );
+                    return NomosDaResult::ErrorInternal;
+                }
+            }
+        }
+
+        let out_blobs = std::slice::from_raw_parts_mut(out_blobs, blob_count);
+        for (out_blob, encoded) in out_blobs.iter_mut().zip(encoded_blobs) {
+            *out_blob = Box::into_raw(Box::new(EncodedDataHandle::new(encoded)));
+        }
+        let manifest = BlobManifest {
+            max_blob_size: max_blob_size as u64,
+            payload_len: data_len as u64,
+            blobs: entries,
+        };
+        *out_manifest = Box::into_raw(Box::new(BlobManifestHandle { manifest }));
+        NomosDaResult::Success
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_manifest_free(handle: *mut BlobManifestHandle) {
+    ffi_boundary_keep_error(|| {
+        if !handle.is_null() {
+            let _ = Box::from_raw(handle);
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_manifest_blob_count(handle: *mut BlobManifestHandle) -> CSizeT {
+    ffi_boundary(|| {
+        if handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Manifest handle is null".to_string(),
+            );
+            return 0;
+        }
+        (*handle).manifest.blobs.len()
+    })
+}
+
+/// Length of the whole payload the manifest describes, 0 if `handle` is null
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_manifest_payload_len(handle: *mut BlobManifestHandle) -> u64 {
+    ffi_boundary(|| {
+        if handle.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Manifest handle is null".to_string(),
+            );
+            return 0;
+        }
+        (*handle).manifest.payload_len
+    })
+}
+
+/// Writes the blob id, payload length and padding of blob `index`, in payload order
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_manifest_entry(
+    handle: *mut BlobManifestHandle,
+    index: CSizeT,
+    out_entry: *mut BlobManifestEntry,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_entry.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Manifest handle is null (index: {})", index),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output entry pointer is null (index: {})", index),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let blobs = &(*handle).manifest.blobs;
+        match blobs.get(index) {
+            Some(entry) => {
+                *out_entry = *entry;
+                NomosDaResult::Success
+            }
+            None => {
+                set_error(
+                    NomosDaResult::ErrorIndexOutOfRange,
+                    format!(
+                        "Blob index out of range (index: {}, blob_count: {})",
+                        index,
+                        blobs.len()
+                    ),
+                );
+                NomosDaResult::ErrorIndexOutOfRange
+            }
+        }
+    })
+}
+
+/// Serializes the manifest in the node wire format; free the buffer with
+/// `nomos_da_bytes_free`
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_manifest_to_bytes(
+    handle: *mut BlobManifestHandle,
+    out_data: *mut *mut u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null() || out_data.is_null() || out_len.is_null() {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Manifest handle is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output data or length pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        match wire_serialize(&(*handle).manifest) {
+            Ok(bytes) => {
+                write_bytes_out(bytes, out_data, out_len);
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorInternal,
+                    format!("Manifest serialization error: {:?}", e),
+                );
+                NomosDaResult::ErrorInternal
+            }
+        }
+    })
+}
+
+/// Decodes a manifest written by `nomos_da_manifest_to_bytes`. A manifest
+/// whose recorded lengths disagree with each other is rejected with
+/// `ErrorInvalidInput`, so later calls never act on forged lengths.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_manifest_from_bytes(
+    data: *const u8,
+    data_len: CSizeT,
+    out_manifest: *mut *mut BlobManifestHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if data.is_null() || out_manifest.is_null() {
+            if data.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Data pointer is null (data_len: {})", data_len),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output manifest handle is null (data_len: {})", data_len),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        if data_len == 0 {
+            set_error(
+                NomosDaResult::ErrorBadLength,
+                "Data length must be greater than 0".to_string(),
+            );
+            return NomosDaResult::ErrorBadLength;
+        }
+
+        let bytes = std::slice::from_raw_parts(data, data_len);
+        match wire_deserialize::<BlobManifest>(bytes) {
+            Ok(manifest) => {
+                if let Err(e) = manifest.validate() {
+                    let code = NomosDaResult::from(&e);
+                    set_error(
+                        code,
+                        format!(
+                            "Decoded manifest is inconsistent: {} (data_len: {})",
+                            e, data_len
+                        ),
+                    );
+                    return code;
+                }
+                *out_manifest = Box::into_raw(Box::new(BlobManifestHandle { manifest }));
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                set_error(
+                    NomosDaResult::ErrorDeserialization,
+                    format!(
+                        "Manifest deserialization error: {:?} (data_len: {})",
+                        e, data_len
+                    ),
+                );
+                NomosDaResult::ErrorDeserialization
+            }
+        }
+    })
+}
+
+/// Rebuilds the payload from its blobs as returned by reconstruction, given in
+/// manifest order as `blob_count` buffers. Each blob is unpadded and checked
+/// against the payload length the manifest records for it; the blob ids are not
+/// checked here (`nomos_da_reconstruct_and_verify` does that per blob). Free
+/// the payload with `nomos_da_bytes_free`.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_manifest_reassemble(
+    handle: *mut BlobManifestHandle,
+    blobs: *const *const u8,
+    blob_lens: *const CSizeT,
+    blob_count: CSizeT,
+    out_data: *mut *mut u8,
+    out_len: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if handle.is_null()
+            || ((blobs.is_null() || blob_lens.is_null()) && blob_count > 0)
+            || out_data.is_null()
+            || out_len.is_null()
+        {
+            if handle.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Manifest handle is null (blob_count: {})", blob_count),
+                );
+            } else if out_data.is_null() || out_len.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output data or length pointer is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Blobs or blob lengths pointer is null (blob_count: {})",
+                        blob_count
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let mut blob_slices: Vec<&[u8]> = Vec::with_capacity(blob_count);
+        if blob_count > 0 {
+            let pointers = std::slice::from_raw_parts(blobs, blob_count);
+            let lens = std::slice::from_raw_parts(blob_lens, blob_count);
+            for (i, (&blob, &len)) in pointers.iter().zip(lens).enumerate() {
+                if blob.is_null() && len > 0 {
+                    set_error(
+                        NomosDaResult::ErrorNullHandle,
+                        format!("Blob at index {} is null (blob_count: {})", i, blob_count),
+                    );
+                    return NomosDaResult::ErrorNullHandle;
+                }
+                blob_slices.push(if len == 0 {
+                    &[]
+                } else {
+                    std::slice::from_raw_parts(blob, len)
+                });
+            }
+        }
+
+        match (*handle).manifest.reassemble(&blob_slices) {
+            Ok(payload) => {
+                write_bytes_out(payload, out_data, out_len);
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(code, format!("Reassembly failed: {}", e));
+                code
+            }
+        }
+    })
+}
+
+/// Opens the share store rooted at the directory `path`, creating it if needed.
+/// Returns null on failure.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_store_open(path: *const c_char) -> *mut ShareStoreHandle {
+    ffi_boundary(|| {
+        let path = match path_from_c_str(path) {
+            Ok(path) => path,
+            Err(_) => return ptr::null_mut(),
+        };
+
+        match ShareStore::open(path) {
+            Ok(store) => Box::into_raw(Box::new(ShareStoreHandle { store })),
+            Err(e) => {
+                set_error(
+                    NomosDaResult::from(&e),
+                    format!("Failed to open share store: {}", e),
+                );
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_store_free(handle: *mut ShareStoreHandle) {
+    ffi_boundary_keep_error(|| {
+        if !handle.is_null() {
+            let _ = Box::from_raw(handle);
+        }
+    })
+}
+
+/// Persists a share with its row commitments under its blob id and index,
+/// replacing any copy already stored
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_store_put(
+    store: *mut ShareStoreHandle,
+    share_handle: *mut ShareHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if store.is_null() || share_handle.is_null() {
+            if store.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Share store handle is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Share handle is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let share = &(*share_handle).share;
+        match (*store).store.put(share) {
+            Ok(()) => NomosDaResult::Success,
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(
+                    code,
+                    format!(
+                        "Failed to store share: {} (share_idx: {})",
+                        e, share.share_idx
+                    ),
+                );
+                code
+            }
+        }
+    })
+}
+
+/// Loads the share of `column` for the 32-byte `blob_id`. Returns
+/// `ErrorNotFound` when the store does not hold it.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_store_get(
+    store: *mut ShareStoreHandle,
+    blob_id: *const u8,
+    column: u16,
+    out_share_handle: *mut *mut ShareHandle,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if store.is_null() || blob_id.is_null() || out_share_handle.is_null() {
+            if store.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Share store handle is null (column: {})", column),
+                );
+            } else if blob_id.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Blob id pointer is null (column: {})", column),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Output share handle is null (column: {})", column),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let blob_id = &*(blob_id as *const [u8; BLOB_ID_SIZE]);
+        match (*store).store.get(blob_id, column) {
+            Ok(Some(share)) => {
+                *out_share_handle = into_shared(ShareHandle { share });
+                NomosDaResult::Success
+            }
+            Ok(None) => {
+                set_error(
+                    NomosDaResult::ErrorNotFound,
+                    format!("Share store holds no share for column {}", column),
+                );
+                NomosDaResult::ErrorNotFound
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(
+                    code,
+                    format!("Failed to load share: {} (column: {})", e, column),
+                );
+                code
+            }
+        }
+    })
+}
+
+/// Lists the column indices held for the 32-byte `blob_id` in ascending order.
+/// An unknown blob yields an empty list with a null `out_columns`. The output
+/// must be released with `nomos_da_store_columns_free`.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_store_list_columns(
+    store: *mut ShareStoreHandle,
+    blob_id: *const u8,
+    out_columns: *mut *mut u16,
+    out_count: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if store.is_null() || blob_id.is_null() || out_columns.is_null() || out_count.is_null() {
+            if store.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Share store handle is null".to_string(),
+                );
+            } else if blob_id.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Blob id pointer is null".to_string(),
+                );
+            } else if out_columns.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output columns pointer is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output count pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let blob_id = &*(blob_id as *const [u8; BLOB_ID_SIZE]);
+        match (*store).store.columns(blob_id) {
+            Ok(columns) => {
+                *out_count = columns.len();
+                *out_columns = if columns.is_empty() {
+                    ptr::null_mut()
+                } else {
+                    Box::into_raw(columns.into_boxed_slice()) as *mut u16
+                };
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(code, format!("Failed to list stored columns: {}", e));
+                code
+            }
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_store_columns_free(columns: *mut u16, count: CSizeT) {
+    ffi_boundary_keep_error(|| {
+        if !columns.is_null() && count > 0 {
+            let slice_ptr: *mut [u16] = ptr::slice_from_raw_parts_mut(columns, count);
+            let _ = Box::from_raw(slice_ptr);
+        }
+    })
+}
+
+/// Removes every share stored more than `max_age_secs` seconds ago and writes
+/// the number of removed shares into `out_removed`
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_store_prune(
+    store: *mut ShareStoreHandle,
+    max_age_secs: u64,
+    out_removed: *mut CSizeT,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if store.is_null() || out_removed.is_null() {
+            if store.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Share store handle is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output removed count pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        match (*store).store.prune(Duration::from_secs(max_age_secs)) {
+            Ok(removed) => {
+                *out_removed = removed;
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(
+                    code,
+                    format!(
+                        "Failed to prune share store: {} (max_age_secs: {})",
+                        e, max_age_secs
+                    ),
+                );
+                code
+            }
+        }
+    })
+}
+
+/// Deterministically picks `sample_count` distinct columns of the blob with the
+/// 32-byte `blob_id` for a node identified by `seed`, writing them in ascending
+/// order into `out_columns` (which must hold `sample_count` entries). The
+/// probability that the sample detects an unrecoverable blob is written into
+/// `out_confidence` when it is not null.
+///
+/// Columns are drawn the way a logos-blockchain node picks the subnetworks it
+/// samples, from a generator seeded by `blob_id` and `seed` instead of entropy.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_sample_columns(
+    blob_id: *const u8,
+    seed: *const u8,
+    seed_len: CSizeT,
+    column_count: CSizeT,
+    sample_count: CSizeT,
+    out_columns: *mut u16,
+    out_confidence: *mut f64,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if blob_id.is_null()
+            || (seed.is_null() && seed_len > 0)
+            || (out_columns.is_null() && sample_count > 0)
+        {
+            if blob_id.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Blob id pointer is null".to_string(),
+                );
+            } else if seed.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!("Seed pointer is null (seed_len: {})", seed_len),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    format!(
+                        "Output columns pointer is null (sample_count: {})",
+                        sample_count
+                    ),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let blob_id = &*(blob_id as *const [u8; BLOB_ID_SIZE]);
+        let seed: &[u8] = if seed_len == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(seed, seed_len)
+        };
+        let selected =
+            select_sample_columns(blob_id, seed, column_count, sample_count).and_then(|columns| {
+                sampling_confidence(column_count, sample_count)
+                    .map(|confidence| (columns, confidence))
+            });
+        match selected {
+            Ok((columns, confidence)) => {
+                if !columns.is_empty() {
+                    ptr::copy_nonoverlapping(columns.as_ptr(), out_columns, columns.len());
+                }
+                if !out_confidence.is_null() {
+                    *out_confidence = confidence;
+                }
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(
+                    code,
+                    format!(
+                        "Sample selection failed: {} (column_count: {}, sample_count: {})",
+                        e, column_count, sample_count
+                    ),
+                );
+                code
+            }
+        }
+    })
+}
+
+/// Writes into `out_confidence` the probability that `sample_count` distinct
+/// samples detect a blob withheld just enough to be unrecoverable
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_sampling_confidence(
+    column_count: CSizeT,
+    sample_count: CSizeT,
+    out_confidence: *mut f64,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if out_confidence.is_null() {
+            set_error(
+                NomosDaResult::ErrorNullHandle,
+                "Output confidence pointer is null".to_string(),
+            );
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        match sampling_confidence(column_count, sample_count) {
+            Ok(confidence) => {
+                *out_confidence = confidence;
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(
+                    code,
+                    format!(
+                        "Invalid sampling parameters: {} (column_count: {}, sample_count: {})",
+                        e, column_count, sample_count
+                    ),
+                );
+                code
+            }
+        }
+    })
+}
+
+/// Runs the sampling simulation described by `config` and writes the outcome
+/// into `out_report`. See the `simulation` module for the model.
+#[no_mangle]
+pub unsafe extern "C" fn nomos_da_simulate(
+    config: *const SimulationConfig,
+    out_report: *mut SimulationReport,
+) -> NomosDaResult {
+    ffi_boundary(|| {
+        if config.is_null() || out_report.is_null() {
+            if config.is_null() {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Simulation config pointer is null".to_string(),
+                );
+            } else {
+                set_error(
+                    NomosDaResult::ErrorNullHandle,
+                    "Output report pointer is null".to_string(),
+                );
+            }
+            return NomosDaResult::ErrorNullHandle;
+        }
+
+        let config = *config;
+        match Simulator::new(config).and_then(|simulator| simulator.run()) {
+            Ok(report) => {
+                *out_report = report;
+                NomosDaResult::Success
+            }
+            Err(e) => {
+                let code = NomosDaResult::from(&e);
+                set_error(
+                    code,
+                    format!(
+                        "Simulation failed: {} (column_count: {}, node_count: {})",
+                        e, config.column_count, config.node_count
+                    ),
+                );
+                code
+            }
+        }
+    })
+}
@@ -0,0 +1,85 @@
+//! Canonical padding for payloads of arbitrary length
+//!
+//! A padded payload is laid out as `[original length: u64 LE][payload][zeros]`,
+//! where the zeros extend the total length to a multiple of the encoding chunk
+//! size. Reconstruction may append further zero rows; `unpad` accepts those as
+//! long as every byte after the payload is zero.
+
+use std::fmt;
+
+use logos_blockchain_kzgrs_backend::encoder::DaEncoderParams;
+
+const CHUNK_SIZE: usize = DaEncoderParams::MAX_BLS12_381_ENCODING_CHUNK_SIZE;
+
+/// Size of the little-endian length header prepended to padded payloads
+pub const LENGTH_HEADER_SIZE: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaddingError {
+    MissingHeader { data_len: usize },
+    LengthOutOfBounds { payload_len: u64, available: usize },
+    NonZeroPadding { offset: usize },
+}
+
+impl fmt::Display for PaddingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHeader { data_len } => write!(
+                f,
+                "Padded data is too short to hold the length header (data_len: {}, header_size: {})",
+                data_len, LENGTH_HEADER_SIZE
+            ),
+            Self::LengthOutOfBounds { payload_len, available } => write!(
+                f,
+                "Recorded payload length {} exceeds the {} bytes available",
+                payload_len, available
+            ),
+            Self::NonZeroPadding { offset } => {
+                write!(f, "Padding byte at offset {} is not zero", offset)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PaddingError {}
+
+/// Frames `data` with its length and zero-pads it to a multiple of the chunk size
+pub fn pad(data: &[u8]) -> Vec<u8> {
+    let framed_len = LENGTH_HEADER_SIZE + data.len();
+    let padded_len = framed_len.div_ceil(CHUNK_SIZE) * CHUNK_SIZE;
+
+    let mut padded = Vec::with_capacity(padded_len);
+    padded.extend_from_slice(&(data.len() as u64).to_le_bytes());
+    padded.extend_from_slice(data);
+    padded.resize(padded_len, 0);
+    padded
+}
+
+/// Returns the original payload of data produced by `pad`
+pub fn unpad(padded: &[u8]) -> Result<&[u8], PaddingError> {
+    if padded.len() < LENGTH_HEADER_SIZE {
+        return Err(PaddingError::MissingHeader {
+            data_len: padded.len(),
+        });
+    }
+
+    let (header, body) = padded.split_at(LENGTH_HEADER_SIZE);
+    let payload_len = u64::from_le_bytes(header.try_into().expect("header is 8 bytes"));
+    let available = body.len();
+    let payload_len_usize = usize::try_from(payload_len)
+        .ok()
+        .filter(|len| *len <= available)
+        .ok_or(PaddingError::LengthOutOfBounds {
+            payload_len,
+            available,
+        })?;
+
+    let (payload, padding) = body.split_at(payload_len_usize);
+    if let Some(position) = padding.iter().position(|byte| *byte != 0) {
+        return Err(PaddingError::NonZeroPadding {
+            offset: LENGTH_HEADER_SIZE + payload_len_usize + position,
+        });
+    }
+
+    Ok(payload)
+}
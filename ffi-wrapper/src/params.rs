@@ -0,0 +1,73 @@
+//! Loading and exporting KZG global parameters and verification keys
+//!
+//! Both are stored in the compressed ark-serialize encoding, which is also what
+//! `to_bytes` produces, so exported parameters can be loaded back unchanged.
+
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+
+#[derive(Debug)]
+pub enum ParamsError {
+    Empty,
+    Io { path: String, source: io::Error },
+    Deserialization(SerializationError),
+    TrailingBytes { consumed: usize, total: usize },
+}
+
+impl fmt::Display for ParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Parameter data is empty"),
+            Self::Io { path, source } => {
+                write!(f, "Failed to read parameters from {}: {}", path, source)
+            }
+            Self::Deserialization(e) => write!(f, "Invalid parameter encoding: {}", e),
+            Self::TrailingBytes { consumed, total } => write!(
+                f,
+                "Parameter data has {} trailing bytes (consumed: {}, total: {})",
+                total - consumed,
+                consumed,
+                total
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParamsError {}
+
+/// Decodes compressed parameters, rejecting empty input and trailing bytes
+pub fn from_bytes<T: CanonicalDeserialize>(bytes: &[u8]) -> Result<T, ParamsError> {
+    if bytes.is_empty() {
+        return Err(ParamsError::Empty);
+    }
+
+    let mut reader = bytes;
+    let params = T::deserialize_compressed(&mut reader).map_err(ParamsError::Deserialization)?;
+    if !reader.is_empty() {
+        return Err(ParamsError::TrailingBytes {
+            consumed: bytes.len() - reader.len(),
+            total: bytes.len(),
+        });
+    }
+
+    Ok(params)
+}
+
+/// Reads and decodes compressed parameters from a file
+pub fn from_file<T: CanonicalDeserialize>(path: &Path) -> Result<T, ParamsError> {
+    let bytes = std::fs::read(path).map_err(|source| ParamsError::Io {
+        path: path.display().to_string(),
+        source,
+    })?;
+    from_bytes(&bytes)
+}
+
+/// Encodes parameters in the compressed form accepted by `from_bytes`
+pub fn to_bytes<T: CanonicalSerialize>(params: &T) -> Result<Vec<u8>, SerializationError> {
+    let mut bytes = Vec::with_capacity(params.compressed_size());
+    params.serialize_compressed(&mut bytes)?;
+    Ok(bytes)
+}
@@ -0,0 +1,268 @@
+//! Reconstruction of the original payload from any sufficient subset of shares
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+use ark_ff::{BigInteger, PrimeField};
+use ark_poly::EvaluationDomain;
+use logos_blockchain_core::da::DaEncoder as _;
+use logos_blockchain_kzgrs::{rs, FieldElement, PolynomialEvaluationDomain};
+use logos_blockchain_kzgrs_backend::{
+    common::{share::DaShare, Chunk, ChunksMatrix, Row},
+    encoder::{DaEncoder, DaEncoderParams, EncodedData},
+};
+
+const CHUNK_SIZE: usize = DaEncoderParams::MAX_BLS12_381_ENCODING_CHUNK_SIZE;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReconstructionError {
+    NoShares,
+    InvalidColumnCount(usize),
+    IndexOutOfRange {
+        share_idx: u16,
+        column_count: usize,
+    },
+    CommitmentsMismatch {
+        share_idx: u16,
+    },
+    ColumnLengthMismatch {
+        share_idx: u16,
+        expected: usize,
+        actual: usize,
+    },
+    InsufficientShares {
+        distinct: usize,
+        required: usize,
+    },
+    DuplicateIndex {
+        share_idx: u16,
+    },
+    Encoding(String),
+    ReencodingMismatch {
+        mismatched_rows: usize,
+        rows: usize,
+    },
+}
+
+impl fmt::Display for ReconstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoShares => write!(f, "No shares supplied"),
+            Self::InvalidColumnCount(column_count) => write!(
+                f,
+                "Column count must be an even power of two, got {}",
+                column_count
+            ),
+            Self::IndexOutOfRange { share_idx, column_count } => write!(
+                f,
+                "Share index {} is out of range for {} columns",
+                share_idx, column_count
+            ),
+            Self::CommitmentsMismatch { share_idx } => write!(
+                f,
+                "Share {} carries row commitments that differ from the other shares",
+                share_idx
+            ),
+            Self::ColumnLengthMismatch { share_idx, expected, actual } => write!(
+                f,
+                "Share {} has {} rows but {} were expected",
+                share_idx, actual, expected
+            ),
+            Self::InsufficientShares { distinct, required } => write!(
+                f,
+                "Insufficient shares: {} distinct indices supplied, at least {} required",
+                distinct, required
+            ),
+            Self::DuplicateIndex { share_idx } => {
+                write!(f, "Share index {} is supplied more than once", share_idx)
+            }
+            Self::Encoding(e) => write!(f, "Re-encoding the reconstructed data failed: {}", e),
+            Self::ReencodingMismatch { mismatched_rows, rows } => write!(
+                f,
+                "Re-encoded data does not match the row commitments of the shares ({} of {} rows differ)",
+                mismatched_rows, rows
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReconstructionError {}
+
+fn chunk_to_point(chunk: &[u8]) -> FieldElement {
+    FieldElement::from_le_bytes_mod_order(chunk)
+}
+
+fn point_to_chunk(point: &FieldElement) -> Vec<u8> {
+    let mut bytes = point.into_bigint().to_bytes_le();
+    bytes.truncate(CHUNK_SIZE);
+    bytes
+}
+
+/// Rebuilds the original (row-padded) payload from at least `column_count / 2`
+/// shares with distinct indices.
+///
+/// When every original column is present the chunks are read back directly,
+/// otherwise each row is Reed-Solomon decoded from the available evaluations.
+pub fn reconstruct_with_missing_data(
+    shares: &[&DaShare],
+    column_count: usize,
+) -> Result<Vec<u8>, ReconstructionError> {
+    let first = shares.first().ok_or(ReconstructionError::NoShares)?;
+    let domain = PolynomialEvaluationDomain::new(column_count)
+        .filter(|domain| column_count >= 2 && domain.size() == column_count)
+        .ok_or(ReconstructionError::InvalidColumnCount(column_count))?;
+
+    let rows = first.column.0.len();
+    let mut by_index: BTreeMap<u16, &DaShare> = BTreeMap::new();
+    for &share in shares {
+        if share.share_idx as usize >= column_count {
+            return Err(ReconstructionError::IndexOutOfRange {
+                share_idx: share.share_idx,
+                column_count,
+            });
+        }
+        if share.rows_commitments != first.rows_commitments {
+            return Err(ReconstructionError::CommitmentsMismatch {
+                share_idx: share.share_idx,
+            });
+        }
+        if share.column.0.len() != rows {
+            return Err(ReconstructionError::ColumnLengthMismatch {
+                share_idx: share.share_idx,
+                expected: rows,
+                actual: share.column.0.len(),
+            });
+        }
+        by_index.entry(share.share_idx).or_insert(share);
+    }
+
+    let required = column_count / 2;
+    if by_index.len() < required {
+        return Err(ReconstructionError::InsufficientShares {
+            distinct: by_index.len(),
+            required,
+        });
+    }
+
+    // Shares are ordered by index, so the original columns come first when present
+    let selected: Vec<&DaShare> = by_index.values().take(required).copied().collect();
+    let systematic = selected
+        .iter()
+        .enumerate()
+        .all(|(i, share)| share.share_idx as usize == i);
+
+    let mut data = Vec::with_capacity(rows * required * CHUNK_SIZE);
+    for row in 0..rows {
+        if systematic {
+            for share in &selected {
+                let point = chunk_to_point(&share.column.0[row].0);
+                data.extend_from_slice(&point_to_chunk(&point));
+            }
+        } else {
+            let mut points: Vec<Option<FieldElement>> = vec![None; column_count];
+            for share in &selected {
+                points[share.share_idx as usize] = Some(chunk_to_point(&share.column.0[row].0));
+            }
+            let evaluations = rs::decode(required, &points, domain);
+            for point in evaluations.evals.iter() {
+                data.extend_from_slice(&point_to_chunk(point));
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+/// Reconstructs like `reconstruct_with_missing_data`, then re-encodes the result
+/// with `encoder` and checks that it commits to the same rows as the shares.
+///
+/// Shares can agree with each other and still carry commitments that do not
+/// belong to the data they hold; this catches that case. The encoder must use
+/// the parameters the blob was encoded with, or every blob is a mismatch.
+pub fn reconstruct_and_verify(
+    encoder: &DaEncoder,
+    shares: &[&DaShare],
+    column_count: usize,
+) -> Result<Vec<u8>, ReconstructionError> {
+    let data = reconstruct_with_missing_data(shares, column_count)?;
+    let reencoded = encoder
+        .encode(&data)
+        .map_err(|e| ReconstructionError::Encoding(format!("{:?}", e)))?;
+
+    let claimed = &shares[0].rows_commitments;
+    let rows = claimed.len().max(reencoded.row_commitments.len());
+    let mismatched_rows = (0..rows)
+        .filter(|&row| claimed.get(row) != reencoded.row_commitments.get(row))
+        .count();
+    if mismatched_rows > 0 {
+        return Err(ReconstructionError::ReencodingMismatch {
+            mismatched_rows,
+            rows,
+        });
+    }
+
+    Ok(data)
+}
+
+/// Rebuilds the encoded data of a blob from exactly one share per column.
+///
+/// The extended matrix, proofs and row commitments are taken from the shares
+/// as they are; the original data is read back from the first half of the
+/// columns, so it includes any zero chunks the encoder added to fill the last
+/// row.
+pub fn assemble_encoded_data(
+    shares: &[&DaShare],
+    column_count: usize,
+) -> Result<EncodedData, ReconstructionError> {
+    let mut seen = HashSet::with_capacity(shares.len());
+    if let Some(duplicate) = shares.iter().find(|share| !seen.insert(share.share_idx)) {
+        return Err(ReconstructionError::DuplicateIndex {
+            share_idx: duplicate.share_idx,
+        });
+    }
+    if shares.len() < column_count {
+        return Err(ReconstructionError::InsufficientShares {
+            distinct: shares.len(),
+            required: column_count,
+        });
+    }
+
+    // Checks the column count, index range, commitments and column lengths
+    let data = reconstruct_with_missing_data(shares, column_count)?;
+
+    let mut ordered = shares.to_vec();
+    ordered.sort_unstable_by_key(|share| share.share_idx);
+    let rows = ordered[0].column.0.len();
+
+    let extended_data = ChunksMatrix(
+        (0..rows)
+            .map(|row| {
+                Row(ordered
+                    .iter()
+                    .map(|share| share.column.0[row].clone())
+                    .collect())
+            })
+            .collect(),
+    );
+    let chunked_data = ChunksMatrix(
+        data.chunks(CHUNK_SIZE * column_count / 2)
+            .map(|row| {
+                Row(row
+                    .chunks(CHUNK_SIZE)
+                    .map(|chunk| Chunk(chunk.to_vec()))
+                    .collect())
+            })
+            .collect(),
+    );
+
+    Ok(EncodedData {
+        data,
+        chunked_data,
+        extended_data,
+        row_commitments: ordered[0].rows_commitments.clone(),
+        combined_column_proofs: ordered
+            .iter()
+            .map(|share| share.combined_column_proof)
+            .collect(),
+    })
+}
@@ -0,0 +1,120 @@
+//! Deterministic selection of the columns a light node samples
+//!
+//! Selection follows the `KzgrsSamplingBackend` of logos-blockchain: a node
+//! samples one column per subnetwork, with subnetwork `i` serving column `i`,
+//! and picks its subnetworks by drawing `sample_count` of `0..column_count`
+//! with `IteratorRandom::choose_multiple` from a `ChaCha20Rng`. The node seeds
+//! that generator once from entropy; here it is seeded per blob from a hash of
+//! the blob id and a node-local seed, so a given node can reproduce its picks
+//! for a blob while different nodes (or seeds) spread their samples
+//! independently. The known-answer vectors in the tests pin the draw.
+
+use std::fmt;
+
+use blake2::{digest::consts::U32, Blake2b, Digest};
+use rand::{seq::IteratorRandom, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+use crate::BLOB_ID_SIZE;
+
+/// Prefix of the hash input, so sampling seeds never collide with other hashes
+/// over blob ids
+const SAMPLING_DOMAIN_TAG: &[u8] = b"NOMOS_DA_SAMPLING";
+
+/// Largest column count whose indices fit the `u16` share index
+const MAX_COLUMN_COUNT: usize = u16::MAX as usize + 1;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SamplingError {
+    InvalidColumnCount(usize),
+    TooManySamples {
+        sample_count: usize,
+        column_count: usize,
+    },
+}
+
+impl fmt::Display for SamplingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidColumnCount(column_count) => write!(
+                f,
+                "Column count must be even and between 2 and {}, got {}",
+                MAX_COLUMN_COUNT, column_count
+            ),
+            Self::TooManySamples {
+                sample_count,
+                column_count,
+            } => write!(
+                f,
+                "Cannot sample {} distinct columns out of {}",
+                sample_count, column_count
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SamplingError {}
+
+fn check_column_count(column_count: usize) -> Result<(), SamplingError> {
+    if column_count < 2 || column_count % 2 != 0 || column_count > MAX_COLUMN_COUNT {
+        return Err(SamplingError::InvalidColumnCount(column_count));
+    }
+    Ok(())
+}
+
+fn sampling_rng(blob_id: &[u8; BLOB_ID_SIZE], seed: &[u8]) -> ChaCha20Rng {
+    let mut hasher = Blake2b::<U32>::new();
+    hasher.update(SAMPLING_DOMAIN_TAG);
+    hasher.update(blob_id);
+    hasher.update((seed.len() as u64).to_le_bytes());
+    hasher.update(seed);
+    ChaCha20Rng::from_seed(hasher.finalize().into())
+}
+
+/// Picks `sample_count` distinct column indices of the blob, in ascending order
+pub fn select_sample_columns(
+    blob_id: &[u8; BLOB_ID_SIZE],
+    seed: &[u8],
+    column_count: usize,
+    sample_count: usize,
+) -> Result<Vec<u16>, SamplingError> {
+    check_column_count(column_count)?;
+    if sample_count > column_count {
+        return Err(SamplingError::TooManySamples {
+            sample_count,
+            column_count,
+        });
+    }
+
+    let mut rng = sampling_rng(blob_id, seed);
+    let mut columns = (0..column_count)
+        .map(|column| column as u16)
+        .choose_multiple(&mut rng, sample_count);
+    columns.sort_unstable();
+    Ok(columns)
+}
+
+/// Probability that `sample_count` distinct samples hit at least one withheld
+/// column when the blob cannot be reconstructed.
+///
+/// Reconstruction needs half of the columns, so an adversary hiding the data
+/// must withhold at least `column_count / 2 + 1` of them. The result is the
+/// complement of every sample landing among the remaining available columns.
+pub fn sampling_confidence(column_count: usize, sample_count: usize) -> Result<f64, SamplingError> {
+    check_column_count(column_count)?;
+    if sample_count > column_count {
+        return Err(SamplingError::TooManySamples {
+            sample_count,
+            column_count,
+        });
+    }
+
+    let available = column_count / 2 - 1;
+    if sample_count > available {
+        return Ok(1.0);
+    }
+    let miss_probability: f64 = (0..sample_count)
+        .map(|i| (available - i) as f64 / (column_count - i) as f64)
+        .product();
+    Ok(1.0 - miss_probability)
+}
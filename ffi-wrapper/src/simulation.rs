@@ -0,0 +1,260 @@
+//! In-process DA sampling simulator
+//!
+//! A random payload is encoded once and its shares are spread over simulated
+//! storage nodes, each holding a random subset of columns. Adversarial nodes
+//! either withhold their shares or serve them with a corrupted column. Light
+//! clients pick their samples with `select_sample_columns` and ask the holders
+//! of each sampled column until one returns a share that verifies; a client
+//! detects unavailability when some sampled column has no valid share. The
+//! report also says whether the honestly served shares reconstruct the payload.
+
+use std::collections::HashSet;
+use std::fmt;
+
+use logos_blockchain_core::da::{blob::Share as _, DaEncoder as _};
+use logos_blockchain_kzgrs::KzgRsError;
+use logos_blockchain_kzgrs_backend::{
+    common::share::DaShare,
+    encoder::{DaEncoder, DaEncoderParams, EncodedData},
+    kzg_keys::VERIFICATION_KEY,
+    verifier::DaVerifier,
+};
+use rand::{seq::index, Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+use crate::padding::{pad, unpad};
+use crate::reconstruction::reconstruct_with_missing_data;
+use crate::sampling::{sampling_confidence, select_sample_columns, SamplingError};
+use crate::verify_light_share;
+
+/// How adversarial storage nodes treat requests for their shares
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdversaryBehavior {
+    /// Never answer
+    Withhold = 0,
+    /// Answer with a share whose column was tampered with
+    Corrupt = 1,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct SimulationConfig {
+    /// Number of columns of the extended data
+    pub column_count: usize,
+    /// Size in bytes of the random payload that is encoded
+    pub payload_size: usize,
+    pub node_count: usize,
+    /// Distinct columns held by each storage node
+    pub columns_per_node: usize,
+    /// Storage nodes, picked at random, that follow `behavior`
+    pub adversarial_nodes: usize,
+    pub behavior: AdversaryBehavior,
+    pub light_clients: usize,
+    /// Distinct columns each light client samples
+    pub samples_per_client: usize,
+    /// Seed for the payload, column placement, adversaries and client seeds
+    pub seed: u64,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SimulationReport {
+    /// Columns for which at least one node serves a valid share
+    pub available_columns: usize,
+    /// Light clients that found a sampled column without a valid share
+    pub detecting_clients: usize,
+    /// `detecting_clients / light_clients`, 0 without light clients
+    pub detection_rate: f64,
+    /// Detection probability predicted by `sampling_confidence` for an
+    /// adversary withholding just enough columns to prevent reconstruction
+    pub expected_confidence: f64,
+    /// The served shares reconstruct the original payload
+    pub reconstructed: bool,
+}
+
+#[derive(Debug)]
+pub enum SimulationError {
+    InvalidConfig(String),
+    Sampling(SamplingError),
+    Encoding(KzgRsError),
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidConfig(reason) => write!(f, "Invalid simulation config: {}", reason),
+            Self::Sampling(e) => write!(f, "{}", e),
+            Self::Encoding(e) => write!(f, "Encoding failed: {:?}", e),
+        }
+    }
+}
+
+impl std::error::Error for SimulationError {}
+
+impl From<SamplingError> for SimulationError {
+    fn from(err: SamplingError) -> Self {
+        Self::Sampling(err)
+    }
+}
+
+fn check_config(config: &SimulationConfig) -> Result<(), SimulationError> {
+    let invalid = |reason: String| Err(SimulationError::InvalidConfig(reason));
+    if config.node_count == 0 {
+        return invalid("node_count must be greater than 0".to_string());
+    }
+    if config.columns_per_node == 0 || config.columns_per_node > config.column_count {
+        return invalid(format!(
+            "columns_per_node must be between 1 and {}, got {}",
+            config.column_count, config.columns_per_node
+        ));
+    }
+    if config.adversarial_nodes > config.node_count {
+        return invalid(format!(
+            "adversarial_nodes ({}) exceeds node_count ({})",
+            config.adversarial_nodes, config.node_count
+        ));
+    }
+    Ok(())
+}
+
+fn corrupt(mut share: DaShare) -> DaShare {
+    if let Some(byte) = share
+        .column
+        .0
+        .first_mut()
+        .and_then(|chunk| chunk.0.first_mut())
+    {
+        *byte ^= 1;
+    }
+    share
+}
+
+struct StorageNode {
+    columns: HashSet<usize>,
+    adversarial: bool,
+}
+
+pub struct Simulator {
+    config: SimulationConfig,
+    verifier: DaVerifier,
+    payload: Vec<u8>,
+    encoded: EncodedData,
+    nodes: Vec<StorageNode>,
+}
+
+impl Simulator {
+    /// Encodes the payload and places the columns on the storage nodes
+    pub fn new(config: SimulationConfig) -> Result<Self, SimulationError> {
+        // Validates the column count and sample count before anything is encoded
+        sampling_confidence(config.column_count, config.samples_per_client)?;
+        check_config(&config)?;
+
+        let mut rng = ChaCha20Rng::seed_from_u64(config.seed);
+        let payload: Vec<u8> = (0..config.payload_size).map(|_| rng.gen()).collect();
+        let encoder = DaEncoder::new(DaEncoderParams::default_with(config.column_count));
+        let encoded = encoder
+            .encode(&pad(&payload))
+            .map_err(SimulationError::Encoding)?;
+
+        let adversaries: HashSet<usize> =
+            index::sample(&mut rng, config.node_count, config.adversarial_nodes)
+                .into_iter()
+                .collect();
+        let nodes = (0..config.node_count)
+            .map(|node| StorageNode {
+                columns: index::sample(&mut rng, config.column_count, config.columns_per_node)
+                    .into_iter()
+                    .collect(),
+                adversarial: adversaries.contains(&node),
+            })
+            .collect();
+
+        Ok(Self {
+            config,
+            verifier: DaVerifier::new(VERIFICATION_KEY.clone()),
+            payload,
+            encoded,
+            nodes,
+        })
+    }
+
+    /// Share of `column` served by `node`, `None` if it holds or serves nothing
+    fn serve(&self, node: &StorageNode, column: usize) -> Option<DaShare> {
+        if !node.columns.contains(&column) {
+            return None;
+        }
+        let share = self.encoded.to_da_share(column)?;
+        match (node.adversarial, self.config.behavior) {
+            (false, _) => Some(share),
+            (true, AdversaryBehavior::Withhold) => None,
+            (true, AdversaryBehavior::Corrupt) => Some(corrupt(share)),
+        }
+    }
+
+    /// First share of `column` that verifies, asking the nodes in order
+    fn fetch_valid(&self, column: usize) -> Option<DaShare> {
+        self.nodes
+            .iter()
+            .filter_map(|node| self.serve(node, column))
+            .find(|share| {
+                let (light_share, commitments) = share.clone().into_share_and_commitments();
+                verify_light_share(
+                    &self.verifier,
+                    &light_share,
+                    &commitments,
+                    self.config.column_count,
+                )
+                .is_ok()
+            })
+    }
+
+    pub fn run(&self) -> Result<SimulationReport, SimulationError> {
+        let column_count = self.config.column_count;
+        let available: Vec<Option<DaShare>> = (0..column_count)
+            .map(|column| self.fetch_valid(column))
+            .collect();
+        let blob_id = self
+            .encoded
+            .to_da_share(0)
+            .map(|share| share.blob_id())
+            .ok_or_else(|| {
+                SimulationError::InvalidConfig("Encoding produced no shares".to_string())
+            })?;
+
+        let mut detecting_clients = 0;
+        for client in 0..self.config.light_clients {
+            let mut seed = self.config.seed.to_le_bytes().to_vec();
+            seed.extend_from_slice(&(client as u64).to_le_bytes());
+            let samples = select_sample_columns(
+                &blob_id,
+                &seed,
+                column_count,
+                self.config.samples_per_client,
+            )?;
+            if samples
+                .iter()
+                .any(|&column| available[column as usize].is_none())
+            {
+                detecting_clients += 1;
+            }
+        }
+
+        let served: Vec<&DaShare> = available.iter().flatten().collect();
+        let reconstructed = reconstruct_with_missing_data(&served, column_count)
+            .ok()
+            .is_some_and(|padded| unpad(&padded).is_ok_and(|payload| payload == self.payload));
+
+        Ok(SimulationReport {
+            available_columns: served.len(),
+            detecting_clients,
+            detection_rate: if self.config.light_clients == 0 {
+                0.0
+            } else {
+                detecting_clients as f64 / self.config.light_clients as f64
+            },
+            expected_confidence: sampling_confidence(column_count, self.config.samples_per_client)?,
+            reconstructed,
+        })
+    }
+}
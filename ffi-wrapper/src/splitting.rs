@@ -0,0 +1,217 @@
+//! Splitting of payloads larger than one blob
+//!
+//! A payload is cut into consecutive pieces that each fit in `max_blob_size`
+//! bytes once framed with `pad`, so every blob carries its own length header and
+//! is unpadded on its own. The manifest lists the blobs in payload order with
+//! their ids and the number of payload and padding bytes each holds, which is
+//! all that is needed to put the payload back together from the reconstructed
+//! blobs.
+
+use std::fmt;
+
+use logos_blockchain_kzgrs_backend::encoder::DaEncoderParams;
+use serde::{Deserialize, Serialize};
+
+use crate::padding::{unpad, PaddingError, LENGTH_HEADER_SIZE};
+use crate::BLOB_ID_SIZE;
+
+const CHUNK_SIZE: usize = DaEncoderParams::MAX_BLS12_381_ENCODING_CHUNK_SIZE;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SplitError {
+    InvalidMaxBlobSize(usize),
+    BlobCountMismatch {
+        expected: usize,
+        got: usize,
+    },
+    BlobLengthMismatch {
+        index: usize,
+        expected: u64,
+        got: usize,
+    },
+    Padding {
+        index: usize,
+        source: PaddingError,
+    },
+    PayloadLengthMismatch {
+        expected: u64,
+        got: usize,
+    },
+    EntryTooLarge {
+        index: usize,
+        payload_len: u64,
+        capacity: usize,
+    },
+    InconsistentManifest {
+        payload_len: u64,
+        entries_total: Option<u64>,
+    },
+}
+
+impl fmt::Display for SplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMaxBlobSize(max_blob_size) => write!(
+                f,
+                "Max blob size must be a positive multiple of chunk size (max_blob_size: {}, chunk_size: {})",
+                max_blob_size, CHUNK_SIZE
+            ),
+            Self::BlobCountMismatch { expected, got } => write!(
+                f,
+                "Manifest lists {} blobs, got {}",
+                expected, got
+            ),
+            Self::BlobLengthMismatch { index, expected, got } => write!(
+                f,
+                "Blob {} holds {} payload bytes, manifest expects {}",
+                index, got, expected
+            ),
+            Self::Padding { index, source } => write!(f, "Blob {}: {}", index, source),
+            Self::PayloadLengthMismatch { expected, got } => write!(
+                f,
+                "Reassembled payload is {} bytes, manifest expects {}",
+                got, expected
+            ),
+            Self::EntryTooLarge { index, payload_len, capacity } => write!(
+                f,
+                "Manifest entry {} claims {} payload bytes, more than a blob holds (capacity: {})",
+                index, payload_len, capacity
+            ),
+            Self::InconsistentManifest { payload_len, entries_total: Some(total) } => write!(
+                f,
+                "Manifest records {} payload bytes but its blobs add up to {}",
+                payload_len, total
+            ),
+            Self::InconsistentManifest { payload_len, entries_total: None } => write!(
+                f,
+                "Manifest records {} payload bytes but the lengths of its blobs overflow",
+                payload_len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SplitError {}
+
+/// Payload bytes that fit in one blob of `max_blob_size` encoded bytes
+pub fn blob_capacity(max_blob_size: usize) -> Result<usize, SplitError> {
+    if max_blob_size == 0 || max_blob_size % CHUNK_SIZE != 0 {
+        return Err(SplitError::InvalidMaxBlobSize(max_blob_size));
+    }
+    Ok(max_blob_size - LENGTH_HEADER_SIZE)
+}
+
+/// Number of blobs a payload of `payload_len` bytes is split into; an empty
+/// payload still takes one blob
+pub fn blob_count(payload_len: usize, max_blob_size: usize) -> Result<usize, SplitError> {
+    let capacity = blob_capacity(max_blob_size)?;
+    Ok(payload_len.div_ceil(capacity).max(1))
+}
+
+/// Consecutive pieces of `payload`, each small enough to be padded into a blob
+/// of at most `max_blob_size` bytes
+pub fn split_payload(payload: &[u8], max_blob_size: usize) -> Result<Vec<&[u8]>, SplitError> {
+    let capacity = blob_capacity(max_blob_size)?;
+    if payload.is_empty() {
+        return Ok(vec![payload]);
+    }
+    Ok(payload.chunks(capacity).collect())
+}
+
+/// One blob of a split payload, as exposed across the FFI
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlobManifestEntry {
+    pub blob_id: [u8; BLOB_ID_SIZE],
+    /// Payload bytes carried by the blob
+    pub payload_len: u64,
+    /// Zero bytes appended after the payload to reach a chunk boundary
+    pub padding: u64,
+}
+
+impl BlobManifestEntry {
+    pub fn new(blob_id: [u8; BLOB_ID_SIZE], payload_len: usize) -> Self {
+        let framed_len = LENGTH_HEADER_SIZE + payload_len;
+        let padded_len = framed_len.div_ceil(CHUNK_SIZE) * CHUNK_SIZE;
+        Self {
+            blob_id,
+            payload_len: payload_len as u64,
+            padding: (padded_len - framed_len) as u64,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlobManifest {
+    /// Limit the payload was split under
+    pub max_blob_size: u64,
+    pub payload_len: u64,
+    /// Blobs in payload order
+    pub blobs: Vec<BlobManifestEntry>,
+}
+
+impl BlobManifest {
+    /// Checks that the lengths the manifest records agree with each other: every
+    /// entry fits a blob of `max_blob_size` and the entries add up to
+    /// `payload_len`. A manifest read from untrusted bytes must pass this before
+    /// any of its lengths is trusted.
+    pub fn validate(&self) -> Result<(), SplitError> {
+        let max_blob_size = usize::try_from(self.max_blob_size)
+            .map_err(|_| SplitError::InvalidMaxBlobSize(usize::MAX))?;
+        let capacity = blob_capacity(max_blob_size)?;
+
+        let mut entries_total: Option<u64> = Some(0);
+        for (index, entry) in self.blobs.iter().enumerate() {
+            if entry.payload_len > capacity as u64 {
+                return Err(SplitError::EntryTooLarge {
+                    index,
+                    payload_len: entry.payload_len,
+                    capacity,
+                });
+            }
+            entries_total = entries_total.and_then(|total| total.checked_add(entry.payload_len));
+        }
+
+        if entries_total != Some(self.payload_len) {
+            return Err(SplitError::InconsistentManifest {
+                payload_len: self.payload_len,
+                entries_total,
+            });
+        }
+        Ok(())
+    }
+
+    /// Concatenates the payloads of the blobs, given in manifest order as
+    /// returned by reconstruction (padded, possibly with trailing zero rows)
+    pub fn reassemble(&self, blobs: &[&[u8]]) -> Result<Vec<u8>, SplitError> {
+        self.validate()?;
+        if blobs.len() != self.blobs.len() {
+            return Err(SplitError::BlobCountMismatch {
+                expected: self.blobs.len(),
+                got: blobs.len(),
+            });
+        }
+
+        // Sized from the blobs actually supplied, never from the recorded lengths
+        let mut payload = Vec::with_capacity(blobs.iter().map(|blob| blob.len()).sum());
+        for (index, (blob, entry)) in blobs.iter().zip(&self.blobs).enumerate() {
+            let piece = unpad(blob).map_err(|source| SplitError::Padding { index, source })?;
+            if piece.len() as u64 != entry.payload_len {
+                return Err(SplitError::BlobLengthMismatch {
+                    index,
+                    expected: entry.payload_len,
+                    got: piece.len(),
+                });
+            }
+            payload.extend_from_slice(piece);
+        }
+
+        if payload.len() as u64 != self.payload_len {
+            return Err(SplitError::PayloadLengthMismatch {
+                expected: self.payload_len,
+                got: payload.len(),
+            });
+        }
+        Ok(payload)
+    }
+}
@@ -0,0 +1,300 @@
+//! On-disk share store keyed by (blob id, column index)
+//!
+//! Each blob gets a directory named after its hex-encoded blob id, holding one
+//! `<column>.share` file per stored column in the node wire format. A share
+//! carries its row commitments, so every file is self-contained. Files are
+//! written to a temporary name unique to the put, synced and renamed into
+//! place, so neither a crash nor a concurrent put of the same share ever leaves
+//! a truncated share behind. The modification time of a share file is the time
+//! it was last put, which is what `prune` compares against. `prune` only ever
+//! removes share files and the temporary files of puts, and a put that loses
+//! its blob directory to a concurrent `prune` recreates it and tries again.
+
+use std::ffi::OsStr;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime};
+
+use logos_blockchain_core::{da::blob::Share as _, wire};
+use logos_blockchain_kzgrs_backend::common::share::DaShare;
+
+use crate::{wire_deserialize, wire_serialize, BLOB_ID_SIZE};
+
+const SHARE_EXTENSION: &str = "share";
+const TEMP_EXTENSION: &str = "tmp";
+/// Attempts `put` makes when a concurrent `prune` removes the blob directory
+/// or temporary file out from under it
+const PUT_ATTEMPTS: usize = 8;
+
+/// Distinguishes the temporary files of puts made by this process
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug)]
+pub enum StoreError {
+    Io { path: PathBuf, source: io::Error },
+    Serialization(wire::Error),
+    Corrupt { path: PathBuf, reason: String },
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Self::Serialization(e) => write!(f, "Share serialization error: {:?}", e),
+            Self::Corrupt { path, reason } => {
+                write!(f, "Stored share {} is corrupt: {}", path.display(), reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+fn io_error(path: &Path) -> impl FnOnce(io::Error) -> StoreError + '_ {
+    move |source| StoreError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+fn blob_dir_name(blob_id: &[u8; BLOB_ID_SIZE]) -> String {
+    blob_id.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn column_from_file_name(path: &Path) -> Option<u16> {
+    if path.extension()? != SHARE_EXTENSION {
+        return None;
+    }
+    path.file_stem()?.to_str()?.parse().ok()
+}
+
+/// Whether `path` is the temporary file of a put, `<column>.share.<pid>.<n>.tmp`
+fn is_temp_file_name(path: &Path) -> bool {
+    if !path.extension().is_some_and(|ext| ext == TEMP_EXTENSION) {
+        return false;
+    }
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let mut parts = name.split('.');
+    parts
+        .next()
+        .is_some_and(|column| column.parse::<u16>().is_ok())
+        && parts.next() == Some(SHARE_EXTENSION)
+}
+
+fn is_blob_dir_name(name: &OsStr) -> bool {
+    name.to_str().is_some_and(|name| {
+        name.len() == BLOB_ID_SIZE * 2
+            && name
+                .bytes()
+                .all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+    })
+}
+
+fn is_not_found(e: &io::Error) -> bool {
+    e.kind() == io::ErrorKind::NotFound
+}
+
+pub struct ShareStore {
+    root: PathBuf,
+}
+
+impl ShareStore {
+    /// Opens the store rooted at `root`, creating the directory if needed
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self, StoreError> {
+        let root = root.into();
+        fs::create_dir_all(&root).map_err(io_error(&root))?;
+        Ok(Self { root })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn blob_dir(&self, blob_id: &[u8; BLOB_ID_SIZE]) -> PathBuf {
+        self.root.join(blob_dir_name(blob_id))
+    }
+
+    fn share_path(&self, blob_id: &[u8; BLOB_ID_SIZE], column: u16) -> PathBuf {
+        self.blob_dir(blob_id)
+            .join(format!("{:05}.{}", column, SHARE_EXTENSION))
+    }
+
+    /// Temporary path for one put of `column`, not shared with any other put
+    /// from this or another process
+    fn temp_path(&self, blob_id: &[u8; BLOB_ID_SIZE], column: u16) -> PathBuf {
+        self.blob_dir(blob_id).join(format!(
+            "{:05}.{}.{}.{}.{}",
+            column,
+            SHARE_EXTENSION,
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed),
+            TEMP_EXTENSION
+        ))
+    }
+
+    /// Stores `share` under its blob id and index, replacing any previous copy
+    pub fn put(&self, share: &DaShare) -> Result<(), StoreError> {
+        let blob_id = share.blob_id();
+        let bytes = wire_serialize(share).map_err(StoreError::Serialization)?;
+        let mut attempt = 1;
+        loop {
+            match self.write_share(&blob_id, share.share_idx, &bytes) {
+                Err(StoreError::Io { source, .. })
+                    if is_not_found(&source) && attempt < PUT_ATTEMPTS =>
+                {
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// One attempt of `put`, failing with `NotFound` if a concurrent `prune`
+    /// removed the blob directory or the temporary file before the rename
+    fn write_share(
+        &self,
+        blob_id: &[u8; BLOB_ID_SIZE],
+        column: u16,
+        bytes: &[u8],
+    ) -> Result<(), StoreError> {
+        let dir = self.blob_dir(blob_id);
+        fs::create_dir_all(&dir).map_err(io_error(&dir))?;
+
+        let path = self.share_path(blob_id, column);
+        let temp_path = self.temp_path(blob_id, column);
+        let written = File::create(&temp_path)
+            .and_then(|mut file| {
+                file.write_all(bytes)?;
+                file.sync_all()
+            })
+            .map_err(io_error(&temp_path));
+        if let Err(e) = written {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e);
+        }
+        fs::rename(&temp_path, &path).map_err(io_error(&path))
+    }
+
+    /// Loads the share of `column` for `blob_id`, or `None` if it is not stored
+    pub fn get(
+        &self,
+        blob_id: &[u8; BLOB_ID_SIZE],
+        column: u16,
+    ) -> Result<Option<DaShare>, StoreError> {
+        let path = self.share_path(blob_id, column);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(io_error(&path)(e)),
+        };
+
+        let share: DaShare = wire_deserialize(&bytes).map_err(|e| StoreError::Corrupt {
+            path: path.clone(),
+            reason: format!("{:?}", e),
+        })?;
+        if share.share_idx != column || share.blob_id() != *blob_id {
+            return Err(StoreError::Corrupt {
+                path,
+                reason: format!(
+                    "holds share {} of a different blob or column",
+                    share.share_idx
+                ),
+            });
+        }
+        Ok(Some(share))
+    }
+
+    /// Column indices held for `blob_id`, in ascending order
+    pub fn columns(&self, blob_id: &[u8; BLOB_ID_SIZE]) -> Result<Vec<u16>, StoreError> {
+        let dir = self.blob_dir(blob_id);
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(io_error(&dir)(e)),
+        };
+
+        let mut columns = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(io_error(&dir))?;
+            if let Some(column) = column_from_file_name(&entry.path()) {
+                columns.push(column);
+            }
+        }
+        columns.sort_unstable();
+        Ok(columns)
+    }
+
+    /// Removes every share put more than `max_age` ago, along with blob
+    /// directories left empty, and returns the number of shares removed.
+    /// Entries the store did not write are left in place.
+    pub fn prune(&self, max_age: Duration) -> Result<usize, StoreError> {
+        let cutoff = SystemTime::now()
+            .checked_sub(max_age)
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        let mut removed = 0;
+
+        for blob_entry in fs::read_dir(&self.root).map_err(io_error(&self.root))? {
+            let blob_entry = blob_entry.map_err(io_error(&self.root))?;
+            let blob_dir = blob_entry.path();
+            let is_dir = blob_entry
+                .file_type()
+                .map_err(io_error(&blob_dir))?
+                .is_dir();
+            if !is_dir || !is_blob_dir_name(&blob_entry.file_name()) {
+                continue;
+            }
+            let entries = match fs::read_dir(&blob_dir) {
+                Ok(entries) => entries,
+                Err(e) if is_not_found(&e) => continue,
+                Err(e) => return Err(io_error(&blob_dir)(e)),
+            };
+
+            let mut remaining = 0;
+            for entry in entries {
+                let entry = entry.map_err(io_error(&blob_dir))?;
+                let path = entry.path();
+                let is_file = entry.file_type().map_err(io_error(&path))?.is_file();
+                let is_share = column_from_file_name(&path).is_some();
+                // Leftover temporary files from an interrupted put are pruned too
+                if !is_file || !(is_share || is_temp_file_name(&path)) {
+                    remaining += 1;
+                    continue;
+                }
+
+                // A concurrent put or prune may rename or remove the file first
+                let modified = match entry.metadata().and_then(|metadata| metadata.modified()) {
+                    Ok(modified) => modified,
+                    Err(e) if is_not_found(&e) => continue,
+                    Err(e) => return Err(io_error(&path)(e)),
+                };
+                if modified > cutoff {
+                    remaining += 1;
+                    continue;
+                }
+                match fs::remove_file(&path) {
+                    Ok(()) if is_share => removed += 1,
+                    Ok(()) => {}
+                    Err(e) if is_not_found(&e) => {}
+                    Err(e) => return Err(io_error(&path)(e)),
+                }
+            }
+
+            // A put may have refilled or already removed the directory since it was read
+            if remaining == 0 {
+                match fs::remove_dir(&blob_dir) {
+                    Err(e) if !is_not_found(&e) && e.kind() != io::ErrorKind::DirectoryNotEmpty => {
+                        return Err(io_error(&blob_dir)(e));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(removed)
+    }
+}
@@ -0,0 +1,144 @@
+//! Incremental collection of the data for one encoding
+//!
+//! A session accepts the data in slices of any length, so a slice may end in
+//! the middle of an encoding chunk and the next one continues it. Alignment is
+//! therefore tracked across calls and only a partial chunk left at the end is
+//! an error. When the expected length is announced up front, it is checked for
+//! alignment at once and the buffer is allocated a single time. Every push then
+//! checks the running total against it, so a push that would overrun the
+//! expected length is rejected by that call, without appending anything, rather
+//! than surfacing when the session is finished.
+
+use std::fmt;
+
+use logos_blockchain_kzgrs_backend::encoder::DaEncoderParams;
+
+const CHUNK_SIZE: usize = DaEncoderParams::MAX_BLS12_381_ENCODING_CHUNK_SIZE;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamingError {
+    UnalignedExpectedLength {
+        expected_len: usize,
+    },
+    ExceedsExpectedLength {
+        expected_len: usize,
+        received: usize,
+        pushed: usize,
+    },
+    Empty,
+    PartialChunk {
+        received: usize,
+    },
+    Incomplete {
+        expected_len: usize,
+        received: usize,
+    },
+    Finished,
+}
+
+impl fmt::Display for StreamingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnalignedExpectedLength { expected_len } => write!(
+                f,
+                "Expected length must be a multiple of chunk size (expected_len: {}, chunk_size: {})",
+                expected_len, CHUNK_SIZE
+            ),
+            Self::ExceedsExpectedLength { expected_len, received, pushed } => write!(
+                f,
+                "Pushing {} bytes would exceed the expected length (expected_len: {}, received: {}, chunk_size: {})",
+                pushed, expected_len, received, CHUNK_SIZE
+            ),
+            Self::Empty => write!(f, "No data was pushed to the encoding session"),
+            Self::PartialChunk { received } => write!(
+                f,
+                "Data ends in a partial chunk of {} bytes (received: {}, chunk_size: {})",
+                received % CHUNK_SIZE,
+                received,
+                CHUNK_SIZE
+            ),
+            Self::Incomplete { expected_len, received } => write!(
+                f,
+                "Session finished before the expected length was reached (expected_len: {}, received: {})",
+                expected_len, received
+            ),
+            Self::Finished => write!(f, "Encoding session was already finished"),
+        }
+    }
+}
+
+impl std::error::Error for StreamingError {}
+
+pub struct EncoderSession {
+    /// Total length announced when the session began, if any
+    expected_len: Option<usize>,
+    buffer: Vec<u8>,
+    finished: bool,
+}
+
+impl EncoderSession {
+    pub fn new(expected_len: Option<usize>) -> Result<Self, StreamingError> {
+        if let Some(expected_len) = expected_len {
+            if expected_len % CHUNK_SIZE != 0 {
+                return Err(StreamingError::UnalignedExpectedLength { expected_len });
+            }
+        }
+        Ok(Self {
+            expected_len,
+            buffer: Vec::with_capacity(expected_len.unwrap_or(0)),
+            finished: false,
+        })
+    }
+
+    /// Bytes pushed so far
+    pub fn received(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Appends `data`, which may start or end in the middle of a chunk. A push
+    /// that would take the running total past the expected length is rejected
+    /// and leaves the session as it was.
+    pub fn push(&mut self, data: &[u8]) -> Result<(), StreamingError> {
+        if self.finished {
+            return Err(StreamingError::Finished);
+        }
+        if let Some(expected_len) = self.expected_len {
+            let remaining = expected_len - self.buffer.len();
+            if data.len() > remaining {
+                return Err(StreamingError::ExceedsExpectedLength {
+                    expected_len,
+                    received: self.buffer.len(),
+                    pushed: data.len(),
+                });
+            }
+        }
+        self.buffer.extend_from_slice(data);
+        Ok(())
+    }
+
+    /// Hands out the collected data once it forms whole chunks; the session
+    /// accepts nothing afterwards, even if the data was rejected
+    pub fn finish(&mut self) -> Result<Vec<u8>, StreamingError> {
+        if self.finished {
+            return Err(StreamingError::Finished);
+        }
+        self.finished = true;
+
+        let received = self.buffer.len();
+        if let Some(expected_len) = self.expected_len {
+            if received != expected_len {
+                return Err(StreamingError::Incomplete {
+                    expected_len,
+                    received,
+                });
+            }
+        }
+        if received == 0 {
+            return Err(StreamingError::Empty);
+        }
+        if received % CHUNK_SIZE != 0 {
+            return Err(StreamingError::PartialChunk { received });
+        }
+        Ok(std::mem::take(&mut self.buffer))
+    }
+}
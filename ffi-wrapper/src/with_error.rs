@@ -0,0 +1,374 @@
+//! `_with_error` variants of the fallible entry points
+//!
+//! Each variant takes the arguments of the entry point it is named after plus a
+//! trailing `out_error`, calls it, and fills `out_error` (when not null) with its
+//! result code and message. The struct lives in caller memory, so reading the
+//! outcome needs no further call and no allocation. The last error of the
+//! calling thread is set exactly as by the plain entry point.
+//!
+//! The variants are generated by `with_error_entry_points!`, so a new fallible
+//! entry point only needs a line here. cbindgen does not expand macros, so their
+//! C declarations are kept in the `trailer` of `cbindgen.toml`; a test checks
+//! that the generated header declares every name in `WITH_ERROR_ENTRY_POINTS`.
+
+use std::os::raw::c_char;
+
+use crate::simulation::{SimulationConfig, SimulationReport};
+
+/// Defines each `$name` as `$entry` with a trailing `out_error`, and lists the
+/// names in `WITH_ERROR_ENTRY_POINTS`
+macro_rules! with_error_entry_points {
+    ($(fn $name:ident => $entry:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;)*) => {
+        $(
+            #[no_mangle]
+            pub unsafe extern "C" fn $name($($arg: $ty,)* out_error: *mut NomosDaError) -> $ret {
+                ffi_boundary_keep_error(|| {
+                    let result = $entry($($arg),*);
+                    write_last_error(out_error);
+                    result
+                })
+            }
+        )*
+
+        /// Names of every `_with_error` entry point
+        pub const WITH_ERROR_ENTRY_POINTS: &[&str] = &[$(stringify!($name)),*];
+    };
+}
+
+with_error_entry_points! {
+    fn nomos_da_encoder_new_with_error => nomos_da_encoder_new(
+        column_count: CSizeT,
+    ) -> *mut EncoderHandle;
+    fn nomos_da_encoder_new_with_options_with_error => nomos_da_encoder_new_with_options(
+        options: *const NomosDaEncoderOptions,
+    ) -> *mut EncoderHandle;
+    fn nomos_da_encoder_has_cache_with_error => nomos_da_encoder_has_cache(
+        handle: *mut EncoderHandle,
+    ) -> bool;
+    fn nomos_da_encoder_thread_count_with_error => nomos_da_encoder_thread_count(
+        handle: *mut EncoderHandle,
+    ) -> CSizeT;
+    fn nomos_da_encoder_new_with_params_with_error => nomos_da_encoder_new_with_params(
+        column_count: CSizeT,
+        params_data: *const u8,
+        params_len: CSizeT,
+    ) -> *mut EncoderHandle;
+    fn nomos_da_encoder_new_with_params_file_with_error => nomos_da_encoder_new_with_params_file(
+        column_count: CSizeT,
+        path: *const c_char,
+    ) -> *mut EncoderHandle;
+    fn nomos_da_encoder_export_params_with_error => nomos_da_encoder_export_params(
+        handle: *mut EncoderHandle,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_encoder_encode_with_error => nomos_da_encoder_encode(
+        encoder: *mut EncoderHandle,
+        data: *const u8,
+        data_len: CSizeT,
+        out_handle: *mut *mut EncodedDataHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_encoded_data_blob_id_with_error => nomos_da_encoded_data_blob_id(
+        handle: *mut EncodedDataHandle,
+        out_blob_id: *mut u8,
+    ) -> NomosDaResult;
+    fn nomos_da_encoder_encode_padded_with_error => nomos_da_encoder_encode_padded(
+        encoder: *mut EncoderHandle,
+        data: *const u8,
+        data_len: CSizeT,
+        out_handle: *mut *mut EncodedDataHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_encoder_session_begin_with_error => nomos_da_encoder_session_begin(
+        encoder: *mut EncoderHandle,
+        expected_len: CSizeT,
+        out_session: *mut *mut EncoderSessionHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_encoder_session_push_with_error => nomos_da_encoder_session_push(
+        session: *mut EncoderSessionHandle,
+        data: *const u8,
+        data_len: CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_encoder_session_finish_with_error => nomos_da_encoder_session_finish(
+        session: *mut EncoderSessionHandle,
+        out_handle: *mut *mut EncodedDataHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_encoder_session_received_with_error => nomos_da_encoder_session_received(
+        session: *mut EncoderSessionHandle,
+    ) -> CSizeT;
+    fn nomos_da_encoded_data_get_data_with_error => nomos_da_encoded_data_get_data(
+        handle: *mut EncodedDataHandle,
+        out_data: *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_encoded_data_data_view_with_error => nomos_da_encoded_data_data_view(
+        handle: *mut EncodedDataHandle,
+        out_data: *mut *const u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_encoded_data_column_view_with_error => nomos_da_encoded_data_column_view(
+        handle: *mut EncodedDataHandle,
+        column: CSizeT,
+        out_data: *mut *const u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_encoded_data_proof_view_with_error => nomos_da_encoded_data_proof_view(
+        handle: *mut EncodedDataHandle,
+        column: CSizeT,
+        out_proof: *mut *const u8,
+    ) -> NomosDaResult;
+    fn nomos_da_encoded_data_get_row_count_with_error => nomos_da_encoded_data_get_row_count(
+        handle: *mut EncodedDataHandle,
+    ) -> CSizeT;
+    fn nomos_da_encoded_data_get_column_count_with_error => nomos_da_encoded_data_get_column_count(
+        handle: *mut EncodedDataHandle,
+    ) -> CSizeT;
+    fn nomos_da_encoded_data_get_column_with_error => nomos_da_encoded_data_get_column(
+        handle: *mut EncodedDataHandle,
+        column: CSizeT,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_encoded_data_get_chunk_with_error => nomos_da_encoded_data_get_chunk(
+        handle: *mut EncodedDataHandle,
+        row: CSizeT,
+        column: CSizeT,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_encoded_data_get_row_commitment_with_error => nomos_da_encoded_data_get_row_commitment(
+        handle: *mut EncodedDataHandle,
+        row: CSizeT,
+        out_commitment: *mut u8,
+    ) -> NomosDaResult;
+    fn nomos_da_encoded_data_get_column_proof_with_error => nomos_da_encoded_data_get_column_proof(
+        handle: *mut EncodedDataHandle,
+        column: CSizeT,
+        out_proof: *mut u8,
+    ) -> NomosDaResult;
+    fn nomos_da_verifier_new_with_error => nomos_da_verifier_new() -> *mut VerifierHandle;
+    fn nomos_da_verifier_new_with_key_with_error => nomos_da_verifier_new_with_key(
+        key_data: *const u8,
+        key_len: CSizeT,
+    ) -> *mut VerifierHandle;
+    fn nomos_da_verifier_new_with_key_file_with_error => nomos_da_verifier_new_with_key_file(
+        path: *const c_char,
+    ) -> *mut VerifierHandle;
+    fn nomos_da_verifier_export_key_with_error => nomos_da_verifier_export_key(
+        handle: *mut VerifierHandle,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_encoded_data_get_share_count_with_error => nomos_da_encoded_data_get_share_count(
+        handle: *mut EncodedDataHandle,
+    ) -> CSizeT;
+    fn nomos_da_encoded_data_get_share_with_error => nomos_da_encoded_data_get_share(
+        handle: *mut EncodedDataHandle,
+        index: CSizeT,
+        out_share_handle: *mut *mut ShareHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_share_clone_with_error => nomos_da_share_clone(
+        share_handle: *mut ShareHandle,
+    ) -> *mut ShareHandle;
+    fn nomos_da_share_get_index_with_error => nomos_da_share_get_index(
+        share_handle: *mut ShareHandle,
+    ) -> u16;
+    fn nomos_da_share_get_commitments_with_error => nomos_da_share_get_commitments(
+        share_handle: *mut ShareHandle,
+        out_commitments_handle: *mut *mut CommitmentsHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_share_to_bytes_with_error => nomos_da_share_to_bytes(
+        share_handle: *mut ShareHandle,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_share_from_bytes_with_error => nomos_da_share_from_bytes(
+        data: *const u8,
+        data_len: CSizeT,
+        out_share_handle: *mut *mut ShareHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_verifier_verify_with_error => nomos_da_verifier_verify(
+        verifier: *mut VerifierHandle,
+        share_handle: *mut ShareHandle,
+        rows_domain_size: CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_verifier_verify_batch_with_error => nomos_da_verifier_verify_batch(
+        verifier: *mut VerifierHandle,
+        shares: *const *mut ShareHandle,
+        share_count: CSizeT,
+        rows_domain_size: CSizeT,
+        out_results: *mut NomosDaResult,
+    ) -> NomosDaResult;
+    fn nomos_da_verifier_verify_light_with_error => nomos_da_verifier_verify_light(
+        verifier: *mut VerifierHandle,
+        light_share_handle: *mut LightShareHandle,
+        commitments_handle: *mut CommitmentsHandle,
+        rows_domain_size: CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_light_share_from_share_with_error => nomos_da_light_share_from_share(
+        share_handle: *mut ShareHandle,
+        out_light_share_handle: *mut *mut LightShareHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_light_share_to_bytes_with_error => nomos_da_light_share_to_bytes(
+        light_share_handle: *mut LightShareHandle,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_light_share_from_bytes_with_error => nomos_da_light_share_from_bytes(
+        data: *const u8,
+        data_len: CSizeT,
+        out_light_share_handle: *mut *mut LightShareHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_light_share_get_index_with_error => nomos_da_light_share_get_index(
+        light_share_handle: *mut LightShareHandle,
+    ) -> u16;
+    fn nomos_da_light_share_clone_with_error => nomos_da_light_share_clone(
+        light_share_handle: *mut LightShareHandle,
+    ) -> *mut LightShareHandle;
+    fn nomos_da_commitments_clone_with_error => nomos_da_commitments_clone(
+        handle: *mut CommitmentsHandle,
+    ) -> *mut CommitmentsHandle;
+    fn nomos_da_commitments_row_count_with_error => nomos_da_commitments_row_count(
+        handle: *mut CommitmentsHandle,
+    ) -> CSizeT;
+    fn nomos_da_commitments_to_bytes_with_error => nomos_da_commitments_to_bytes(
+        handle: *mut CommitmentsHandle,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_commitments_from_bytes_with_error => nomos_da_commitments_from_bytes(
+        data: *const u8,
+        data_len: CSizeT,
+        out_commitments_handle: *mut *mut CommitmentsHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_commitments_blob_id_with_error => nomos_da_commitments_blob_id(
+        handle: *mut CommitmentsHandle,
+        out_blob_id: *mut u8,
+    ) -> NomosDaResult;
+    fn nomos_da_share_blob_id_with_error => nomos_da_share_blob_id(
+        share_handle: *mut ShareHandle,
+        out_blob_id: *mut u8,
+    ) -> NomosDaResult;
+    fn nomos_da_commitments_equal_with_error => nomos_da_commitments_equal(
+        lhs: *mut CommitmentsHandle,
+        rhs: *mut CommitmentsHandle,
+    ) -> bool;
+    fn nomos_da_reconstruct_with_error => nomos_da_reconstruct(
+        shares: *const *mut ShareHandle,
+        share_count: CSizeT,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_reconstruct_with_missing_data_with_error => nomos_da_reconstruct_with_missing_data(
+        shares: *const *mut ShareHandle,
+        share_count: CSizeT,
+        column_count: CSizeT,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_reconstruct_and_verify_with_error => nomos_da_reconstruct_and_verify(
+        encoder: *mut EncoderHandle,
+        shares: *const *mut ShareHandle,
+        share_count: CSizeT,
+        column_count: CSizeT,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_reconstruct_padded_with_error => nomos_da_reconstruct_padded(
+        shares: *const *mut ShareHandle,
+        share_count: CSizeT,
+        column_count: CSizeT,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_encoded_data_from_shares_with_error => nomos_da_encoded_data_from_shares(
+        shares: *const *mut ShareHandle,
+        share_count: CSizeT,
+        column_count: CSizeT,
+        out_handle: *mut *mut EncodedDataHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_split_blob_count_with_error => nomos_da_split_blob_count(
+        data_len: CSizeT,
+        max_blob_size: CSizeT,
+        out_count: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_encoder_encode_split_with_error => nomos_da_encoder_encode_split(
+        encoder: *mut EncoderHandle,
+        data: *const u8,
+        data_len: CSizeT,
+        max_blob_size: CSizeT,
+        out_blobs: *mut *mut EncodedDataHandle,
+        blob_count: CSizeT,
+        out_manifest: *mut *mut BlobManifestHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_manifest_blob_count_with_error => nomos_da_manifest_blob_count(
+        handle: *mut BlobManifestHandle,
+    ) -> CSizeT;
+    fn nomos_da_manifest_payload_len_with_error => nomos_da_manifest_payload_len(
+        handle: *mut BlobManifestHandle,
+    ) -> u64;
+    fn nomos_da_manifest_entry_with_error => nomos_da_manifest_entry(
+        handle: *mut BlobManifestHandle,
+        index: CSizeT,
+        out_entry: *mut BlobManifestEntry,
+    ) -> NomosDaResult;
+    fn nomos_da_manifest_to_bytes_with_error => nomos_da_manifest_to_bytes(
+        handle: *mut BlobManifestHandle,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_manifest_from_bytes_with_error => nomos_da_manifest_from_bytes(
+        data: *const u8,
+        data_len: CSizeT,
+        out_manifest: *mut *mut BlobManifestHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_manifest_reassemble_with_error => nomos_da_manifest_reassemble(
+        handle: *mut BlobManifestHandle,
+        blobs: *const *const u8,
+        blob_lens: *const CSizeT,
+        blob_count: CSizeT,
+        out_data: *mut *mut u8,
+        out_len: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_store_open_with_error => nomos_da_store_open(
+        path: *const c_char,
+    ) -> *mut ShareStoreHandle;
+    fn nomos_da_store_put_with_error => nomos_da_store_put(
+        store: *mut ShareStoreHandle,
+        share_handle: *mut ShareHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_store_get_with_error => nomos_da_store_get(
+        store: *mut ShareStoreHandle,
+        blob_id: *const u8,
+        column: u16,
+        out_share_handle: *mut *mut ShareHandle,
+    ) -> NomosDaResult;
+    fn nomos_da_store_list_columns_with_error => nomos_da_store_list_columns(
+        store: *mut ShareStoreHandle,
+        blob_id: *const u8,
+        out_columns: *mut *mut u16,
+        out_count: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_store_prune_with_error => nomos_da_store_prune(
+        store: *mut ShareStoreHandle,
+        max_age_secs: u64,
+        out_removed: *mut CSizeT,
+    ) -> NomosDaResult;
+    fn nomos_da_sample_columns_with_error => nomos_da_sample_columns(
+        blob_id: *const u8,
+        seed: *const u8,
+        seed_len: CSizeT,
+        column_count: CSizeT,
+        sample_count: CSizeT,
+        out_columns: *mut u16,
+        out_confidence: *mut f64,
+    ) -> NomosDaResult;
+    fn nomos_da_sampling_confidence_with_error => nomos_da_sampling_confidence(
+        column_count: CSizeT,
+        sample_count: CSizeT,
+        out_confidence: *mut f64,
+    ) -> NomosDaResult;
+    fn nomos_da_simulate_with_error => nomos_da_simulate(
+        config: *const SimulationConfig,
+        out_report: *mut SimulationReport,
+    ) -> NomosDaResult;
+}
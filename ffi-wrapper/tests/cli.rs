@@ -0,0 +1,220 @@
+//! Tests running the `nomos-da` command line tool
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use logos_blockchain_core::da::{blob::Share as _, DaEncoder as _};
+use logos_blockchain_kzgrs_backend::{
+    common::share::DaShare,
+    encoder::{DaEncoder, DaEncoderParams},
+};
+use nomos_da_ffi::padding::pad;
+
+const COLUMN_COUNT: usize = 4;
+
+fn nomos_da(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_nomos-da"))
+        .args(args)
+        .output()
+        .expect("Failed to run nomos-da")
+}
+
+fn test_dir(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("nomos-da-cli-{}-{}", name, std::process::id()));
+    std::fs::remove_dir_all(&path).ok();
+    std::fs::create_dir_all(&path).expect("Failed to create test directory");
+    path
+}
+
+fn path_str(path: &Path) -> &str {
+    path.to_str().expect("Test paths are UTF-8")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn share_path(dir: &Path, index: usize) -> PathBuf {
+    dir.join(format!("{:05}.share", index))
+}
+
+/// Writes a payload of `len` bytes and encodes it into `<dir>/shares`
+fn encode_payload(dir: &Path, len: usize) -> (Vec<u8>, PathBuf) {
+    let payload: Vec<u8> = (0..len).map(|i| (i * 7 % 251) as u8).collect();
+    let input = dir.join("payload.bin");
+    std::fs::write(&input, &payload).expect("Failed to write payload");
+    let shares = dir.join("shares");
+
+    let output = nomos_da(&[
+        "encode",
+        path_str(&input),
+        "--columns",
+        &COLUMN_COUNT.to_string(),
+        "--out",
+        path_str(&shares),
+    ]);
+    assert!(
+        output.status.success(),
+        "encode should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    (payload, shares)
+}
+
+/// Shares the library produces for the same payload the tool encodes
+fn library_shares(payload: &[u8]) -> Vec<DaShare> {
+    let encoded = DaEncoder::new(DaEncoderParams::default_with(COLUMN_COUNT))
+        .encode(&pad(payload))
+        .expect("Library encoding should succeed");
+    (0..COLUMN_COUNT)
+        .map(|index| encoded.to_da_share(index).unwrap())
+        .collect()
+}
+
+fn reconstruct(dir: &Path, extra: &[&str]) -> Vec<u8> {
+    let out = dir.join("reconstructed.bin");
+    let mut args = vec!["reconstruct", path_str(dir), "--out", path_str(&out)];
+    args.extend_from_slice(extra);
+    let output = nomos_da(&args);
+    assert!(
+        output.status.success(),
+        "reconstruct should succeed (args: {:?}): {}",
+        extra,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let data = std::fs::read(&out).expect("Reconstructed file should be written");
+    std::fs::remove_file(&out).ok();
+    data
+}
+
+#[test]
+fn test_cli_encode_verify_reconstruct() {
+    let dir = test_dir("roundtrip");
+    let (payload, shares) = encode_payload(&dir, 1000);
+
+    for index in 0..COLUMN_COUNT {
+        let output = nomos_da(&[
+            "verify",
+            path_str(&share_path(&shares, index)),
+            "--domain",
+            &COLUMN_COUNT.to_string(),
+        ]);
+        assert_eq!(
+            output.status.code(),
+            Some(0),
+            "Encoded share {} should verify: {}",
+            index,
+            String::from_utf8_lossy(&output.stdout)
+        );
+    }
+    let output = nomos_da(&["verify", path_str(&share_path(&shares, 0)), "--domain", "3"]);
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "A non power-of-two domain should make the share invalid"
+    );
+
+    assert_eq!(
+        reconstruct(&shares, &[]),
+        payload,
+        "The manifest should make reconstruct strip the padding"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_cli_reconstruct_with_missing_shares() {
+    let dir = test_dir("missing");
+    let (payload, shares) = encode_payload(&dir, 300);
+
+    for index in [1, 2] {
+        std::fs::remove_file(share_path(&shares, index)).expect("Failed to remove share");
+    }
+    assert_eq!(
+        reconstruct(&shares, &[]),
+        payload,
+        "Half of the columns should be enough to reconstruct"
+    );
+
+    std::fs::remove_file(share_path(&shares, 3)).expect("Failed to remove share");
+    let output = nomos_da(&["reconstruct", path_str(&shares)]);
+    assert_eq!(
+        output.status.code(),
+        Some(2),
+        "Too few shares should be reported as an error"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_cli_reconstruct_is_raw_without_manifest() {
+    let dir = test_dir("raw");
+    let (payload, shares) = encode_payload(&dir, 200);
+    std::fs::remove_file(shares.join("blob.manifest")).expect("Failed to remove manifest");
+
+    let output = nomos_da(&["reconstruct", path_str(&shares)]);
+    assert_eq!(
+        output.status.code(),
+        Some(2),
+        "The column count is required without a manifest"
+    );
+
+    let columns = COLUMN_COUNT.to_string();
+    let raw = reconstruct(&shares, &["--columns", &columns]);
+    let padded = pad(&payload);
+    assert!(
+        raw.starts_with(&padded) && raw[padded.len()..].iter().all(|&byte| byte == 0),
+        "Shares without a manifest should be reconstructed as they are"
+    );
+    assert_eq!(
+        reconstruct(&shares, &["--columns", &columns, "--unpad"]),
+        payload,
+        "--unpad should strip the padding"
+    );
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_cli_blob_id_and_inspect_match_library() {
+    let dir = test_dir("inspect");
+    let (payload, shares) = encode_payload(&dir, 500);
+    let expected = library_shares(&payload);
+    let blob_id = hex(&expected[0].blob_id());
+
+    let paths: Vec<PathBuf> = (0..COLUMN_COUNT)
+        .map(|index| share_path(&shares, index))
+        .collect();
+    let mut args = vec!["blob-id"];
+    args.extend(paths.iter().map(|path| path_str(path)));
+    let output = nomos_da(&args);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), COLUMN_COUNT, "One line per share: {}", stdout);
+    for (line, path) in lines.iter().zip(&paths) {
+        assert_eq!(*line, format!("{} {}", blob_id, path.display()));
+    }
+
+    let share = &expected[1];
+    let output = nomos_da(&["inspect", path_str(&paths[1])]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for expected_line in [
+        format!("blob_id:         {}", blob_id),
+        format!("share_idx:       {}", share.share_idx),
+        format!("rows:            {}", share.column.0.len()),
+        format!("row_commitments: {}", share.rows_commitments.len()),
+    ] {
+        assert!(
+            stdout.lines().any(|line| line == expected_line),
+            "inspect should print {:?}: {}",
+            expected_line,
+            stdout
+        );
+    }
+
+    std::fs::remove_dir_all(&dir).ok();
+}
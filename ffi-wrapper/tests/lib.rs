@@ -1,19 +1,61 @@
 //! Integration tests for nomos-da FFI wrapper
 
+use ark_serialize::CanonicalSerialize;
+use logos_blockchain_core::da::blob::Share as _;
+use logos_blockchain_kzgrs::global_parameters::global_parameters_from_randomness;
 use logos_blockchain_kzgrs_backend::encoder::DaEncoderParams;
+use nomos_da_ffi::padding::{pad, unpad, PaddingError, LENGTH_HEADER_SIZE};
+use nomos_da_ffi::simulation::{AdversaryBehavior, SimulationConfig, SimulationReport};
+use nomos_da_ffi::splitting::{split_payload, BlobManifestEntry, SplitError};
+use nomos_da_ffi::store::ShareStore;
+use nomos_da_ffi::with_error::{
+    nomos_da_encoder_encode_with_error, nomos_da_share_from_bytes_with_error,
+    nomos_da_verifier_new_with_key_with_error, WITH_ERROR_ENTRY_POINTS,
+};
 use nomos_da_ffi::{
-    nomos_da_cleanup, nomos_da_commitments_free,
-    nomos_da_encoder_encode, nomos_da_encoder_free,
-    nomos_da_encoder_new, nomos_da_encoded_data_free,
-    nomos_da_encoded_data_get_data, nomos_da_encoded_data_get_share,
-    nomos_da_encoded_data_get_share_count,
-    nomos_da_init, nomos_da_reconstruct, nomos_da_reconstruct_free,
-    nomos_da_share_free,
-    nomos_da_share_get_commitments, nomos_da_share_get_index, nomos_da_verifier_free,
-    nomos_da_verifier_new, nomos_da_verifier_verify, CommitmentsHandle, EncodedDataHandle,
-    NomosDaResult, ShareHandle,
+    nomos_da_bytes_free, nomos_da_cleanup, nomos_da_commitment_size, nomos_da_commitments_blob_id,
+    nomos_da_commitments_clone, nomos_da_commitments_equal, nomos_da_commitments_free,
+    nomos_da_commitments_from_bytes, nomos_da_commitments_release, nomos_da_commitments_retain,
+    nomos_da_commitments_row_count, nomos_da_commitments_to_bytes, nomos_da_encoded_data_blob_id,
+    nomos_da_encoded_data_column_view, nomos_da_encoded_data_data_view, nomos_da_encoded_data_free,
+    nomos_da_encoded_data_from_shares, nomos_da_encoded_data_get_chunk,
+    nomos_da_encoded_data_get_column, nomos_da_encoded_data_get_column_count,
+    nomos_da_encoded_data_get_column_proof, nomos_da_encoded_data_get_data,
+    nomos_da_encoded_data_get_row_commitment, nomos_da_encoded_data_get_row_count,
+    nomos_da_encoded_data_get_share, nomos_da_encoded_data_get_share_count,
+    nomos_da_encoded_data_proof_view, nomos_da_encoder_encode, nomos_da_encoder_encode_padded,
+    nomos_da_encoder_encode_split, nomos_da_encoder_export_params, nomos_da_encoder_free,
+    nomos_da_encoder_has_cache, nomos_da_encoder_new, nomos_da_encoder_new_with_options,
+    nomos_da_encoder_new_with_params, nomos_da_encoder_new_with_params_file,
+    nomos_da_encoder_release, nomos_da_encoder_retain, nomos_da_encoder_session_begin,
+    nomos_da_encoder_session_finish, nomos_da_encoder_session_free, nomos_da_encoder_session_push,
+    nomos_da_encoder_session_received, nomos_da_encoder_thread_count, nomos_da_free_string,
+    nomos_da_get_last_error, nomos_da_get_last_error_code, nomos_da_get_last_error_message,
+    nomos_da_init, nomos_da_light_share_clone, nomos_da_light_share_free,
+    nomos_da_light_share_from_bytes, nomos_da_light_share_from_share,
+    nomos_da_light_share_get_index, nomos_da_light_share_to_bytes, nomos_da_manifest_blob_count,
+    nomos_da_manifest_entry, nomos_da_manifest_free, nomos_da_manifest_from_bytes,
+    nomos_da_manifest_payload_len, nomos_da_manifest_reassemble, nomos_da_manifest_to_bytes,
+    nomos_da_reconstruct, nomos_da_reconstruct_and_verify, nomos_da_reconstruct_free,
+    nomos_da_reconstruct_padded, nomos_da_reconstruct_with_missing_data, nomos_da_sample_columns,
+    nomos_da_sampling_confidence, nomos_da_share_blob_id, nomos_da_share_clone,
+    nomos_da_share_free, nomos_da_share_from_bytes, nomos_da_share_get_commitments,
+    nomos_da_share_get_index, nomos_da_share_release, nomos_da_share_retain,
+    nomos_da_share_to_bytes, nomos_da_simulate, nomos_da_split_blob_count,
+    nomos_da_store_columns_free, nomos_da_store_free, nomos_da_store_get,
+    nomos_da_store_list_columns, nomos_da_store_open, nomos_da_store_prune, nomos_da_store_put,
+    nomos_da_verifier_export_key, nomos_da_verifier_free, nomos_da_verifier_new,
+    nomos_da_verifier_new_with_key, nomos_da_verifier_new_with_key_file, nomos_da_verifier_release,
+    nomos_da_verifier_retain, nomos_da_verifier_verify, nomos_da_verifier_verify_batch,
+    nomos_da_verifier_verify_light, wire_serialize, BlobManifestHandle, CommitmentsHandle,
+    EncodedDataHandle, EncoderHandle, EncoderSessionHandle, LightShareHandle,
+    NomosDaEncoderOptions, NomosDaError, NomosDaResult, ShareHandle, ShareStoreHandle,
+    VerifierHandle, BLOB_ID_SIZE, COMMITMENT_SIZE, ERROR_MESSAGE_SIZE, PROOF_SIZE,
 };
+use std::ffi::{c_char, CStr, CString};
 use std::ptr;
+use std::thread;
+use std::time::Duration;
 
 // ============================================================================
 // Constants and Helper Functions
@@ -27,37 +69,69 @@ fn create_test_data(size: usize) -> Vec<u8> {
 
 unsafe fn test_encode_success(data_size: usize, column_count: usize) {
     let encoder = nomos_da_encoder_new(column_count);
-    assert!(!encoder.is_null(), "Encoder should be created (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
+    assert!(
+        !encoder.is_null(),
+        "Encoder should be created (data_size: {}, column_count: {}, chunk_size: {})",
+        data_size,
+        column_count,
+        CHUNK_SIZE
+    );
 
     let data = create_test_data(data_size);
     let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-    let result = nomos_da_encoder_encode(
-        encoder,
-        data.as_ptr(),
-        data.len(),
-        &mut out_handle,
+    let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+
+    assert_eq!(
+        result,
+        NomosDaResult::Success,
+        "Encoding should succeed (data_size: {}, column_count: {}, chunk_size: {})",
+        data_size,
+        column_count,
+        CHUNK_SIZE
+    );
+    assert!(
+        !out_handle.is_null(),
+        "Output handle should not be null (data_size: {}, column_count: {}, chunk_size: {})",
+        data_size,
+        column_count,
+        CHUNK_SIZE
     );
-    
-    assert_eq!(result, NomosDaResult::Success, "Encoding should succeed (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
-    assert!(!out_handle.is_null(), "Output handle should not be null (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
 
     let encoded = &(*out_handle).data;
-    
+
     let padded_len = if data_size % CHUNK_SIZE == 0 {
         data_size
     } else {
         data_size + (CHUNK_SIZE - (data_size % CHUNK_SIZE))
     };
-    
+
     assert_eq!(encoded.data.len(), padded_len, "Encoded data length should be padded to {} (data_size: {}, column_count: {}, chunk_size: {})", padded_len, data_size, column_count, CHUNK_SIZE);
     assert_eq!(&encoded.data[..data_size], data.as_slice(), "Original data should be preserved at the beginning (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
-    
+
     for i in data_size..padded_len {
         assert_eq!(encoded.data[i], 0, "Padding byte at index {} should be zero (data_size: {}, column_count: {}, chunk_size: {})", i, data_size, column_count, CHUNK_SIZE);
     }
-    assert!(!encoded.chunked_data.0.is_empty(), "chunked_data should not be empty (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
-    assert!(!encoded.extended_data.0.is_empty(), "extended_data should not be empty (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
-    assert!(!encoded.row_commitments.is_empty(), "row_commitments should not be empty (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
+    assert!(
+        !encoded.chunked_data.0.is_empty(),
+        "chunked_data should not be empty (data_size: {}, column_count: {}, chunk_size: {})",
+        data_size,
+        column_count,
+        CHUNK_SIZE
+    );
+    assert!(
+        !encoded.extended_data.0.is_empty(),
+        "extended_data should not be empty (data_size: {}, column_count: {}, chunk_size: {})",
+        data_size,
+        column_count,
+        CHUNK_SIZE
+    );
+    assert!(
+        !encoded.row_commitments.is_empty(),
+        "row_commitments should not be empty (data_size: {}, column_count: {}, chunk_size: {})",
+        data_size,
+        column_count,
+        CHUNK_SIZE
+    );
     assert!(!encoded.combined_column_proofs.is_empty(), "combined_column_proofs should not be empty (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
 
     let chunks_per_row = column_count / 2;
@@ -71,9 +145,18 @@ unsafe fn test_encode_success(data_size: usize, column_count: usize) {
 
     let actual_columns = encoded.extended_data.0[0].0.len();
     assert_eq!(actual_columns, expected_columns_after_rs, "After RS encoding, each row should have {} columns (data_size: {}, column_count: {}, chunk_size: {})", expected_columns_after_rs, data_size, column_count, CHUNK_SIZE);
-    
+
     for (i, row) in encoded.extended_data.0.iter().enumerate() {
-        assert_eq!(row.0.len(), expected_columns_after_rs, "Row {} should have {} columns (data_size: {}, column_count: {}, chunk_size: {})", i, expected_columns_after_rs, data_size, column_count, CHUNK_SIZE);
+        assert_eq!(
+            row.0.len(),
+            expected_columns_after_rs,
+            "Row {} should have {} columns (data_size: {}, column_count: {}, chunk_size: {})",
+            i,
+            expected_columns_after_rs,
+            data_size,
+            column_count,
+            CHUNK_SIZE
+        );
     }
 
     assert_eq!(encoded.combined_column_proofs.len(), expected_columns_after_rs, "Number of column proofs should match number of columns (data_size: {}, column_count: {}, chunk_size: {}, expected_columns: {})", data_size, column_count, CHUNK_SIZE, expected_columns_after_rs);
@@ -93,12 +176,15 @@ unsafe fn test_encode_success(data_size: usize, column_count: usize) {
 
     let mut out_data = vec![0u8; padded_len * 2];
     let mut out_len = out_data.len();
-    let result = nomos_da_encoded_data_get_data(
-        out_handle,
-        out_data.as_mut_ptr(),
-        &mut out_len,
+    let result = nomos_da_encoded_data_get_data(out_handle, out_data.as_mut_ptr(), &mut out_len);
+    assert_eq!(
+        result,
+        NomosDaResult::Success,
+        "Get data should succeed (data_size: {}, column_count: {}, chunk_size: {})",
+        data_size,
+        column_count,
+        CHUNK_SIZE
     );
-    assert_eq!(result, NomosDaResult::Success, "Get data should succeed (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
     assert_eq!(out_len, padded_len, "Retrieved data length should match padded length (data_size: {}, column_count: {}, chunk_size: {}, padded_len: {})", data_size, column_count, CHUNK_SIZE, padded_len);
     assert_eq!(&out_data[..data_size], data.as_slice(), "Original data should be preserved at the beginning (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
     for i in data_size..padded_len {
@@ -111,19 +197,33 @@ unsafe fn test_encode_success(data_size: usize, column_count: usize) {
 
 unsafe fn test_encode_failure(data_size: usize, column_count: usize) {
     let encoder = nomos_da_encoder_new(column_count);
-    assert!(!encoder.is_null(), "Encoder should be created (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
+    assert!(
+        !encoder.is_null(),
+        "Encoder should be created (data_size: {}, column_count: {}, chunk_size: {})",
+        data_size,
+        column_count,
+        CHUNK_SIZE
+    );
 
     let data = create_test_data(data_size);
     let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-    let result = nomos_da_encoder_encode(
-        encoder,
-        data.as_ptr(),
-        data.len(),
-        &mut out_handle,
+    let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+
+    assert_ne!(
+        result,
+        NomosDaResult::Success,
+        "Encoding should fail (data_size: {}, column_count: {}, chunk_size: {})",
+        data_size,
+        column_count,
+        CHUNK_SIZE
+    );
+    assert!(
+        out_handle.is_null(),
+        "Output handle should be null on failure (data_size: {}, column_count: {}, chunk_size: {})",
+        data_size,
+        column_count,
+        CHUNK_SIZE
     );
-    
-    assert_ne!(result, NomosDaResult::Success, "Encoding should fail (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
-    assert!(out_handle.is_null(), "Output handle should be null on failure (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
 
     nomos_da_encoder_free(encoder);
 }
@@ -160,9 +260,118 @@ fn test_verifier_create_and_free() {
 // Encoding Tests
 // ============================================================================
 
+#[test]
+fn test_encoder_with_options() {
+    unsafe {
+        let column_count = 8;
+        let data = create_test_data(CHUNK_SIZE * 8);
+        let plain = nomos_da_encoder_new(column_count);
+        assert!(
+            !nomos_da_encoder_has_cache(plain),
+            "Default encoder should not hold a cache"
+        );
+
+        let mut blob_ids = Vec::new();
+        for (with_cache, thread_count) in [(false, 0), (true, 0), (true, 2)] {
+            let options = NomosDaEncoderOptions {
+                column_count,
+                with_cache,
+                thread_count,
+            };
+            let encoder = nomos_da_encoder_new_with_options(&options);
+            assert!(
+                !encoder.is_null(),
+                "Encoder should be created (with_cache: {}, thread_count: {})",
+                with_cache,
+                thread_count
+            );
+
+            assert_eq!(
+                nomos_da_encoder_has_cache(encoder),
+                with_cache,
+                "Cache should be reported only when it is enabled (with_cache: {})",
+                with_cache
+            );
+
+            let expected_threads = if thread_count > 0 {
+                thread_count
+            } else {
+                rayon::current_num_threads()
+            };
+            assert_eq!(
+                nomos_da_encoder_thread_count(encoder),
+                expected_threads,
+                "Encoder should fan out to its configured pool (thread_count: {})",
+                thread_count
+            );
+
+            for handle in [encoder, plain] {
+                let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+                let result =
+                    nomos_da_encoder_encode(handle, data.as_ptr(), data.len(), &mut out_handle);
+                assert_eq!(
+                    result,
+                    NomosDaResult::Success,
+                    "Encoding should succeed (with_cache: {}, thread_count: {})",
+                    with_cache,
+                    thread_count
+                );
+                let mut blob_id = [0u8; BLOB_ID_SIZE];
+                assert_eq!(
+                    nomos_da_encoded_data_blob_id(out_handle, blob_id.as_mut_ptr()),
+                    NomosDaResult::Success
+                );
+                blob_ids.push(blob_id);
+                nomos_da_encoded_data_free(out_handle);
+            }
+
+            nomos_da_encoder_free(encoder);
+        }
+        assert!(
+            blob_ids.windows(2).all(|pair| pair[0] == pair[1]),
+            "Options should not change the encoded blob"
+        );
+
+        let zero_columns = NomosDaEncoderOptions {
+            column_count: 0,
+            with_cache: true,
+            thread_count: 0,
+        };
+        assert!(
+            nomos_da_encoder_new_with_options(&zero_columns).is_null(),
+            "Zero columns should be rejected"
+        );
+        assert!(
+            nomos_da_encoder_new_with_options(ptr::null()).is_null(),
+            "Null options should be rejected"
+        );
+        assert!(
+            !nomos_da_encoder_has_cache(ptr::null_mut()),
+            "Null encoder should report no cache"
+        );
+        assert!(
+            nomos_da_encoder_new(0).is_null(),
+            "Zero columns should be rejected by the plain constructor"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::ErrorInvalidInput
+        );
+        assert_eq!(
+            nomos_da_encoder_thread_count(ptr::null_mut()),
+            0,
+            "Null encoder should report no threads"
+        );
+
+        nomos_da_encoder_free(plain);
+    }
+}
+
 #[test]
 fn test_encode_size_0() {
-    unsafe { test_encode_failure(0, 4); }
+    unsafe {
+        test_encode_failure(0, 4);
+    }
 }
 
 #[test]
@@ -170,35 +379,40 @@ fn test_encode_not_multiple_of_chunk_size() {
     unsafe {
         let column_count = 4;
         let encoder = nomos_da_encoder_new(column_count);
-        assert!(!encoder.is_null(), "Encoder should be created (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+        assert!(
+            !encoder.is_null(),
+            "Encoder should be created (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
 
         // Test with data length that is not a multiple of chunk size
         let invalid_sizes = [1, CHUNK_SIZE - 1, CHUNK_SIZE + 1, 2 * CHUNK_SIZE - 1];
-        
+
         for data_size in invalid_sizes.iter() {
             let data = create_test_data(*data_size);
             let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-            let result = nomos_da_encoder_encode(
-                encoder,
-                data.as_ptr(),
-                data.len(),
-                &mut out_handle,
+            let result =
+                nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+            assert_eq!(result, NomosDaResult::ErrorBadLength, "Encoding should fail when data length is not a multiple of chunk size (data_size: {}, chunk_size: {})", data_size, CHUNK_SIZE);
+            assert!(
+                out_handle.is_null(),
+                "Output handle should be null on failure (data_size: {}, chunk_size: {})",
+                data_size,
+                CHUNK_SIZE
             );
-            assert_eq!(result, NomosDaResult::ErrorInvalidInput, "Encoding should fail when data length is not a multiple of chunk size (data_size: {}, chunk_size: {})", data_size, CHUNK_SIZE);
-            assert!(out_handle.is_null(), "Output handle should be null on failure (data_size: {}, chunk_size: {})", data_size, CHUNK_SIZE);
         }
 
         nomos_da_encoder_free(encoder);
     }
 }
 
-
 #[test]
 fn test_encode_various_sizes_and_column_counts() {
     unsafe {
         let column_counts = [2, 4, 8];
         let chunk_multipliers = [1, 2, 4, 8];
-        
+
         for column_count in column_counts.iter() {
             for multiplier in chunk_multipliers.iter() {
                 let data_size = *multiplier * CHUNK_SIZE;
@@ -208,6 +422,288 @@ fn test_encode_various_sizes_and_column_counts() {
     }
 }
 
+#[test]
+fn test_encoded_data_accessors() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        let data = create_test_data(CHUNK_SIZE * 6);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoding should succeed (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+        let encoded = &(*out_handle).data;
+
+        let row_count = nomos_da_encoded_data_get_row_count(out_handle);
+        let columns = nomos_da_encoded_data_get_column_count(out_handle);
+        assert_eq!(
+            row_count, 3,
+            "Row count should match (row_count: {})",
+            row_count
+        );
+        assert_eq!(
+            columns, column_count,
+            "Column count should match (columns: {}, column_count: {})",
+            columns, column_count
+        );
+
+        for column in 0..columns {
+            let mut column_data: *mut u8 = ptr::null_mut();
+            let mut column_len: usize = 0;
+            let result = nomos_da_encoded_data_get_column(
+                out_handle,
+                column,
+                &mut column_data,
+                &mut column_len,
+            );
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Should get column (column: {})",
+                column
+            );
+            let column_bytes = std::slice::from_raw_parts(column_data, column_len).to_vec();
+            nomos_da_bytes_free(column_data, column_len);
+
+            let share = encoded.to_da_share(column).unwrap();
+            let expected: Vec<u8> = share
+                .column
+                .0
+                .iter()
+                .flat_map(|chunk| chunk.0.clone())
+                .collect();
+            assert_eq!(
+                column_bytes, expected,
+                "Column bytes should match share column (column: {})",
+                column
+            );
+
+            let mut offset = 0;
+            for row in 0..row_count {
+                let mut chunk_data: *mut u8 = ptr::null_mut();
+                let mut chunk_len: usize = 0;
+                let result = nomos_da_encoded_data_get_chunk(
+                    out_handle,
+                    row,
+                    column,
+                    &mut chunk_data,
+                    &mut chunk_len,
+                );
+                assert_eq!(
+                    result,
+                    NomosDaResult::Success,
+                    "Should get chunk (row: {}, column: {})",
+                    row,
+                    column
+                );
+                let chunk = std::slice::from_raw_parts(chunk_data, chunk_len);
+                assert_eq!(
+                    chunk,
+                    &column_bytes[offset..offset + chunk_len],
+                    "Chunk should match column slice (row: {}, column: {})",
+                    row,
+                    column
+                );
+                offset += chunk_len;
+                nomos_da_bytes_free(chunk_data, chunk_len);
+            }
+
+            let mut proof = [0u8; PROOF_SIZE];
+            let result =
+                nomos_da_encoded_data_get_column_proof(out_handle, column, proof.as_mut_ptr());
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Should get column proof (column: {})",
+                column
+            );
+            let mut expected_proof = Vec::new();
+            encoded.combined_column_proofs[column]
+                .serialize_compressed(&mut expected_proof)
+                .unwrap();
+            assert_eq!(
+                proof.as_slice(),
+                expected_proof.as_slice(),
+                "Proof should match (column: {})",
+                column
+            );
+        }
+
+        for row in 0..row_count {
+            let mut commitment = [0u8; COMMITMENT_SIZE];
+            let result =
+                nomos_da_encoded_data_get_row_commitment(out_handle, row, commitment.as_mut_ptr());
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Should get row commitment (row: {})",
+                row
+            );
+            let mut expected_commitment = Vec::new();
+            encoded.row_commitments[row]
+                .serialize_compressed(&mut expected_commitment)
+                .unwrap();
+            assert_eq!(
+                commitment.as_slice(),
+                expected_commitment.as_slice(),
+                "Commitment should match (row: {})",
+                row
+            );
+        }
+
+        let mut bytes: *mut u8 = ptr::null_mut();
+        let mut bytes_len: usize = 0;
+        let mut point = [0u8; COMMITMENT_SIZE];
+        assert_eq!(
+            nomos_da_encoded_data_get_column(out_handle, columns, &mut bytes, &mut bytes_len),
+            NomosDaResult::ErrorIndexOutOfRange,
+            "Out of range column should fail"
+        );
+        assert_eq!(
+            nomos_da_encoded_data_get_chunk(out_handle, row_count, 0, &mut bytes, &mut bytes_len),
+            NomosDaResult::ErrorIndexOutOfRange,
+            "Out of range row should fail"
+        );
+        assert_eq!(
+            nomos_da_encoded_data_get_row_commitment(out_handle, row_count, point.as_mut_ptr()),
+            NomosDaResult::ErrorIndexOutOfRange,
+            "Out of range commitment should fail"
+        );
+        assert_eq!(
+            nomos_da_encoded_data_get_column_proof(out_handle, columns, point.as_mut_ptr()),
+            NomosDaResult::ErrorIndexOutOfRange,
+            "Out of range proof should fail"
+        );
+        assert_eq!(
+            nomos_da_encoded_data_get_row_count(ptr::null_mut()),
+            0,
+            "Null handle should have no rows"
+        );
+        assert_eq!(
+            nomos_da_encoded_data_get_column_count(ptr::null_mut()),
+            0,
+            "Null handle should have no columns"
+        );
+
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_encoded_data_views() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        let data = create_test_data(CHUNK_SIZE * 6);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle),
+            NomosDaResult::Success
+        );
+
+        let mut view: *const u8 = ptr::null();
+        let mut view_len: usize = 0;
+        assert_eq!(
+            nomos_da_encoded_data_data_view(out_handle, &mut view, &mut view_len),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            view,
+            (*out_handle).data.data.as_ptr(),
+            "Data view should borrow the handle's buffer"
+        );
+        assert_eq!(
+            std::slice::from_raw_parts(view, view_len),
+            data.as_slice(),
+            "Data view should hold the original data"
+        );
+
+        for column in 0..column_count {
+            let mut column_data: *mut u8 = ptr::null_mut();
+            let mut column_len: usize = 0;
+            assert_eq!(
+                nomos_da_encoded_data_get_column(
+                    out_handle,
+                    column,
+                    &mut column_data,
+                    &mut column_len
+                ),
+                NomosDaResult::Success
+            );
+            let expected_column = std::slice::from_raw_parts(column_data, column_len).to_vec();
+            nomos_da_bytes_free(column_data, column_len);
+
+            let mut view: *const u8 = ptr::null();
+            let mut view_len: usize = 0;
+            assert_eq!(
+                nomos_da_encoded_data_column_view(out_handle, column, &mut view, &mut view_len),
+                NomosDaResult::Success
+            );
+            assert_eq!(
+                std::slice::from_raw_parts(view, view_len),
+                expected_column.as_slice(),
+                "Column view should match the copied column (column: {})",
+                column
+            );
+
+            let mut again: *const u8 = ptr::null();
+            assert_eq!(
+                nomos_da_encoded_data_column_view(out_handle, column, &mut again, &mut view_len),
+                NomosDaResult::Success
+            );
+            assert_eq!(
+                again, view,
+                "Repeated views should reuse the same buffer (column: {})",
+                column
+            );
+
+            let mut expected_proof = [0u8; PROOF_SIZE];
+            assert_eq!(
+                nomos_da_encoded_data_get_column_proof(
+                    out_handle,
+                    column,
+                    expected_proof.as_mut_ptr()
+                ),
+                NomosDaResult::Success
+            );
+            let mut proof: *const u8 = ptr::null();
+            assert_eq!(
+                nomos_da_encoded_data_proof_view(out_handle, column, &mut proof),
+                NomosDaResult::Success
+            );
+            assert_eq!(
+                std::slice::from_raw_parts(proof, PROOF_SIZE),
+                expected_proof.as_slice(),
+                "Proof view should match the copied proof (column: {})",
+                column
+            );
+        }
+
+        let mut proof: *const u8 = ptr::null();
+        assert_eq!(
+            nomos_da_encoded_data_column_view(out_handle, column_count, &mut view, &mut view_len),
+            NomosDaResult::ErrorIndexOutOfRange
+        );
+        assert_eq!(
+            nomos_da_encoded_data_proof_view(out_handle, column_count, &mut proof),
+            NomosDaResult::ErrorIndexOutOfRange
+        );
+        assert_eq!(
+            nomos_da_encoded_data_data_view(ptr::null_mut(), &mut view, &mut view_len),
+            NomosDaResult::ErrorNullHandle
+        );
+
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
 // ============================================================================
 // Share Extraction Tests
 // ============================================================================
@@ -220,17 +716,16 @@ fn test_get_share_count() {
 
         let data = create_test_data(CHUNK_SIZE);
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-        let result = nomos_da_encoder_encode(
-            encoder,
-            data.as_ptr(),
-            data.len(),
-            &mut out_handle,
-        );
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
         assert_eq!(result, NomosDaResult::Success);
         assert!(!out_handle.is_null());
 
         let share_count = nomos_da_encoded_data_get_share_count(out_handle);
-        assert_eq!(share_count, 4, "Share count should match column count (column_count: 4, chunk_size: {})", CHUNK_SIZE);
+        assert_eq!(
+            share_count, 4,
+            "Share count should match column count (column_count: 4, chunk_size: {})",
+            CHUNK_SIZE
+        );
 
         nomos_da_encoded_data_free(out_handle);
         nomos_da_encoder_free(encoder);
@@ -242,31 +737,59 @@ fn test_get_share_valid_indices() {
     unsafe {
         let column_count = 4;
         let encoder = nomos_da_encoder_new(column_count);
-        assert!(!encoder.is_null(), "Encoder should be created (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+        assert!(
+            !encoder.is_null(),
+            "Encoder should be created (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
 
         let data = create_test_data(CHUNK_SIZE);
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-        let result = nomos_da_encoder_encode(
-            encoder,
-            data.as_ptr(),
-            data.len(),
-            &mut out_handle,
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoding should succeed (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+        assert!(
+            !out_handle.is_null(),
+            "Output handle should not be null (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
         );
-        assert_eq!(result, NomosDaResult::Success, "Encoding should succeed (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
-        assert!(!out_handle.is_null(), "Output handle should not be null (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
 
         let share_count = nomos_da_encoded_data_get_share_count(out_handle);
-        
+
         for i in 0..share_count {
             let mut share_handle: *mut ShareHandle = ptr::null_mut();
             let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
-            assert_eq!(result, NomosDaResult::Success, "Should successfully get share {} (column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
-            assert!(!share_handle.is_null(), "Share handle should not be null for index {} (column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
-            
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Should successfully get share {} (column_count: {}, chunk_size: {})",
+                i,
+                column_count,
+                CHUNK_SIZE
+            );
+            assert!(
+                !share_handle.is_null(),
+                "Share handle should not be null for index {} (column_count: {}, chunk_size: {})",
+                i,
+                column_count,
+                CHUNK_SIZE
+            );
+
             let share = &(*share_handle).share;
-            assert_eq!(share.share_idx, i as u16, "Share index should match (share_index: {}, column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
+            assert_eq!(
+                share.share_idx, i as u16,
+                "Share index should match (share_index: {}, column_count: {}, chunk_size: {})",
+                i, column_count, CHUNK_SIZE
+            );
             assert_eq!(share.rows_commitments.len(), 1, "Should have one row commitment (share_index: {}, column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
-            
+
             nomos_da_share_free(share_handle);
         }
 
@@ -280,18 +803,29 @@ fn test_get_share_invalid_index() {
     unsafe {
         let column_count = 4;
         let encoder = nomos_da_encoder_new(column_count);
-        assert!(!encoder.is_null(), "Encoder should be created (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+        assert!(
+            !encoder.is_null(),
+            "Encoder should be created (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
 
         let data = create_test_data(CHUNK_SIZE);
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-        let result = nomos_da_encoder_encode(
-            encoder,
-            data.as_ptr(),
-            data.len(),
-            &mut out_handle,
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoding should succeed (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+        assert!(
+            !out_handle.is_null(),
+            "Output handle should not be null (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
         );
-        assert_eq!(result, NomosDaResult::Success, "Encoding should succeed (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
-        assert!(!out_handle.is_null(), "Output handle should not be null (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
 
         let share_count = nomos_da_encoded_data_get_share_count(out_handle);
         let invalid_index = share_count;
@@ -311,17 +845,24 @@ fn test_get_share_different_column_counts() {
     unsafe {
         for column_count in [2, 4, 8] {
             let encoder = nomos_da_encoder_new(column_count);
-            assert!(!encoder.is_null(), "Encoder should be created (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+            assert!(
+                !encoder.is_null(),
+                "Encoder should be created (column_count: {}, chunk_size: {})",
+                column_count,
+                CHUNK_SIZE
+            );
 
             let data = create_test_data(CHUNK_SIZE);
             let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-            let result = nomos_da_encoder_encode(
-                encoder,
-                data.as_ptr(),
-                data.len(),
-                &mut out_handle,
+            let result =
+                nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Encoding should succeed (column_count: {}, chunk_size: {})",
+                column_count,
+                CHUNK_SIZE
             );
-            assert_eq!(result, NomosDaResult::Success, "Encoding should succeed (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
 
             let share_count = nomos_da_encoded_data_get_share_count(out_handle);
             assert_eq!(share_count, column_count, "Share count should match column count (column_count: {}, chunk_size: {}, share_count: {})", column_count, CHUNK_SIZE, share_count);
@@ -349,32 +890,55 @@ fn test_verifier_verify_valid_shares() {
     unsafe {
         let column_count = 4;
         let encoder = nomos_da_encoder_new(column_count);
-        assert!(!encoder.is_null(), "Encoder should be created (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+        assert!(
+            !encoder.is_null(),
+            "Encoder should be created (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
 
         let data = create_test_data(CHUNK_SIZE);
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-        let result = nomos_da_encoder_encode(
-            encoder,
-            data.as_ptr(),
-            data.len(),
-            &mut out_handle,
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoding should succeed (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+        assert!(
+            !out_handle.is_null(),
+            "Output handle should not be null (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
         );
-        assert_eq!(result, NomosDaResult::Success, "Encoding should succeed (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
-        assert!(!out_handle.is_null(), "Output handle should not be null (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
 
         let verifier = nomos_da_verifier_new();
-        assert!(!verifier.is_null(), "Verifier should be created (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+        assert!(
+            !verifier.is_null(),
+            "Verifier should be created (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
 
         let share_count = nomos_da_encoded_data_get_share_count(out_handle);
-        
+
         for i in 0..share_count {
             let mut share_handle: *mut ShareHandle = ptr::null_mut();
             let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
-            assert_eq!(result, NomosDaResult::Success, "Should successfully get share (share_index: {}, column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Should successfully get share (share_index: {}, column_count: {}, chunk_size: {})",
+                i,
+                column_count,
+                CHUNK_SIZE
+            );
             assert!(!share_handle.is_null(), "Share handle should not be null (share_index: {}, column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
 
             let verify_result = nomos_da_verifier_verify(verifier, share_handle, column_count);
-            assert!(verify_result, "Share verification should succeed (share_index: {}, column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
+            assert_eq!(verify_result, NomosDaResult::Success, "Share verification should succeed (share_index: {}, column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
 
             nomos_da_share_free(share_handle);
         }
@@ -390,31 +954,43 @@ fn test_verifier_verify_different_column_counts() {
     unsafe {
         for column_count in [2, 4, 8] {
             let encoder = nomos_da_encoder_new(column_count);
-            assert!(!encoder.is_null(), "Encoder should be created (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+            assert!(
+                !encoder.is_null(),
+                "Encoder should be created (column_count: {}, chunk_size: {})",
+                column_count,
+                CHUNK_SIZE
+            );
 
             let data = create_test_data(CHUNK_SIZE);
             let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-            let result = nomos_da_encoder_encode(
-                encoder,
-                data.as_ptr(),
-                data.len(),
-                &mut out_handle,
+            let result =
+                nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Encoding should succeed (column_count: {}, chunk_size: {})",
+                column_count,
+                CHUNK_SIZE
             );
-            assert_eq!(result, NomosDaResult::Success, "Encoding should succeed (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
 
             let verifier = nomos_da_verifier_new();
-            assert!(!verifier.is_null(), "Verifier should be created (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+            assert!(
+                !verifier.is_null(),
+                "Verifier should be created (column_count: {}, chunk_size: {})",
+                column_count,
+                CHUNK_SIZE
+            );
 
             let share_count = nomos_da_encoded_data_get_share_count(out_handle);
-            
+
             for i in 0..share_count {
                 let mut share_handle: *mut ShareHandle = ptr::null_mut();
                 let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
                 assert_eq!(result, NomosDaResult::Success, "Should successfully get share (share_index: {}, column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
-                
-            let verify_result = nomos_da_verifier_verify(verifier, share_handle, column_count);
-            assert!(verify_result, "Share verification should succeed (share_index: {}, column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
-                
+
+                let verify_result = nomos_da_verifier_verify(verifier, share_handle, column_count);
+                assert_eq!(verify_result, NomosDaResult::Success, "Share verification should succeed (share_index: {}, column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
+
                 nomos_da_share_free(share_handle);
             }
 
@@ -432,12 +1008,7 @@ fn test_verifier_verify_null_handles() {
         let encoder = nomos_da_encoder_new(column_count);
         let data = create_test_data(CHUNK_SIZE);
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-        let result = nomos_da_encoder_encode(
-            encoder,
-            data.as_ptr(),
-            data.len(),
-            &mut out_handle,
-        );
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
         assert_eq!(result, NomosDaResult::Success);
 
         let verifier = nomos_da_verifier_new();
@@ -446,14 +1017,34 @@ fn test_verifier_verify_null_handles() {
         assert_eq!(result, NomosDaResult::Success);
         assert!(!share_handle.is_null());
 
-        let verify_result_null_verifier = nomos_da_verifier_verify(ptr::null_mut(), share_handle, column_count);
-        assert!(!verify_result_null_verifier, "Verification should fail with null verifier (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+        let verify_result_null_verifier =
+            nomos_da_verifier_verify(ptr::null_mut(), share_handle, column_count);
+        assert_eq!(
+            verify_result_null_verifier,
+            NomosDaResult::ErrorNullHandle,
+            "Verification should fail with null verifier (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
 
-        let verify_result_null_share = nomos_da_verifier_verify(verifier, ptr::null_mut(), column_count);
-        assert!(!verify_result_null_share, "Verification should fail with null share (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+        let verify_result_null_share =
+            nomos_da_verifier_verify(verifier, ptr::null_mut(), column_count);
+        assert_eq!(
+            verify_result_null_share,
+            NomosDaResult::ErrorNullHandle,
+            "Verification should fail with null share (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
 
         let verify_result_invalid_domain = nomos_da_verifier_verify(verifier, share_handle, 0);
-        assert!(!verify_result_invalid_domain, "Verification should fail with invalid domain size (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+        assert_eq!(
+            verify_result_invalid_domain,
+            NomosDaResult::ErrorDomainSizeMismatch,
+            "Verification should fail with invalid domain size (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
 
         nomos_da_share_free(share_handle);
         nomos_da_verifier_free(verifier);
@@ -463,221 +1054,3791 @@ fn test_verifier_verify_null_handles() {
 }
 
 #[test]
-fn test_share_get_index() {
+fn test_verifier_verify_malformed_domain_size_does_not_unwind() {
     unsafe {
         let column_count = 4;
         let encoder = nomos_da_encoder_new(column_count);
-        assert!(!encoder.is_null(), "Encoder should be created (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
-
         let data = create_test_data(CHUNK_SIZE);
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-        let result = nomos_da_encoder_encode(
-            encoder,
-            data.as_ptr(),
-            data.len(),
-            &mut out_handle,
-        );
-        assert_eq!(result, NomosDaResult::Success, "Encoding should succeed (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
-
-        let share_count = nomos_da_encoded_data_get_share_count(out_handle);
-        assert_eq!(share_count, column_count, "Share count should equal column_count (share_count: {}, column_count: {}, chunk_size: {})", share_count, column_count, CHUNK_SIZE);
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(result, NomosDaResult::Success);
 
-        // Test getting index from multiple shares
-        for i in 0..share_count {
-            let mut share_handle: *mut ShareHandle = ptr::null_mut();
-            let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
-            assert_eq!(result, NomosDaResult::Success, "Should successfully get share (share_index: {}, column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
-            assert!(!share_handle.is_null(), "Share handle should not be null (share_index: {}, chunk_size: {})", i, CHUNK_SIZE);
+        let verifier = nomos_da_verifier_new();
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        let result = nomos_da_encoded_data_get_share(out_handle, 0, &mut share_handle);
+        assert_eq!(result, NomosDaResult::Success);
 
-            let share_idx = nomos_da_share_get_index(share_handle);
-            assert_eq!(share_idx, i as u16, "Share index should match (expected: {}, got: {}, chunk_size: {})", i, share_idx, CHUNK_SIZE);
+        for rows_domain_size in [3, 1 << 40, usize::MAX] {
+            let verify_result = nomos_da_verifier_verify(verifier, share_handle, rows_domain_size);
+            assert_eq!(verify_result, NomosDaResult::ErrorDomainSizeMismatch, "Verification should fail with a malformed domain size (rows_domain_size: {}, column_count: {})", rows_domain_size, column_count);
 
-            nomos_da_share_free(share_handle);
+            let err = nomos_da_get_last_error();
+            assert!(!err.is_null(), "A failed or panicking verification should leave an error message (rows_domain_size: {})", rows_domain_size);
+            assert!(
+                !CStr::from_ptr(err).to_bytes().is_empty(),
+                "Error message should not be empty (rows_domain_size: {})",
+                rows_domain_size
+            );
+            nomos_da_free_string(err);
         }
 
-        // Test null handle returns 0
-        let null_idx = nomos_da_share_get_index(ptr::null_mut());
-        assert_eq!(null_idx, 0, "Null share handle should return index 0 (chunk_size: {})", CHUNK_SIZE);
-
+        nomos_da_share_free(share_handle);
+        nomos_da_verifier_free(verifier);
         nomos_da_encoded_data_free(out_handle);
         nomos_da_encoder_free(encoder);
     }
 }
 
 #[test]
-fn test_share_get_commitments_null_handles() {
+fn test_panic_result_code() {
+    assert_eq!(
+        NomosDaResult::ErrorPanic as i32,
+        -4,
+        "ErrorPanic must keep its C value"
+    );
+}
+
+#[test]
+fn test_last_error_code_tracks_failure_kind() {
     unsafe {
-        let mut commitments_handle: *mut CommitmentsHandle = ptr::null_mut();
+        let verifier = nomos_da_verifier_new();
 
-        let result_null_share = nomos_da_share_get_commitments(ptr::null_mut(), &mut commitments_handle);
-        assert_eq!(result_null_share, NomosDaResult::ErrorInvalidInput, "Should fail with null share handle");
+        assert_eq!(
+            nomos_da_verifier_verify(verifier, ptr::null_mut(), 4),
+            NomosDaResult::ErrorNullHandle,
+            "Verification should fail with null share"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::ErrorNullHandle,
+            "Null share should be reported as a null handle"
+        );
 
-        let result_null_output = nomos_da_share_get_commitments(ptr::null_mut(), ptr::null_mut());
-        assert_eq!(result_null_output, NomosDaResult::ErrorInvalidInput, "Should fail with null output handle");
+        let err = nomos_da_get_last_error();
+        assert!(
+            !err.is_null(),
+            "Error message should be set for a null share"
+        );
+        nomos_da_free_string(err);
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::Success,
+            "Taking the message should clear the error code with it"
+        );
+
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        let result = nomos_da_share_from_bytes([0xffu8; 16].as_ptr(), 16, &mut share_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorDeserialization,
+            "Malformed bytes should be reported as a deserialization failure"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            result,
+            "Last error code should match the returned code"
+        );
+
+        nomos_da_verifier_free(verifier);
     }
 }
 
-// ============================================================================
-// Data Reconstruction Tests
-// ============================================================================
-
 #[test]
-fn test_reconstruct_from_all_shares() {
+fn test_verifier_verify_batch() {
     unsafe {
-        let column_count = 4;
+        let column_count = 8;
         let encoder = nomos_da_encoder_new(column_count);
-        assert!(!encoder.is_null(), "Encoder should be created (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
-
-        let original_data = create_test_data(CHUNK_SIZE);
+        let data = create_test_data(CHUNK_SIZE * 8);
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-        let result = nomos_da_encoder_encode(
-            encoder,
-            original_data.as_ptr(),
-            original_data.len(),
-            &mut out_handle,
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoding should succeed (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
         );
-        assert_eq!(result, NomosDaResult::Success, "Encoding should succeed (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
 
         let share_count = nomos_da_encoded_data_get_share_count(out_handle);
-        assert_eq!(share_count, column_count, "Share count should equal column_count (share_count: {}, column_count: {}, chunk_size: {})", share_count, column_count, CHUNK_SIZE);
-
-        // For reconstruction, we only need the first column_count/2 shares (original columns)
-        // The remaining shares are RS-encoded redundancy
-        let original_share_count = column_count / 2;
-
-        let mut share_handles: Vec<*mut ShareHandle> = Vec::with_capacity(original_share_count);
-        for i in 0..original_share_count {
+        let mut share_handles: Vec<*mut ShareHandle> = Vec::with_capacity(share_count + 1);
+        for i in 0..share_count {
             let mut share_handle: *mut ShareHandle = ptr::null_mut();
             let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
-            assert_eq!(result, NomosDaResult::Success, "Should successfully get share (share_index: {}, column_count: {}, chunk_size: {})", i, column_count, CHUNK_SIZE);
-            assert!(!share_handle.is_null(), "Share handle should not be null (share_index: {}, chunk_size: {})", i, CHUNK_SIZE);
+            assert_eq!(result, NomosDaResult::Success);
             share_handles.push(share_handle);
         }
+        share_handles.push(ptr::null_mut());
 
-        let mut reconstructed_data: *mut u8 = ptr::null_mut();
-        let mut reconstructed_len: usize = 0;
-        let reconstruct_result = nomos_da_reconstruct(
+        let verifier = nomos_da_verifier_new();
+        let mut results = vec![NomosDaResult::ErrorInternal; share_handles.len()];
+        let result = nomos_da_verifier_verify_batch(
+            verifier,
             share_handles.as_ptr(),
             share_handles.len(),
-            &mut reconstructed_data,
-            &mut reconstructed_len,
+            column_count,
+            results.as_mut_ptr(),
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Batch verification should complete (share_count: {})",
+            share_handles.len()
+        );
+        for (i, code) in results.iter().take(share_count).enumerate() {
+            assert_eq!(
+                *code,
+                NomosDaResult::Success,
+                "Share should verify in batch (share_index: {}, column_count: {})",
+                i,
+                column_count
+            );
+        }
+        assert_eq!(
+            results[share_count],
+            NomosDaResult::ErrorNullHandle,
+            "Null share handle should be reported per share"
         );
-        assert_eq!(reconstruct_result, NomosDaResult::Success, "Reconstruction should succeed (share_count: {}, chunk_size: {})", share_count, CHUNK_SIZE);
-        assert!(!reconstructed_data.is_null(), "Reconstructed data should not be null (chunk_size: {})", CHUNK_SIZE);
-        assert!(reconstructed_len > 0, "Reconstructed length should be greater than 0 (reconstructed_len: {}, chunk_size: {})", reconstructed_len, CHUNK_SIZE);
 
-        // Calculate expected reconstructed size (padded to row boundaries)
-        // Row size = (column_count / 2) * chunk_size
-        let row_size = (column_count / 2) * CHUNK_SIZE;
-        let num_rows = (original_data.len() + row_size - 1) / row_size; // Ceiling division
-        let expected_reconstructed_len = num_rows * row_size;
-        
-        assert_eq!(reconstructed_len, expected_reconstructed_len, "Reconstructed length should match expected padded size (reconstructed_len: {}, expected_len: {}, original_len: {}, row_size: {}, num_rows: {}, chunk_size: {})", reconstructed_len, expected_reconstructed_len, original_data.len(), row_size, num_rows, CHUNK_SIZE);
-        
-        let reconstructed_slice = std::slice::from_raw_parts(reconstructed_data, reconstructed_len);
-        // Check that the original data matches the beginning of reconstructed data
-        assert_eq!(&reconstructed_slice[..original_data.len()], original_data.as_slice(), "Reconstructed data prefix should match original (reconstructed_len: {}, original_len: {}, chunk_size: {})", reconstructed_len, original_data.len(), CHUNK_SIZE);
-        // Check that the padding is zeros
-        if reconstructed_len > original_data.len() {
-            let padding = &reconstructed_slice[original_data.len()..];
-            assert!(padding.iter().all(|&b| b == 0), "Padding should be zeros (reconstructed_len: {}, original_len: {}, chunk_size: {})", reconstructed_len, original_data.len(), CHUNK_SIZE);
+        let result = nomos_da_verifier_verify_batch(
+            verifier,
+            share_handles.as_ptr(),
+            share_count,
+            6,
+            results.as_mut_ptr(),
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Batch verification should complete with a malformed domain"
+        );
+        for (i, code) in results.iter().take(share_count).enumerate() {
+            assert_eq!(
+                *code,
+                NomosDaResult::ErrorDomainSizeMismatch,
+                "Non power-of-two domain should be rejected per share (share_index: {})",
+                i
+            );
+        }
+
+        let result = nomos_da_verifier_verify_batch(
+            verifier,
+            share_handles.as_ptr(),
+            share_count,
+            4,
+            results.as_mut_ptr(),
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Batch verification should complete with a small domain"
+        );
+        assert_eq!(
+            results[share_count - 1],
+            NomosDaResult::ErrorIndexOutOfRange,
+            "Share beyond the domain should be rejected per share (share_count: {})",
+            share_count
+        );
+
+        let result = nomos_da_verifier_verify_batch(
+            verifier,
+            share_handles.as_ptr(),
+            share_handles.len(),
+            0,
+            results.as_mut_ptr(),
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorDomainSizeMismatch,
+            "Batch verification should fail with zero rows domain size"
+        );
+
+        let result = nomos_da_verifier_verify_batch(
+            ptr::null_mut(),
+            share_handles.as_ptr(),
+            share_handles.len(),
+            column_count,
+            results.as_mut_ptr(),
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Batch verification should fail with null verifier"
+        );
+
+        let result = nomos_da_verifier_verify_batch(
+            verifier,
+            share_handles.as_ptr(),
+            share_handles.len(),
+            column_count,
+            ptr::null_mut(),
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Batch verification should fail with null results pointer"
+        );
+
+        for share_handle in share_handles.into_iter().filter(|handle| !handle.is_null()) {
+            nomos_da_share_free(share_handle);
+        }
+        nomos_da_verifier_free(verifier);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_verifier_verify_light_share() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoding should succeed (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        let verifier = nomos_da_verifier_new();
+        let share_count = nomos_da_encoded_data_get_share_count(out_handle);
+
+        for i in 0..share_count {
+            let mut share_handle: *mut ShareHandle = ptr::null_mut();
+            let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
+            assert_eq!(result, NomosDaResult::Success);
+
+            let mut light_share: *mut LightShareHandle = ptr::null_mut();
+            let result = nomos_da_light_share_from_share(share_handle, &mut light_share);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Should split light share (share_index: {})",
+                i
+            );
+
+            let mut commitments_handle: *mut CommitmentsHandle = ptr::null_mut();
+            let result = nomos_da_share_get_commitments(share_handle, &mut commitments_handle);
+            assert_eq!(result, NomosDaResult::Success);
+
+            // Round-trip the light share through its wire encoding
+            let mut bytes: *mut u8 = ptr::null_mut();
+            let mut bytes_len: usize = 0;
+            let result = nomos_da_light_share_to_bytes(light_share, &mut bytes, &mut bytes_len);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Light share serialization should succeed (share_index: {})",
+                i
+            );
+
+            let mut decoded: *mut LightShareHandle = ptr::null_mut();
+            let result = nomos_da_light_share_from_bytes(bytes, bytes_len, &mut decoded);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Light share deserialization should succeed (share_index: {})",
+                i
+            );
+            assert_eq!(
+                nomos_da_light_share_get_index(decoded),
+                i as u16,
+                "Light share index should match (share_index: {})",
+                i
+            );
+
+            assert_eq!(
+                nomos_da_verifier_verify_light(
+                    verifier,
+                    light_share,
+                    commitments_handle,
+                    column_count
+                ),
+                NomosDaResult::Success,
+                "Light share should verify (share_index: {}, column_count: {})",
+                i,
+                column_count
+            );
+            assert_eq!(
+                nomos_da_verifier_verify_light(verifier, decoded, commitments_handle, column_count),
+                NomosDaResult::Success,
+                "Decoded light share should verify (share_index: {}, column_count: {})",
+                i,
+                column_count
+            );
+
+            nomos_da_bytes_free(bytes, bytes_len);
+            nomos_da_light_share_free(decoded);
+            nomos_da_light_share_free(light_share);
+            nomos_da_commitments_free(commitments_handle);
+            nomos_da_share_free(share_handle);
+        }
+
+        nomos_da_verifier_free(verifier);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_verifier_verify_light_null_handles() {
+    unsafe {
+        let verifier = nomos_da_verifier_new();
+        assert_eq!(
+            nomos_da_verifier_verify_light(verifier, ptr::null_mut(), ptr::null_mut(), 4),
+            NomosDaResult::ErrorNullHandle,
+            "Verification should fail with null light share"
+        );
+        assert_eq!(
+            nomos_da_verifier_verify_light(ptr::null_mut(), ptr::null_mut(), ptr::null_mut(), 4),
+            NomosDaResult::ErrorNullHandle,
+            "Verification should fail with null verifier"
+        );
+
+        let mut light_share: *mut LightShareHandle = ptr::null_mut();
+        let result = nomos_da_light_share_from_share(ptr::null_mut(), &mut light_share);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null share handle"
+        );
+        assert!(
+            light_share.is_null(),
+            "Light share handle should be null on failure"
+        );
+
+        let garbage = [0xffu8; 5];
+        let result =
+            nomos_da_light_share_from_bytes(garbage.as_ptr(), garbage.len(), &mut light_share);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorDeserialization,
+            "Should fail with malformed bytes"
+        );
+
+        nomos_da_verifier_free(verifier);
+    }
+}
+
+#[test]
+fn test_verifier_verify_failure_reasons() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+
+        // Two blobs with the same row count and one with more rows
+        let blobs: Vec<*mut EncodedDataHandle> = [
+            create_test_data(CHUNK_SIZE * 4),
+            create_test_data(CHUNK_SIZE * 4)
+                .iter()
+                .map(|byte| byte.wrapping_add(1))
+                .collect(),
+            create_test_data(CHUNK_SIZE * 16),
+        ]
+        .iter()
+        .map(|data| {
+            let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+            let result =
+                nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Encoding should succeed (data_len: {}, column_count: {})",
+                data.len(),
+                column_count
+            );
+            out_handle
+        })
+        .collect();
+
+        let mut commitments: Vec<*mut CommitmentsHandle> = Vec::new();
+        for &blob in &blobs {
+            let mut share_handle: *mut ShareHandle = ptr::null_mut();
+            assert_eq!(
+                nomos_da_encoded_data_get_share(blob, 0, &mut share_handle),
+                NomosDaResult::Success
+            );
+            let mut commitments_handle: *mut CommitmentsHandle = ptr::null_mut();
+            assert_eq!(
+                nomos_da_share_get_commitments(share_handle, &mut commitments_handle),
+                NomosDaResult::Success
+            );
+            commitments.push(commitments_handle);
+            nomos_da_share_free(share_handle);
         }
 
-        for share_handle in share_handles {
-            nomos_da_share_free(share_handle);
-        }
-        nomos_da_reconstruct_free(reconstructed_data, reconstructed_len);
-        nomos_da_encoded_data_free(out_handle);
+        let verifier = nomos_da_verifier_new();
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoded_data_get_share(blobs[0], 3, &mut share_handle),
+            NomosDaResult::Success
+        );
+        let mut light_share: *mut LightShareHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_light_share_from_share(share_handle, &mut light_share),
+            NomosDaResult::Success
+        );
+
+        assert_eq!(
+            nomos_da_verifier_verify(verifier, share_handle, column_count),
+            NomosDaResult::Success,
+            "Unmodified share should verify"
+        );
+        assert_eq!(
+            nomos_da_verifier_verify(verifier, share_handle, 3),
+            NomosDaResult::ErrorDomainSizeMismatch,
+            "Domain size that is not a power of two should be rejected"
+        );
+        assert_eq!(
+            nomos_da_verifier_verify(verifier, share_handle, 2),
+            NomosDaResult::ErrorIndexOutOfRange,
+            "Share index 3 should not fit a domain of size 2"
+        );
+        assert_eq!(
+            nomos_da_verifier_verify_light(verifier, light_share, commitments[1], column_count),
+            NomosDaResult::ErrorProofInvalid,
+            "Proof should not verify against another blob's commitments"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::ErrorProofInvalid,
+            "Last error code should name the failed proof check"
+        );
+        assert_eq!(
+            nomos_da_verifier_verify_light(verifier, light_share, commitments[2], column_count),
+            NomosDaResult::ErrorRowCommitmentCount,
+            "Commitments for a blob with more rows should be rejected"
+        );
+
+        let empty_column = nomos_da_light_share_clone(light_share);
+        (*empty_column).light_share.column.0.clear();
+        assert_eq!(
+            nomos_da_verifier_verify_light(verifier, empty_column, commitments[0], column_count),
+            NomosDaResult::ErrorColumnLengthMismatch,
+            "An empty column should be rejected"
+        );
+
+        let err = nomos_da_get_last_error();
+        assert!(
+            !err.is_null(),
+            "Rejected share should leave an error message"
+        );
+        nomos_da_free_string(err);
+
+        nomos_da_light_share_free(empty_column);
+        nomos_da_light_share_free(light_share);
+        nomos_da_share_free(share_handle);
+        nomos_da_verifier_free(verifier);
+        for handle in commitments {
+            nomos_da_commitments_free(handle);
+        }
+        for blob in blobs {
+            nomos_da_encoded_data_free(blob);
+        }
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_share_get_index() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        assert!(
+            !encoder.is_null(),
+            "Encoder should be created (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        let data = create_test_data(CHUNK_SIZE);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoding should succeed (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        let share_count = nomos_da_encoded_data_get_share_count(out_handle);
+        assert_eq!(share_count, column_count, "Share count should equal column_count (share_count: {}, column_count: {}, chunk_size: {})", share_count, column_count, CHUNK_SIZE);
+
+        // Test getting index from multiple shares
+        for i in 0..share_count {
+            let mut share_handle: *mut ShareHandle = ptr::null_mut();
+            let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Should successfully get share (share_index: {}, column_count: {}, chunk_size: {})",
+                i,
+                column_count,
+                CHUNK_SIZE
+            );
+            assert!(
+                !share_handle.is_null(),
+                "Share handle should not be null (share_index: {}, chunk_size: {})",
+                i,
+                CHUNK_SIZE
+            );
+
+            let share_idx = nomos_da_share_get_index(share_handle);
+            assert_eq!(
+                share_idx, i as u16,
+                "Share index should match (expected: {}, got: {}, chunk_size: {})",
+                i, share_idx, CHUNK_SIZE
+            );
+
+            nomos_da_share_free(share_handle);
+        }
+
+        // Test null handle returns 0
+        let null_idx = nomos_da_share_get_index(ptr::null_mut());
+        assert_eq!(
+            null_idx, 0,
+            "Null share handle should return index 0 (chunk_size: {})",
+            CHUNK_SIZE
+        );
+
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_clone_handles() {
+    unsafe {
+        let encoder = nomos_da_encoder_new(4);
+        let data = create_test_data(CHUNK_SIZE * 2);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(result, NomosDaResult::Success);
+
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        let result = nomos_da_encoded_data_get_share(out_handle, 1, &mut share_handle);
+        assert_eq!(result, NomosDaResult::Success);
+
+        let share_copy = nomos_da_share_clone(share_handle);
+        assert!(!share_copy.is_null(), "Cloned share should not be null");
+        assert_ne!(
+            share_copy, share_handle,
+            "Clone should be a distinct allocation"
+        );
+        assert_eq!(
+            (*share_copy).share,
+            (*share_handle).share,
+            "Cloned share should equal the original"
+        );
+
+        let mut light_share: *mut LightShareHandle = ptr::null_mut();
+        let result = nomos_da_light_share_from_share(share_handle, &mut light_share);
+        assert_eq!(result, NomosDaResult::Success);
+        let light_share_copy = nomos_da_light_share_clone(light_share);
+        assert!(
+            !light_share_copy.is_null(),
+            "Cloned light share should not be null"
+        );
+        assert_eq!(
+            nomos_da_light_share_get_index(light_share_copy),
+            1,
+            "Cloned light share should keep its index"
+        );
+
+        let mut commitments_handle: *mut CommitmentsHandle = ptr::null_mut();
+        let result = nomos_da_share_get_commitments(share_handle, &mut commitments_handle);
+        assert_eq!(result, NomosDaResult::Success);
+        let commitments_copy = nomos_da_commitments_clone(commitments_handle);
+        assert!(
+            nomos_da_commitments_equal(commitments_copy, commitments_handle),
+            "Cloned commitments should equal the original"
+        );
+
+        // Freeing the originals must leave the copies usable
+        nomos_da_share_free(share_handle);
+        nomos_da_light_share_free(light_share);
+        nomos_da_commitments_free(commitments_handle);
+        assert_eq!(
+            nomos_da_share_get_index(share_copy),
+            1,
+            "Cloned share should outlive the original"
+        );
+
+        assert!(
+            nomos_da_share_clone(ptr::null_mut()).is_null(),
+            "Cloning a null share should return null"
+        );
+        assert!(
+            nomos_da_light_share_clone(ptr::null_mut()).is_null(),
+            "Cloning a null light share should return null"
+        );
+        assert!(
+            nomos_da_commitments_clone(ptr::null_mut()).is_null(),
+            "Cloning null commitments should return null"
+        );
+
+        nomos_da_share_free(share_copy);
+        nomos_da_light_share_free(light_share_copy);
+        nomos_da_commitments_free(commitments_copy);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_share_get_commitments_null_handles() {
+    unsafe {
+        let mut commitments_handle: *mut CommitmentsHandle = ptr::null_mut();
+
+        let result_null_share =
+            nomos_da_share_get_commitments(ptr::null_mut(), &mut commitments_handle);
+        assert_eq!(
+            result_null_share,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null share handle"
+        );
+
+        let result_null_output = nomos_da_share_get_commitments(ptr::null_mut(), ptr::null_mut());
+        assert_eq!(
+            result_null_output,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null output handle"
+        );
+    }
+}
+
+// ============================================================================
+// Commitments Tests
+// ============================================================================
+
+#[test]
+fn test_commitments_bytes_roundtrip() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        let data = create_test_data(CHUNK_SIZE * 6);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoding should succeed (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        let result = nomos_da_encoded_data_get_share(out_handle, 0, &mut share_handle);
+        assert_eq!(result, NomosDaResult::Success);
+
+        let mut commitments_handle: *mut CommitmentsHandle = ptr::null_mut();
+        let result = nomos_da_share_get_commitments(share_handle, &mut commitments_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Should get commitments from share"
+        );
+
+        let row_count = nomos_da_commitments_row_count(commitments_handle);
+        let expected_rows = data.len() / ((column_count / 2) * CHUNK_SIZE);
+        assert_eq!(
+            row_count, expected_rows,
+            "Row count should match encoded rows (row_count: {}, expected_rows: {})",
+            row_count, expected_rows
+        );
+        assert_eq!(
+            row_count,
+            (*out_handle).data.row_commitments.len(),
+            "Row count should match encoded row commitments"
+        );
+
+        let mut bytes: *mut u8 = ptr::null_mut();
+        let mut bytes_len: usize = 0;
+        let result = nomos_da_commitments_to_bytes(commitments_handle, &mut bytes, &mut bytes_len);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Commitments serialization should succeed"
+        );
+        assert_eq!(
+            bytes_len,
+            row_count * nomos_da_commitment_size(),
+            "Serialized commitments should be 48 bytes per row (bytes_len: {}, row_count: {})",
+            bytes_len,
+            row_count
+        );
+
+        let mut restored: *mut CommitmentsHandle = ptr::null_mut();
+        let result = nomos_da_commitments_from_bytes(bytes, bytes_len, &mut restored);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Commitments deserialization should succeed (bytes_len: {})",
+            bytes_len
+        );
+        assert!(
+            !restored.is_null(),
+            "Restored commitments handle should not be null"
+        );
+        assert_eq!(
+            nomos_da_commitments_row_count(restored),
+            row_count,
+            "Restored row count should match"
+        );
+        assert!(
+            nomos_da_commitments_equal(restored, commitments_handle),
+            "Restored commitments should equal the original"
+        );
+        assert_eq!(
+            (*restored).commitments.rows_commitments,
+            (*out_handle).data.row_commitments,
+            "Restored commitments should match encoded row commitments"
+        );
+
+        nomos_da_bytes_free(bytes, bytes_len);
+        nomos_da_commitments_free(restored);
+        nomos_da_commitments_free(commitments_handle);
+        nomos_da_share_free(share_handle);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_commitments_equal_different_blobs() {
+    unsafe {
+        let encoder = nomos_da_encoder_new(4);
+        let mut handles: Vec<*mut CommitmentsHandle> = Vec::new();
+        let mut encoded_handles: Vec<*mut EncodedDataHandle> = Vec::new();
+        for seed in [0u8, 1u8] {
+            let mut data = create_test_data(CHUNK_SIZE * 2);
+            data[0] ^= seed;
+            let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+            let result =
+                nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+            assert_eq!(result, NomosDaResult::Success);
+
+            let mut share_handle: *mut ShareHandle = ptr::null_mut();
+            let result = nomos_da_encoded_data_get_share(out_handle, 0, &mut share_handle);
+            assert_eq!(result, NomosDaResult::Success);
+
+            let mut commitments_handle: *mut CommitmentsHandle = ptr::null_mut();
+            let result = nomos_da_share_get_commitments(share_handle, &mut commitments_handle);
+            assert_eq!(result, NomosDaResult::Success);
+
+            nomos_da_share_free(share_handle);
+            handles.push(commitments_handle);
+            encoded_handles.push(out_handle);
+        }
+
+        assert!(
+            nomos_da_commitments_equal(handles[0], handles[0]),
+            "Commitments should equal themselves"
+        );
+        assert!(
+            !nomos_da_commitments_equal(handles[0], handles[1]),
+            "Commitments of different blobs should differ"
+        );
+        assert!(
+            !nomos_da_commitments_equal(handles[0], ptr::null_mut()),
+            "Comparison with a null handle should be false"
+        );
+
+        for handle in handles {
+            nomos_da_commitments_free(handle);
+        }
+        for handle in encoded_handles {
+            nomos_da_encoded_data_free(handle);
+        }
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_commitments_from_bytes_invalid_input() {
+    unsafe {
+        let mut commitments_handle: *mut CommitmentsHandle = ptr::null_mut();
+
+        let short = [0u8; COMMITMENT_SIZE - 1];
+        let result =
+            nomos_da_commitments_from_bytes(short.as_ptr(), short.len(), &mut commitments_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorBadLength,
+            "Should fail when length is not a multiple of 48"
+        );
+
+        let invalid_point = [0xffu8; COMMITMENT_SIZE];
+        let result = nomos_da_commitments_from_bytes(
+            invalid_point.as_ptr(),
+            invalid_point.len(),
+            &mut commitments_handle,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorDeserialization,
+            "Should fail with bytes that are not a valid G1 point"
+        );
+        assert!(
+            commitments_handle.is_null(),
+            "Commitments handle should be null on failure"
+        );
+
+        let result =
+            nomos_da_commitments_from_bytes(ptr::null(), COMMITMENT_SIZE, &mut commitments_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null data pointer"
+        );
+
+        assert_eq!(
+            nomos_da_commitments_row_count(ptr::null_mut()),
+            0,
+            "Null handle should have no rows"
+        );
+    }
+}
+
+#[test]
+fn test_blob_id_matches_across_handles() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(result, NomosDaResult::Success);
+
+        let mut encoded_blob_id = [0u8; BLOB_ID_SIZE];
+        let result = nomos_da_encoded_data_blob_id(out_handle, encoded_blob_id.as_mut_ptr());
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoded data blob id should succeed"
+        );
+        assert_ne!(
+            encoded_blob_id, [0u8; BLOB_ID_SIZE],
+            "Blob id should not be all zeros"
+        );
+
+        for i in 0..nomos_da_encoded_data_get_share_count(out_handle) {
+            let mut share_handle: *mut ShareHandle = ptr::null_mut();
+            let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
+            assert_eq!(result, NomosDaResult::Success);
+
+            let mut commitments_handle: *mut CommitmentsHandle = ptr::null_mut();
+            let result = nomos_da_share_get_commitments(share_handle, &mut commitments_handle);
+            assert_eq!(result, NomosDaResult::Success);
+
+            let mut share_blob_id = [0u8; BLOB_ID_SIZE];
+            let result = nomos_da_share_blob_id(share_handle, share_blob_id.as_mut_ptr());
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Share blob id should succeed (share_index: {})",
+                i
+            );
+            assert_eq!(
+                share_blob_id, encoded_blob_id,
+                "Share blob id should match encoded data (share_index: {})",
+                i
+            );
+            assert_eq!(
+                share_blob_id,
+                (*share_handle).share.blob_id(),
+                "Share blob id should match upstream computation (share_index: {})",
+                i
+            );
+
+            let mut commitments_blob_id = [0u8; BLOB_ID_SIZE];
+            let result =
+                nomos_da_commitments_blob_id(commitments_handle, commitments_blob_id.as_mut_ptr());
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Commitments blob id should succeed (share_index: {})",
+                i
+            );
+            assert_eq!(
+                commitments_blob_id, encoded_blob_id,
+                "Commitments blob id should match encoded data (share_index: {})",
+                i
+            );
+
+            nomos_da_commitments_free(commitments_handle);
+            nomos_da_share_free(share_handle);
+        }
+
+        let mut blob_id = [0u8; BLOB_ID_SIZE];
+        assert_eq!(
+            nomos_da_encoded_data_blob_id(ptr::null_mut(), blob_id.as_mut_ptr()),
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null encoded data handle"
+        );
+        assert_eq!(
+            nomos_da_encoded_data_blob_id(out_handle, ptr::null_mut()),
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null output pointer"
+        );
+        assert_eq!(
+            nomos_da_commitments_blob_id(ptr::null_mut(), blob_id.as_mut_ptr()),
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null commitments handle"
+        );
+        assert_eq!(
+            nomos_da_share_blob_id(ptr::null_mut(), blob_id.as_mut_ptr()),
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null share handle"
+        );
+
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+// ============================================================================
+// Share Serialization Tests
+// ============================================================================
+
+#[test]
+fn test_share_bytes_roundtrip() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        assert!(
+            !encoder.is_null(),
+            "Encoder should be created (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoding should succeed (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        let verifier = nomos_da_verifier_new();
+        let share_count = nomos_da_encoded_data_get_share_count(out_handle);
+
+        for i in 0..share_count {
+            let mut share_handle: *mut ShareHandle = ptr::null_mut();
+            let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Should successfully get share (share_index: {}, column_count: {})",
+                i,
+                column_count
+            );
+
+            let mut bytes: *mut u8 = ptr::null_mut();
+            let mut bytes_len: usize = 0;
+            let result = nomos_da_share_to_bytes(share_handle, &mut bytes, &mut bytes_len);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Share serialization should succeed (share_index: {})",
+                i
+            );
+            assert!(
+                !bytes.is_null() && bytes_len > 0,
+                "Serialized share should not be empty (share_index: {})",
+                i
+            );
+
+            let mut decoded_handle: *mut ShareHandle = ptr::null_mut();
+            let result = nomos_da_share_from_bytes(bytes, bytes_len, &mut decoded_handle);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Share deserialization should succeed (share_index: {}, bytes_len: {})",
+                i,
+                bytes_len
+            );
+            assert!(
+                !decoded_handle.is_null(),
+                "Decoded share handle should not be null (share_index: {})",
+                i
+            );
+
+            let original = &(*share_handle).share;
+            let decoded = &(*decoded_handle).share;
+            assert_eq!(
+                decoded.share_idx, original.share_idx,
+                "Share index should survive roundtrip (share_index: {})",
+                i
+            );
+            assert_eq!(
+                decoded.column, original.column,
+                "Column should survive roundtrip (share_index: {})",
+                i
+            );
+            assert_eq!(
+                decoded.combined_column_proof, original.combined_column_proof,
+                "Combined column proof should survive roundtrip (share_index: {})",
+                i
+            );
+            assert_eq!(
+                decoded.rows_commitments, original.rows_commitments,
+                "Row commitments should survive roundtrip (share_index: {})",
+                i
+            );
+
+            assert_eq!(
+                nomos_da_verifier_verify(verifier, decoded_handle, column_count),
+                NomosDaResult::Success,
+                "Decoded share should verify (share_index: {}, column_count: {})",
+                i,
+                column_count
+            );
+
+            nomos_da_bytes_free(bytes, bytes_len);
+            nomos_da_share_free(decoded_handle);
+            nomos_da_share_free(share_handle);
+        }
+
+        nomos_da_verifier_free(verifier);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_share_from_bytes_invalid_input() {
+    unsafe {
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+
+        let garbage = [0xffu8; 7];
+        let result = nomos_da_share_from_bytes(garbage.as_ptr(), garbage.len(), &mut share_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorDeserialization,
+            "Should fail with malformed bytes"
+        );
+        assert!(
+            share_handle.is_null(),
+            "Share handle should be null on failure"
+        );
+
+        let result = nomos_da_share_from_bytes(garbage.as_ptr(), 0, &mut share_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorBadLength,
+            "Should fail with zero length"
+        );
+
+        let result = nomos_da_share_from_bytes(ptr::null(), 4, &mut share_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null data pointer"
+        );
+
+        let result = nomos_da_share_from_bytes(garbage.as_ptr(), garbage.len(), ptr::null_mut());
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null output handle"
+        );
+    }
+}
+
+#[test]
+fn test_share_to_bytes_null_handles() {
+    unsafe {
+        let mut bytes: *mut u8 = ptr::null_mut();
+        let mut bytes_len: usize = 0;
+
+        let result = nomos_da_share_to_bytes(ptr::null_mut(), &mut bytes, &mut bytes_len);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null share handle"
+        );
+        assert!(
+            bytes.is_null(),
+            "Output data should be untouched on failure"
+        );
+    }
+}
+
+// ============================================================================
+// Data Reconstruction Tests
+// ============================================================================
+
+#[test]
+fn test_reconstruct_from_all_shares() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        assert!(
+            !encoder.is_null(),
+            "Encoder should be created (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        let original_data = create_test_data(CHUNK_SIZE);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(
+            encoder,
+            original_data.as_ptr(),
+            original_data.len(),
+            &mut out_handle,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoding should succeed (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        let share_count = nomos_da_encoded_data_get_share_count(out_handle);
+        assert_eq!(share_count, column_count, "Share count should equal column_count (share_count: {}, column_count: {}, chunk_size: {})", share_count, column_count, CHUNK_SIZE);
+
+        // For reconstruction, we only need the first column_count/2 shares (original columns)
+        // The remaining shares are RS-encoded redundancy
+        let original_share_count = column_count / 2;
+
+        let mut share_handles: Vec<*mut ShareHandle> = Vec::with_capacity(original_share_count);
+        for i in 0..original_share_count {
+            let mut share_handle: *mut ShareHandle = ptr::null_mut();
+            let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Should successfully get share (share_index: {}, column_count: {}, chunk_size: {})",
+                i,
+                column_count,
+                CHUNK_SIZE
+            );
+            assert!(
+                !share_handle.is_null(),
+                "Share handle should not be null (share_index: {}, chunk_size: {})",
+                i,
+                CHUNK_SIZE
+            );
+            share_handles.push(share_handle);
+        }
+
+        let mut reconstructed_data: *mut u8 = ptr::null_mut();
+        let mut reconstructed_len: usize = 0;
+        let reconstruct_result = nomos_da_reconstruct(
+            share_handles.as_ptr(),
+            share_handles.len(),
+            &mut reconstructed_data,
+            &mut reconstructed_len,
+        );
+        assert_eq!(
+            reconstruct_result,
+            NomosDaResult::Success,
+            "Reconstruction should succeed (share_count: {}, chunk_size: {})",
+            share_count,
+            CHUNK_SIZE
+        );
+        assert!(
+            !reconstructed_data.is_null(),
+            "Reconstructed data should not be null (chunk_size: {})",
+            CHUNK_SIZE
+        );
+        assert!(
+            reconstructed_len > 0,
+            "Reconstructed length should be greater than 0 (reconstructed_len: {}, chunk_size: {})",
+            reconstructed_len,
+            CHUNK_SIZE
+        );
+
+        // Calculate expected reconstructed size (padded to row boundaries)
+        // Row size = (column_count / 2) * chunk_size
+        let row_size = (column_count / 2) * CHUNK_SIZE;
+        let num_rows = (original_data.len() + row_size - 1) / row_size; // Ceiling division
+        let expected_reconstructed_len = num_rows * row_size;
+
+        assert_eq!(reconstructed_len, expected_reconstructed_len, "Reconstructed length should match expected padded size (reconstructed_len: {}, expected_len: {}, original_len: {}, row_size: {}, num_rows: {}, chunk_size: {})", reconstructed_len, expected_reconstructed_len, original_data.len(), row_size, num_rows, CHUNK_SIZE);
+
+        let reconstructed_slice = std::slice::from_raw_parts(reconstructed_data, reconstructed_len);
+        // Check that the original data matches the beginning of reconstructed data
+        assert_eq!(&reconstructed_slice[..original_data.len()], original_data.as_slice(), "Reconstructed data prefix should match original (reconstructed_len: {}, original_len: {}, chunk_size: {})", reconstructed_len, original_data.len(), CHUNK_SIZE);
+        // Check that the padding is zeros
+        if reconstructed_len > original_data.len() {
+            let padding = &reconstructed_slice[original_data.len()..];
+            assert!(
+                padding.iter().all(|&b| b == 0),
+                "Padding should be zeros (reconstructed_len: {}, original_len: {}, chunk_size: {})",
+                reconstructed_len,
+                original_data.len(),
+                CHUNK_SIZE
+            );
+        }
+
+        for share_handle in share_handles {
+            nomos_da_share_free(share_handle);
+        }
+        nomos_da_reconstruct_free(reconstructed_data, reconstructed_len);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_reconstruct_different_data_sizes() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        assert!(
+            !encoder.is_null(),
+            "Encoder should be created (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        for data_size in [3 * CHUNK_SIZE, 2 * CHUNK_SIZE, CHUNK_SIZE] {
+            let original_data = create_test_data(data_size);
+            let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+            let result = nomos_da_encoder_encode(
+                encoder,
+                original_data.as_ptr(),
+                original_data.len(),
+                &mut out_handle,
+            );
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Encoding should succeed (data_size: {}, column_count: {}, chunk_size: {})",
+                data_size,
+                column_count,
+                CHUNK_SIZE
+            );
+
+            let share_count = nomos_da_encoded_data_get_share_count(out_handle);
+            let original_share_count = column_count / 2;
+            let mut share_handles: Vec<*mut ShareHandle> = Vec::with_capacity(original_share_count);
+            for i in 0..original_share_count {
+                let mut share_handle: *mut ShareHandle = ptr::null_mut();
+                let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
+                assert_eq!(result, NomosDaResult::Success, "Should successfully get share (share_index: {}, data_size: {}, chunk_size: {})", i, data_size, CHUNK_SIZE);
+                share_handles.push(share_handle);
+            }
+
+            let mut reconstructed_data: *mut u8 = ptr::null_mut();
+            let mut reconstructed_len: usize = 0;
+            let reconstruct_result = nomos_da_reconstruct(
+                share_handles.as_ptr(),
+                share_handles.len(),
+                &mut reconstructed_data,
+                &mut reconstructed_len,
+            );
+            assert_eq!(
+                reconstruct_result,
+                NomosDaResult::Success,
+                "Reconstruction should succeed (data_size: {}, share_count: {}, chunk_size: {})",
+                data_size,
+                share_count,
+                CHUNK_SIZE
+            );
+
+            // Calculate expected reconstructed size (padded to row boundaries)
+            // Row size = (column_count / 2) * chunk_size
+            let row_size = (column_count / 2) * CHUNK_SIZE;
+            let num_rows = (original_data.len() + row_size - 1) / row_size; // Ceiling division
+            let expected_reconstructed_len = num_rows * row_size;
+
+            assert_eq!(reconstructed_len, expected_reconstructed_len, "Reconstructed length should match expected padded size (data_size: {}, reconstructed_len: {}, expected_len: {}, row_size: {}, num_rows: {}, chunk_size: {})", data_size, reconstructed_len, expected_reconstructed_len, row_size, num_rows, CHUNK_SIZE);
+
+            let reconstructed_slice =
+                std::slice::from_raw_parts(reconstructed_data, reconstructed_len);
+            // Check that the original data matches the beginning of reconstructed data
+            assert_eq!(&reconstructed_slice[..original_data.len()], original_data.as_slice(), "Reconstructed data prefix should match original (data_size: {}, reconstructed_len: {}, original_len: {}, chunk_size: {})", data_size, reconstructed_len, original_data.len(), CHUNK_SIZE);
+            // Check that the padding is zeros
+            if reconstructed_len > original_data.len() {
+                let padding = &reconstructed_slice[original_data.len()..];
+                assert!(padding.iter().all(|&b| b == 0), "Padding should be zeros (data_size: {}, reconstructed_len: {}, original_len: {}, chunk_size: {})", data_size, reconstructed_len, original_data.len(), CHUNK_SIZE);
+            }
+
+            for share_handle in share_handles {
+                nomos_da_share_free(share_handle);
+            }
+            nomos_da_reconstruct_free(reconstructed_data, reconstructed_len);
+            nomos_da_encoded_data_free(out_handle);
+        }
+
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_reconstruct_null_handles() {
+    unsafe {
+        let mut data: *mut u8 = ptr::null_mut();
+        let mut len: usize = 0;
+
+        let result_null_shares = nomos_da_reconstruct(ptr::null(), 4, &mut data, &mut len);
+        assert_eq!(
+            result_null_shares,
+            NomosDaResult::ErrorNullHandle,
+            "Reconstruction should fail with null shares array"
+        );
+
+        let result_null_output = nomos_da_reconstruct(ptr::null(), 4, ptr::null_mut(), &mut len);
+        assert_eq!(
+            result_null_output,
+            NomosDaResult::ErrorNullHandle,
+            "Reconstruction should fail with null output data pointer"
+        );
+
+        let result_null_len = nomos_da_reconstruct(ptr::null(), 4, &mut data, ptr::null_mut());
+        assert_eq!(
+            result_null_len,
+            NomosDaResult::ErrorNullHandle,
+            "Reconstruction should fail with null length pointer"
+        );
+
+        let result_zero_count = nomos_da_reconstruct(ptr::null(), 0, &mut data, &mut len);
+        assert_eq!(
+            result_zero_count,
+            NomosDaResult::ErrorBadLength,
+            "Reconstruction should fail with zero share count"
+        );
+    }
+}
+
+unsafe fn reconstruct_from_indices(
+    out_handle: *mut EncodedDataHandle,
+    indices: &[usize],
+    column_count: usize,
+) -> (NomosDaResult, Vec<u8>) {
+    let mut share_handles: Vec<*mut ShareHandle> = Vec::with_capacity(indices.len());
+    for &i in indices {
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Should successfully get share (share_index: {}, column_count: {})",
+            i,
+            column_count
+        );
+        share_handles.push(share_handle);
+    }
+
+    let mut reconstructed_data: *mut u8 = ptr::null_mut();
+    let mut reconstructed_len: usize = 0;
+    let result = nomos_da_reconstruct_with_missing_data(
+        share_handles.as_ptr(),
+        share_handles.len(),
+        column_count,
+        &mut reconstructed_data,
+        &mut reconstructed_len,
+    );
+    let reconstructed = if result == NomosDaResult::Success {
+        let bytes = std::slice::from_raw_parts(reconstructed_data, reconstructed_len).to_vec();
+        nomos_da_reconstruct_free(reconstructed_data, reconstructed_len);
+        bytes
+    } else {
+        Vec::new()
+    };
+
+    for share_handle in share_handles {
+        nomos_da_share_free(share_handle);
+    }
+    (result, reconstructed)
+}
+
+#[test]
+fn test_reconstruct_with_missing_columns() {
+    unsafe {
+        let column_count = 8;
+        let encoder = nomos_da_encoder_new(column_count);
+        assert!(
+            !encoder.is_null(),
+            "Encoder should be created (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        let original_data = create_test_data(CHUNK_SIZE * 8);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(
+            encoder,
+            original_data.as_ptr(),
+            original_data.len(),
+            &mut out_handle,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoding should succeed (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        let subsets: [&[usize]; 4] = [
+            &[0, 1, 2, 3],
+            &[4, 5, 6, 7],
+            &[0, 2, 5, 7],
+            &[7, 6, 1, 1, 4],
+        ];
+        for indices in subsets {
+            let (result, reconstructed) =
+                reconstruct_from_indices(out_handle, indices, column_count);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Reconstruction should succeed (indices: {:?}, column_count: {})",
+                indices,
+                column_count
+            );
+            assert_eq!(
+                reconstructed, original_data,
+                "Reconstructed data should match original (indices: {:?}, column_count: {})",
+                indices, column_count
+            );
+        }
+
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_reconstruct_with_missing_columns_insufficient_shares() {
+    unsafe {
+        let column_count = 8;
+        let encoder = nomos_da_encoder_new(column_count);
+        let original_data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(
+            encoder,
+            original_data.as_ptr(),
+            original_data.len(),
+            &mut out_handle,
+        );
+        assert_eq!(result, NomosDaResult::Success);
+
+        // Four handles but only three distinct indices
+        let (result, _) = reconstruct_from_indices(out_handle, &[1, 3, 3, 6], column_count);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorInsufficientShares,
+            "Reconstruction should fail with too few distinct indices (column_count: {})",
+            column_count
+        );
+
+        let (result, _) = reconstruct_from_indices(out_handle, &[0, 1, 2, 3], 6);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorDomainSizeMismatch,
+            "Reconstruction should fail with a column count that is not a power of two"
+        );
+
+        let (result, _) = reconstruct_from_indices(out_handle, &[0, 1, 5], 4);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorIndexOutOfRange,
+            "Reconstruction should fail when an index exceeds the column count"
+        );
+
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_reconstruct_and_verify() {
+    unsafe {
+        let column_count = 8;
+        let encoder = nomos_da_encoder_new(column_count);
+        let original_data = create_test_data(CHUNK_SIZE * 8);
+        let other_data: Vec<u8> = original_data
+            .iter()
+            .map(|byte| byte.wrapping_add(1))
+            .collect();
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let mut other_handle: *mut EncodedDataHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_encode(
+                encoder,
+                original_data.as_ptr(),
+                original_data.len(),
+                &mut out_handle
+            ),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            nomos_da_encoder_encode(
+                encoder,
+                other_data.as_ptr(),
+                other_data.len(),
+                &mut other_handle
+            ),
+            NomosDaResult::Success
+        );
+
+        let mut shares: Vec<*mut ShareHandle> = Vec::new();
+        for index in [1, 3, 4, 6] {
+            let mut share_handle: *mut ShareHandle = ptr::null_mut();
+            assert_eq!(
+                nomos_da_encoded_data_get_share(out_handle, index, &mut share_handle),
+                NomosDaResult::Success
+            );
+            shares.push(share_handle);
+        }
+
+        let mut data: *mut u8 = ptr::null_mut();
+        let mut len: usize = 0;
+        let result = nomos_da_reconstruct_and_verify(
+            encoder,
+            shares.as_ptr(),
+            shares.len(),
+            column_count,
+            &mut data,
+            &mut len,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Honest shares should pass the re-encoding check"
+        );
+        assert_eq!(
+            std::slice::from_raw_parts(data, len),
+            original_data.as_slice(),
+            "Verified reconstruction should return the original data"
+        );
+        nomos_da_reconstruct_free(data, len);
+
+        // Consistent across shares, but committing to different data
+        for &share in &shares {
+            (*share).share.rows_commitments = (*other_handle).data.row_commitments.clone();
+        }
+        let mut data: *mut u8 = ptr::null_mut();
+        let mut len: usize = 0;
+        let result = nomos_da_reconstruct_with_missing_data(
+            shares.as_ptr(),
+            shares.len(),
+            column_count,
+            &mut data,
+            &mut len,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Plain reconstruction cannot tell the commitments are wrong"
+        );
+        nomos_da_reconstruct_free(data, len);
+
+        let mut data: *mut u8 = ptr::null_mut();
+        let mut len: usize = 0;
+        let result = nomos_da_reconstruct_and_verify(
+            encoder,
+            shares.as_ptr(),
+            shares.len(),
+            column_count,
+            &mut data,
+            &mut len,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorReencodingMismatch,
+            "Foreign commitments should be detected"
+        );
+        assert!(data.is_null(), "No data should be returned on mismatch");
+
+        let result = nomos_da_reconstruct_and_verify(
+            ptr::null_mut(),
+            shares.as_ptr(),
+            shares.len(),
+            column_count,
+            &mut data,
+            &mut len,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Verified reconstruction should require an encoder"
+        );
+
+        let result = nomos_da_reconstruct_and_verify(
+            encoder,
+            shares.as_ptr(),
+            shares.len(),
+            column_count * 2,
+            &mut data,
+            &mut len,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorInvalidInput,
+            "A column count the encoder was not built with should be rejected"
+        );
+        assert!(
+            data.is_null(),
+            "No data should be returned for a column count mismatch"
+        );
+
+        for share in shares {
+            nomos_da_share_free(share);
+        }
+        nomos_da_encoded_data_free(other_handle);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_reconstruct_and_verify_with_loaded_params() {
+    unsafe {
+        let column_count = 4;
+        let global_parameters = global_parameters_from_randomness(&mut rand::thread_rng());
+        let params = nomos_da_ffi::params::to_bytes(&global_parameters)
+            .expect("Parameters should serialize");
+        let custom = nomos_da_encoder_new_with_params(column_count, params.as_ptr(), params.len());
+        assert!(
+            !custom.is_null(),
+            "Encoder should load generated parameters (params_len: {})",
+            params.len()
+        );
+        let builtin = nomos_da_encoder_new(column_count);
+
+        let original_data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_encode(
+                custom,
+                original_data.as_ptr(),
+                original_data.len(),
+                &mut out_handle
+            ),
+            NomosDaResult::Success
+        );
+        let mut shares: Vec<*mut ShareHandle> = Vec::new();
+        for index in [0, 3] {
+            let mut share_handle: *mut ShareHandle = ptr::null_mut();
+            assert_eq!(
+                nomos_da_encoded_data_get_share(out_handle, index, &mut share_handle),
+                NomosDaResult::Success
+            );
+            shares.push(share_handle);
+        }
+
+        let mut data: *mut u8 = ptr::null_mut();
+        let mut len: usize = 0;
+        let result = nomos_da_reconstruct_and_verify(
+            custom,
+            shares.as_ptr(),
+            shares.len(),
+            column_count,
+            &mut data,
+            &mut len,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Re-encoding with the loaded parameters should match"
+        );
+        assert_eq!(
+            std::slice::from_raw_parts(data, len),
+            original_data.as_slice(),
+            "Verified reconstruction should return the original data"
+        );
+        nomos_da_reconstruct_free(data, len);
+
+        let mut data: *mut u8 = ptr::null_mut();
+        let mut len: usize = 0;
+        let result = nomos_da_reconstruct_and_verify(
+            builtin,
+            shares.as_ptr(),
+            shares.len(),
+            column_count,
+            &mut data,
+            &mut len,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorReencodingMismatch,
+            "Re-encoding with other parameters should not match"
+        );
+
+        for share in shares {
+            nomos_da_share_free(share);
+        }
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(builtin);
+        nomos_da_encoder_free(custom);
+    }
+}
+
+unsafe fn assemble_from_indices(
+    encoded: *mut EncodedDataHandle,
+    indices: &[usize],
+    column_count: usize,
+) -> (NomosDaResult, *mut EncodedDataHandle) {
+    let mut share_handles: Vec<*mut ShareHandle> = Vec::with_capacity(indices.len());
+    for &index in indices {
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoded_data_get_share(encoded, index, &mut share_handle),
+            NomosDaResult::Success
+        );
+        share_handles.push(share_handle);
+    }
+
+    let mut assembled: *mut EncodedDataHandle = ptr::null_mut();
+    let result = nomos_da_encoded_data_from_shares(
+        share_handles.as_ptr(),
+        share_handles.len(),
+        column_count,
+        &mut assembled,
+    );
+    for share_handle in share_handles {
+        nomos_da_share_free(share_handle);
+    }
+    (result, assembled)
+}
+
+#[test]
+fn test_encoded_data_from_shares() {
+    unsafe {
+        let column_count = 8;
+        let encoder = nomos_da_encoder_new(column_count);
+        let original_data = create_test_data(CHUNK_SIZE * 8);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_encode(
+                encoder,
+                original_data.as_ptr(),
+                original_data.len(),
+                &mut out_handle
+            ),
+            NomosDaResult::Success
+        );
+
+        let (result, assembled) =
+            assemble_from_indices(out_handle, &[5, 0, 7, 2, 1, 6, 3, 4], column_count);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Assembling every share should succeed"
+        );
+        let original = &(*out_handle).data;
+        let rebuilt = &(*assembled).data;
+        assert_eq!(
+            rebuilt.data, original.data,
+            "Original data should be recovered"
+        );
+        assert_eq!(
+            rebuilt.extended_data.0.len(),
+            original.extended_data.0.len(),
+            "Row count should match"
+        );
+        for (row, (rebuilt_row, original_row)) in rebuilt
+            .extended_data
+            .0
+            .iter()
+            .zip(&original.extended_data.0)
+            .enumerate()
+        {
+            assert_eq!(
+                rebuilt_row.0, original_row.0,
+                "Extended row {} should match",
+                row
+            );
+        }
+        assert_eq!(
+            rebuilt.row_commitments, original.row_commitments,
+            "Row commitments should match"
+        );
+        assert_eq!(
+            rebuilt.combined_column_proofs, original.combined_column_proofs,
+            "Proofs should be ordered by column"
+        );
+
+        let mut original_blob_id = [0u8; BLOB_ID_SIZE];
+        let mut rebuilt_blob_id = [0u8; BLOB_ID_SIZE];
+        assert_eq!(
+            nomos_da_encoded_data_blob_id(out_handle, original_blob_id.as_mut_ptr()),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            nomos_da_encoded_data_blob_id(assembled, rebuilt_blob_id.as_mut_ptr()),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            rebuilt_blob_id, original_blob_id,
+            "Blob id should survive reassembly"
+        );
+
+        // Shares re-served from the assembled data are identical to the originals
+        for index in 0..column_count {
+            let rebuilt_share = rebuilt.to_da_share(index).unwrap();
+            let original_share = original.to_da_share(index).unwrap();
+            assert_eq!(
+                rebuilt_share.column, original_share.column,
+                "Column {} should match",
+                index
+            );
+            assert_eq!(
+                rebuilt_share.combined_column_proof, original_share.combined_column_proof,
+                "Proof {} should match",
+                index
+            );
+        }
+
+        nomos_da_encoded_data_free(assembled);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_encoded_data_from_shares_invalid_sets() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let other_data = create_test_data(CHUNK_SIZE * 6);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let mut other_handle: *mut EncodedDataHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            nomos_da_encoder_encode(
+                encoder,
+                other_data.as_ptr(),
+                other_data.len(),
+                &mut other_handle
+            ),
+            NomosDaResult::Success
+        );
+
+        let (result, assembled) = assemble_from_indices(out_handle, &[0, 1, 2], column_count);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorInsufficientShares,
+            "A missing column should be rejected"
+        );
+        assert!(
+            assembled.is_null(),
+            "Output handle should stay null on failure"
+        );
+
+        let (result, _) = assemble_from_indices(out_handle, &[0, 1, 2, 2], column_count);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorInvalidInput,
+            "A repeated index should be rejected"
+        );
+
+        let mut shares: Vec<*mut ShareHandle> = Vec::new();
+        for (handle, index) in [
+            (out_handle, 0),
+            (out_handle, 1),
+            (out_handle, 2),
+            (other_handle, 3),
+        ] {
+            let mut share_handle: *mut ShareHandle = ptr::null_mut();
+            assert_eq!(
+                nomos_da_encoded_data_get_share(handle, index, &mut share_handle),
+                NomosDaResult::Success
+            );
+            shares.push(share_handle);
+        }
+        let mut assembled: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoded_data_from_shares(
+            shares.as_ptr(),
+            shares.len(),
+            column_count,
+            &mut assembled,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorCommitmentMismatch,
+            "Shares of another blob should be rejected"
+        );
+        for share in shares {
+            nomos_da_share_free(share);
+        }
+
+        nomos_da_encoded_data_free(other_handle);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+// ============================================================================
+// Padded Encoding Tests
+// ============================================================================
+
+#[test]
+fn test_pad_unpad_roundtrip() {
+    for data_size in [
+        0,
+        1,
+        CHUNK_SIZE - LENGTH_HEADER_SIZE,
+        CHUNK_SIZE,
+        3 * CHUNK_SIZE + 7,
+        1000,
+    ] {
+        let data = create_test_data(data_size);
+        let padded = pad(&data);
+        assert_eq!(
+            padded.len() % CHUNK_SIZE,
+            0,
+            "Padded length should be a multiple of chunk size (data_size: {}, padded_len: {})",
+            data_size,
+            padded.len()
+        );
+        assert!(
+            padded.len() >= data_size + LENGTH_HEADER_SIZE,
+            "Padded data should hold header and payload (data_size: {})",
+            data_size
+        );
+        assert_eq!(
+            unpad(&padded).unwrap(),
+            data.as_slice(),
+            "Unpadded data should match original (data_size: {})",
+            data_size
+        );
+
+        // Reconstruction appends zero rows, which must still unpad cleanly
+        let mut row_padded = padded.clone();
+        row_padded.extend_from_slice(&[0u8; 2 * CHUNK_SIZE]);
+        assert_eq!(
+            unpad(&row_padded).unwrap(),
+            data.as_slice(),
+            "Extra zero padding should be ignored (data_size: {})",
+            data_size
+        );
+    }
+}
+
+#[test]
+fn test_unpad_rejects_malformed_input() {
+    assert_eq!(
+        unpad(&[0u8; 3]),
+        Err(PaddingError::MissingHeader { data_len: 3 })
+    );
+
+    let mut padded = pad(&create_test_data(10));
+    padded[0] = 200;
+    assert!(
+        matches!(unpad(&padded), Err(PaddingError::LengthOutOfBounds { .. })),
+        "Oversized length header should be rejected"
+    );
+
+    let mut padded = pad(&create_test_data(10));
+    let last = padded.len() - 1;
+    padded[last] = 1;
+    assert_eq!(
+        unpad(&padded),
+        Err(PaddingError::NonZeroPadding { offset: last }),
+        "Non-zero padding should be rejected"
+    );
+}
+
+#[test]
+fn test_encode_padded_reconstruct_padded_roundtrip() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        assert!(
+            !encoder.is_null(),
+            "Encoder should be created (column_count: {}, chunk_size: {})",
+            column_count,
+            CHUNK_SIZE
+        );
+
+        for data_size in [0, 1, CHUNK_SIZE - 1, CHUNK_SIZE + 1, 250] {
+            let original_data = create_test_data(data_size);
+            let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+            let result = nomos_da_encoder_encode_padded(
+                encoder,
+                if data_size == 0 {
+                    ptr::null()
+                } else {
+                    original_data.as_ptr()
+                },
+                original_data.len(),
+                &mut out_handle,
+            );
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Padded encoding should succeed (data_size: {}, column_count: {})",
+                data_size,
+                column_count
+            );
+            assert!(
+                !out_handle.is_null(),
+                "Output handle should not be null (data_size: {})",
+                data_size
+            );
+            assert_eq!(
+                (*out_handle).data.data,
+                pad(&original_data),
+                "Encoded data should hold the padded payload (data_size: {})",
+                data_size
+            );
+
+            let share_count = nomos_da_encoded_data_get_share_count(out_handle);
+            let mut share_handles: Vec<*mut ShareHandle> = Vec::with_capacity(share_count);
+            for i in 0..share_count {
+                let mut share_handle: *mut ShareHandle = ptr::null_mut();
+                let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
+                assert_eq!(result, NomosDaResult::Success);
+                share_handles.push(share_handle);
+            }
+
+            let mut reconstructed_data: *mut u8 = ptr::null_mut();
+            let mut reconstructed_len: usize = 0;
+            let result = nomos_da_reconstruct_padded(
+                share_handles.as_ptr(),
+                share_handles.len(),
+                column_count,
+                &mut reconstructed_data,
+                &mut reconstructed_len,
+            );
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Padded reconstruction should succeed (data_size: {})",
+                data_size
+            );
+            assert_eq!(reconstructed_len, data_size, "Reconstructed length should match original exactly (data_size: {}, reconstructed_len: {})", data_size, reconstructed_len);
+            if data_size > 0 {
+                let reconstructed =
+                    std::slice::from_raw_parts(reconstructed_data, reconstructed_len);
+                assert_eq!(
+                    reconstructed,
+                    original_data.as_slice(),
+                    "Reconstructed data should match original (data_size: {})",
+                    data_size
+                );
+            }
+
+            nomos_da_reconstruct_free(reconstructed_data, reconstructed_len);
+            for share_handle in share_handles {
+                nomos_da_share_free(share_handle);
+            }
+            nomos_da_encoded_data_free(out_handle);
+        }
+
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_encode_padded_null_handles() {
+    unsafe {
+        let data = create_test_data(10);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+
+        let result = nomos_da_encoder_encode_padded(
+            ptr::null_mut(),
+            data.as_ptr(),
+            data.len(),
+            &mut out_handle,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null encoder handle"
+        );
+
+        let encoder = nomos_da_encoder_new(4);
+        let result = nomos_da_encoder_encode_padded(encoder, ptr::null(), 10, &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null data pointer and non-zero length"
+        );
+
+        let result =
+            nomos_da_encoder_encode_padded(encoder, data.as_ptr(), data.len(), ptr::null_mut());
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null output handle"
+        );
+        assert!(
+            out_handle.is_null(),
+            "Output handle should be null on failure"
+        );
+
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+// ============================================================================
+// Custom Parameters Tests
+// ============================================================================
+
+unsafe fn take_bytes(data: *mut u8, len: usize) -> Vec<u8> {
+    let bytes = std::slice::from_raw_parts(data, len).to_vec();
+    nomos_da_bytes_free(data, len);
+    bytes
+}
+
+#[test]
+fn test_encoder_params_export_and_reload() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+
+        let mut params_data: *mut u8 = ptr::null_mut();
+        let mut params_len: usize = 0;
+        let result = nomos_da_encoder_export_params(encoder, &mut params_data, &mut params_len);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Exporting built-in parameters should succeed"
+        );
+        let params = take_bytes(params_data, params_len);
+        assert!(
+            !params.is_empty(),
+            "Exported parameters should not be empty"
+        );
+
+        let reloaded =
+            nomos_da_encoder_new_with_params(column_count, params.as_ptr(), params.len());
+        assert!(
+            !reloaded.is_null(),
+            "Encoder should load exported parameters (params_len: {})",
+            params.len()
+        );
+        assert!(
+            nomos_da_encoder_new_with_params(0, params.as_ptr(), params.len()).is_null(),
+            "Zero columns should be rejected with loaded parameters"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::ErrorInvalidInput
+        );
+
+        let path = std::env::temp_dir().join(format!("nomos-da-params-{}.bin", std::process::id()));
+        std::fs::write(&path, &params).expect("Failed to write parameters fixture");
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+        let from_file = nomos_da_encoder_new_with_params_file(column_count, c_path.as_ptr());
+        std::fs::remove_file(&path).ok();
+        assert!(
+            !from_file.is_null(),
+            "Encoder should load parameters from a file"
+        );
+
+        let mut reexported_data: *mut u8 = ptr::null_mut();
+        let mut reexported_len: usize = 0;
+        assert_eq!(
+            nomos_da_encoder_export_params(from_file, &mut reexported_data, &mut reexported_len),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            take_bytes(reexported_data, reexported_len),
+            params,
+            "Re-exported parameters should match the loaded ones"
+        );
+
+        // Encoders sharing parameters must produce the same blob
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let mut blob_ids = Vec::new();
+        for handle in [encoder, reloaded, from_file] {
+            let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+            assert_eq!(
+                nomos_da_encoder_encode(handle, data.as_ptr(), data.len(), &mut out_handle),
+                NomosDaResult::Success
+            );
+            let mut blob_id = [0u8; BLOB_ID_SIZE];
+            assert_eq!(
+                nomos_da_encoded_data_blob_id(out_handle, blob_id.as_mut_ptr()),
+                NomosDaResult::Success
+            );
+            blob_ids.push(blob_id);
+            nomos_da_encoded_data_free(out_handle);
+        }
+        assert!(
+            blob_ids.windows(2).all(|pair| pair[0] == pair[1]),
+            "Blob ids should not depend on how parameters were loaded"
+        );
+
+        nomos_da_encoder_free(from_file);
+        nomos_da_encoder_free(reloaded);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_verifier_key_export_and_reload() {
+    unsafe {
+        let column_count = 4;
+        let encoder = nomos_da_encoder_new(column_count);
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle),
+            NomosDaResult::Success
+        );
+
+        let verifier = nomos_da_verifier_new();
+        let mut key_data: *mut u8 = ptr::null_mut();
+        let mut key_len: usize = 0;
+        assert_eq!(
+            nomos_da_verifier_export_key(verifier, &mut key_data, &mut key_len),
+            NomosDaResult::Success,
+            "Exporting the built-in key should succeed"
+        );
+        let key = take_bytes(key_data, key_len);
+
+        let reloaded = nomos_da_verifier_new_with_key(key.as_ptr(), key.len());
+        assert!(
+            !reloaded.is_null(),
+            "Verifier should load the exported key (key_len: {})",
+            key.len()
+        );
+
+        let path = std::env::temp_dir().join(format!("nomos-da-key-{}.bin", std::process::id()));
+        std::fs::write(&path, &key).expect("Failed to write key fixture");
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+        let from_file = nomos_da_verifier_new_with_key_file(c_path.as_ptr());
+        std::fs::remove_file(&path).ok();
+        assert!(
+            !from_file.is_null(),
+            "Verifier should load the key from a file"
+        );
+
+        for i in 0..nomos_da_encoded_data_get_share_count(out_handle) {
+            let mut share_handle: *mut ShareHandle = ptr::null_mut();
+            assert_eq!(
+                nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle),
+                NomosDaResult::Success
+            );
+            assert_eq!(
+                nomos_da_verifier_verify(reloaded, share_handle, column_count),
+                NomosDaResult::Success,
+                "Share should verify with the reloaded key (share_index: {})",
+                i
+            );
+            assert_eq!(
+                nomos_da_verifier_verify(from_file, share_handle, column_count),
+                NomosDaResult::Success,
+                "Share should verify with the key file (share_index: {})",
+                i
+            );
+            nomos_da_share_free(share_handle);
+        }
+
+        nomos_da_verifier_free(from_file);
+        nomos_da_verifier_free(reloaded);
+        nomos_da_verifier_free(verifier);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_custom_params_invalid_input() {
+    unsafe {
+        let garbage = [0xffu8; 64];
+        assert!(
+            nomos_da_encoder_new_with_params(4, garbage.as_ptr(), garbage.len()).is_null(),
+            "Malformed parameters should be rejected"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::ErrorDeserialization
+        );
+
+        assert!(
+            nomos_da_encoder_new_with_params(4, garbage.as_ptr(), 0).is_null(),
+            "Empty parameters should be rejected"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::ErrorBadLength
+        );
+
+        assert!(
+            nomos_da_verifier_new_with_key(garbage.as_ptr(), garbage.len()).is_null(),
+            "Malformed key should be rejected"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::ErrorDeserialization
+        );
+
+        let missing = CString::new("/nonexistent/nomos-da/params.bin").unwrap();
+        assert!(
+            nomos_da_encoder_new_with_params_file(4, missing.as_ptr()).is_null(),
+            "Missing parameters file should be rejected"
+        );
+        assert_eq!(nomos_da_get_last_error_code(), NomosDaResult::ErrorIo);
+        assert!(
+            nomos_da_verifier_new_with_key_file(missing.as_ptr()).is_null(),
+            "Missing key file should be rejected"
+        );
+        assert_eq!(nomos_da_get_last_error_code(), NomosDaResult::ErrorIo);
+
+        assert!(
+            nomos_da_encoder_new_with_params(4, ptr::null(), 0).is_null(),
+            "Null parameters pointer should be rejected"
+        );
+        assert!(
+            nomos_da_verifier_new_with_key_file(ptr::null()).is_null(),
+            "Null path should be rejected"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::ErrorNullHandle
+        );
+    }
+}
+
+// ============================================================================
+// Share Store Tests
+// ============================================================================
+
+unsafe fn open_test_store(name: &str) -> (std::path::PathBuf, *mut ShareStoreHandle) {
+    let path = std::env::temp_dir().join(format!("nomos-da-store-{}-{}", name, std::process::id()));
+    std::fs::remove_dir_all(&path).ok();
+    let c_path = CString::new(path.to_str().unwrap()).unwrap();
+    let store = nomos_da_store_open(c_path.as_ptr());
+    assert!(!store.is_null(), "Store should open at {}", path.display());
+    (path, store)
+}
+
+unsafe fn stored_columns(store: *mut ShareStoreHandle, blob_id: &[u8; BLOB_ID_SIZE]) -> Vec<u16> {
+    let mut columns: *mut u16 = ptr::null_mut();
+    let mut count: usize = 0;
+    let result = nomos_da_store_list_columns(store, blob_id.as_ptr(), &mut columns, &mut count);
+    assert_eq!(
+        result,
+        NomosDaResult::Success,
+        "Listing stored columns should succeed"
+    );
+    if columns.is_null() {
+        return Vec::new();
+    }
+    let listed = std::slice::from_raw_parts(columns, count).to_vec();
+    nomos_da_store_columns_free(columns, count);
+    listed
+}
+
+#[test]
+fn test_store_put_get_and_list() {
+    unsafe {
+        let (path, store) = open_test_store("roundtrip");
+        let encoder = nomos_da_encoder_new(4);
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle),
+            NomosDaResult::Success
+        );
+        let mut blob_id = [0u8; BLOB_ID_SIZE];
+        assert_eq!(
+            nomos_da_encoded_data_blob_id(out_handle, blob_id.as_mut_ptr()),
+            NomosDaResult::Success
+        );
+
+        assert!(
+            stored_columns(store, &blob_id).is_empty(),
+            "A new store should hold no columns"
+        );
+
+        for index in [3, 1] {
+            let mut share: *mut ShareHandle = ptr::null_mut();
+            assert_eq!(
+                nomos_da_encoded_data_get_share(out_handle, index, &mut share),
+                NomosDaResult::Success
+            );
+            assert_eq!(
+                nomos_da_store_put(store, share),
+                NomosDaResult::Success,
+                "Storing share {} should succeed",
+                index
+            );
+            nomos_da_share_free(share);
+        }
+        assert_eq!(
+            stored_columns(store, &blob_id),
+            vec![1, 3],
+            "Stored columns should be listed in order"
+        );
+
+        // A reopened store sees the shares written by the previous one
+        nomos_da_store_free(store);
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+        let store = nomos_da_store_open(c_path.as_ptr());
+        assert!(!store.is_null(), "Store should reopen");
+
+        let mut loaded: *mut ShareHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_store_get(store, blob_id.as_ptr(), 3, &mut loaded),
+            NomosDaResult::Success
+        );
+        let expected = (*out_handle).data.to_da_share(3).unwrap();
+        assert_eq!(
+            (*loaded).share.share_idx,
+            3,
+            "Loaded share should keep its index"
+        );
+        assert_eq!(
+            (*loaded).share.column,
+            expected.column,
+            "Loaded column should match the stored one"
+        );
+        assert_eq!(
+            (*loaded).share.rows_commitments,
+            expected.rows_commitments,
+            "Loaded commitments should match the stored ones"
+        );
+        nomos_da_share_free(loaded);
+
+        let mut missing: *mut ShareHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_store_get(store, blob_id.as_ptr(), 0, &mut missing),
+            NomosDaResult::ErrorNotFound,
+            "Unstored column should not be found"
+        );
+        assert!(
+            missing.is_null(),
+            "Share handle should stay null when not found"
+        );
+
+        nomos_da_store_free(store);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+        std::fs::remove_dir_all(&path).ok();
+    }
+}
+
+#[test]
+fn test_store_prune_by_age() {
+    unsafe {
+        let (path, store) = open_test_store("prune");
+        let encoder = nomos_da_encoder_new(4);
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle),
+            NomosDaResult::Success
+        );
+        let mut blob_id = [0u8; BLOB_ID_SIZE];
+        assert_eq!(
+            nomos_da_encoded_data_blob_id(out_handle, blob_id.as_mut_ptr()),
+            NomosDaResult::Success
+        );
+        for index in 0..2 {
+            let mut share: *mut ShareHandle = ptr::null_mut();
+            assert_eq!(
+                nomos_da_encoded_data_get_share(out_handle, index, &mut share),
+                NomosDaResult::Success
+            );
+            assert_eq!(nomos_da_store_put(store, share), NomosDaResult::Success);
+            nomos_da_share_free(share);
+        }
+
+        let mut removed: usize = usize::MAX;
+        assert_eq!(
+            nomos_da_store_prune(store, 3600, &mut removed),
+            NomosDaResult::Success
+        );
+        assert_eq!(removed, 0, "Fresh shares should survive pruning");
+        assert_eq!(stored_columns(store, &blob_id), vec![0, 1]);
+
+        assert_eq!(
+            nomos_da_store_prune(store, 0, &mut removed),
+            NomosDaResult::Success
+        );
+        assert_eq!(removed, 2, "Every share should be older than a zero age");
+        assert!(
+            stored_columns(store, &blob_id).is_empty(),
+            "Pruned shares should no longer be listed"
+        );
+
+        nomos_da_store_free(store);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+        std::fs::remove_dir_all(&path).ok();
+    }
+}
+
+#[test]
+fn test_store_concurrent_puts_of_one_share() {
+    unsafe {
+        let (path, store) = open_test_store("concurrent");
+        nomos_da_store_free(store);
+        let store = ShareStore::open(&path).expect("Store should open");
+        let encoder = nomos_da_encoder_new(4);
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle),
+            NomosDaResult::Success
+        );
+        let share = (*out_handle).data.to_da_share(2).unwrap();
+        let blob_id = share.blob_id();
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..16 {
+                        store.put(&share).expect("Concurrent put should succeed");
+                    }
+                });
+            }
+        });
+
+        let loaded = store
+            .get(&blob_id, 2)
+            .expect("Stored share should load")
+            .expect("Stored share should be found");
+        assert_eq!(
+            loaded.column, share.column,
+            "Concurrent puts should leave a whole share in place"
+        );
+        let leftovers: Vec<_> = std::fs::read_dir(
+            path.join(
+                blob_id
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect::<String>(),
+            ),
+        )
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+        assert_eq!(
+            leftovers.len(),
+            1,
+            "Only the share file should remain after the puts (files: {:?})",
+            leftovers
+        );
+
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+        std::fs::remove_dir_all(&path).ok();
+    }
+}
+
+#[test]
+fn test_store_prune_leaves_foreign_entries() {
+    unsafe {
+        let (path, store) = open_test_store("foreign");
+        nomos_da_store_free(store);
+        let store = ShareStore::open(&path).expect("Store should open");
+        let encoder = nomos_da_encoder_new(4);
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle),
+            NomosDaResult::Success
+        );
+        let share = (*out_handle).data.to_da_share(1).unwrap();
+        let blob_id = share.blob_id();
+        store.put(&share).expect("Put should succeed");
+
+        let blob_dir = path.join(
+            blob_id
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>(),
+        );
+        std::fs::create_dir(blob_dir.join("nested")).unwrap();
+        std::fs::write(blob_dir.join("notes.txt"), b"kept").unwrap();
+        std::fs::write(blob_dir.join("00001.share.1.0.tmp"), b"stale").unwrap();
+        std::fs::create_dir(path.join("other")).unwrap();
+        std::fs::write(path.join("other").join("00000.share"), b"kept").unwrap();
+
+        assert_eq!(
+            store
+                .prune(Duration::ZERO)
+                .expect("Prune should skip foreign entries"),
+            1,
+            "Only the stored share should be counted"
+        );
+        let mut left: Vec<_> = std::fs::read_dir(&blob_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        left.sort();
+        assert_eq!(
+            left,
+            vec!["nested", "notes.txt"],
+            "Prune should only remove the share and temporary files"
+        );
+        assert!(
+            path.join("other").join("00000.share").exists(),
+            "Directories that are not blob directories should be left alone"
+        );
+
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+        std::fs::remove_dir_all(&path).ok();
+    }
+}
+
+#[test]
+fn test_store_put_races_prune() {
+    unsafe {
+        let (path, store) = open_test_store("race");
+        nomos_da_store_free(store);
+        let store = ShareStore::open(&path).expect("Store should open");
+        let encoder = nomos_da_encoder_new(4);
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle),
+            NomosDaResult::Success
+        );
+        let share = (*out_handle).data.to_da_share(0).unwrap();
+
+        // Pruning everything keeps removing the blob directory a put has just created
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                for _ in 0..200 {
+                    store.prune(Duration::ZERO).expect("Prune should succeed");
+                }
+            });
+            for _ in 0..200 {
+                store
+                    .put(&share)
+                    .expect("Put should survive its directory being pruned");
+            }
+        });
+
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+        std::fs::remove_dir_all(&path).ok();
+    }
+}
+
+#[test]
+fn test_store_null_arguments() {
+    unsafe {
+        assert!(
+            nomos_da_store_open(ptr::null()).is_null(),
+            "Store should not open without a path"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::ErrorNullHandle
+        );
+
+        let blob_id = [0u8; BLOB_ID_SIZE];
+        let mut share: *mut ShareHandle = ptr::null_mut();
+        let mut removed: usize = 0;
+        assert_eq!(
+            nomos_da_store_put(ptr::null_mut(), ptr::null_mut()),
+            NomosDaResult::ErrorNullHandle
+        );
+        assert_eq!(
+            nomos_da_store_get(ptr::null_mut(), blob_id.as_ptr(), 0, &mut share),
+            NomosDaResult::ErrorNullHandle
+        );
+        assert_eq!(
+            nomos_da_store_prune(ptr::null_mut(), 0, &mut removed),
+            NomosDaResult::ErrorNullHandle
+        );
+    }
+}
+
+// ============================================================================
+// Sampling Tests
+// ============================================================================
+
+unsafe fn sample_columns(
+    blob_id: &[u8; BLOB_ID_SIZE],
+    seed: &[u8],
+    column_count: usize,
+    sample_count: usize,
+) -> (Vec<u16>, f64) {
+    let mut columns = vec![0u16; sample_count];
+    let mut confidence = 0.0;
+    let result = nomos_da_sample_columns(
+        blob_id.as_ptr(),
+        seed.as_ptr(),
+        seed.len(),
+        column_count,
+        sample_count,
+        columns.as_mut_ptr(),
+        &mut confidence,
+    );
+    assert_eq!(
+        result,
+        NomosDaResult::Success,
+        "Sampling should succeed (column_count: {}, sample_count: {})",
+        column_count,
+        sample_count
+    );
+    (columns, confidence)
+}
+
+#[test]
+fn test_sample_columns_deterministic_and_distinct() {
+    unsafe {
+        let blob_id = [7u8; BLOB_ID_SIZE];
+        let (columns, confidence) = sample_columns(&blob_id, b"node-a", 64, 16);
+        let (repeated, _) = sample_columns(&blob_id, b"node-a", 64, 16);
+        assert_eq!(
+            columns, repeated,
+            "Same inputs should select the same columns"
+        );
+
+        assert!(
+            columns.windows(2).all(|pair| pair[0] < pair[1]),
+            "Columns should be distinct and ascending: {:?}",
+            columns
+        );
+        assert!(
+            columns.iter().all(|&column| (column as usize) < 64),
+            "Columns should be in range: {:?}",
+            columns
+        );
+        assert!(
+            confidence > 0.99 && confidence < 1.0,
+            "16 of 64 samples should give high confidence, got {}",
+            confidence
+        );
+
+        let (other_seed, _) = sample_columns(&blob_id, b"node-b", 64, 16);
+        let (other_blob, _) = sample_columns(&[8u8; BLOB_ID_SIZE], b"node-a", 64, 16);
+        assert_ne!(
+            columns, other_seed,
+            "A different seed should select different columns"
+        );
+        assert_ne!(
+            columns, other_blob,
+            "A different blob should select different columns"
+        );
+
+        let (all, full_confidence) = sample_columns(&blob_id, &[], 8, 8);
+        assert_eq!(
+            all,
+            (0..8).collect::<Vec<u16>>(),
+            "Sampling every column should return all of them"
+        );
+        assert_eq!(full_confidence, 1.0);
+    }
+}
+
+#[test]
+fn test_sample_columns_known_answers() {
+    unsafe {
+        let mut counting_id = [0u8; BLOB_ID_SIZE];
+        for (i, byte) in counting_id.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        let vectors: [(&[u8; BLOB_ID_SIZE], &[u8], usize, Vec<u16>); 4] = [
+            (&[0u8; BLOB_ID_SIZE], b"", 16, vec![0, 4, 7, 14]),
+            (
+                &counting_id,
+                b"node-1",
+                64,
+                vec![5, 7, 8, 11, 30, 40, 53, 58],
+            ),
+            (
+                &counting_id,
+                b"node-2",
+                64,
+                vec![1, 14, 19, 21, 36, 39, 48, 57],
+            ),
+            (
+                &counting_id,
+                b"node-1",
+                4096,
+                vec![
+                    159, 183, 186, 863, 902, 979, 1059, 1448, 1782, 1963, 2093, 2210, 2574, 2636,
+                    2649, 2816, 2971, 3671, 4017, 4039,
+                ],
+            ),
+        ];
+        for (blob_id, seed, column_count, expected) in vectors {
+            let (columns, _) = sample_columns(blob_id, seed, column_count, expected.len());
+            assert_eq!(
+                columns, expected,
+                "Selection should match the pinned vector (seed: {:?}, column_count: {})",
+                seed, column_count
+            );
+        }
+    }
+}
+
+fn confidence(column_count: usize, sample_count: usize) -> Result<f64, NomosDaResult> {
+    let mut confidence = -1.0;
+    match unsafe { nomos_da_sampling_confidence(column_count, sample_count, &mut confidence) } {
+        NomosDaResult::Success => Ok(confidence),
+        code => Err(code),
+    }
+}
+
+#[test]
+fn test_sampling_confidence() {
+    assert_eq!(confidence(4, 0), Ok(0.0), "No samples give no confidence");
+    // One of four columns is available when the blob is unrecoverable
+    assert!((confidence(4, 1).unwrap() - 0.75).abs() < 1e-12);
+    assert_eq!(
+        confidence(4, 2),
+        Ok(1.0),
+        "Two samples must hit a withheld column"
+    );
+
+    let mut previous = 0.0;
+    for sample_count in 1..=20 {
+        let current = confidence(256, sample_count).unwrap();
+        assert!(
+            current > previous,
+            "Confidence should grow with the sample count ({})",
+            sample_count
+        );
+        previous = current;
+    }
+
+    assert_eq!(
+        confidence(3, 1),
+        Err(NomosDaResult::ErrorDomainSizeMismatch),
+        "Odd column counts are invalid"
+    );
+    assert_eq!(confidence(4, 5), Err(NomosDaResult::ErrorBadLength));
+}
+
+#[test]
+fn test_sample_columns_invalid_arguments() {
+    unsafe {
+        let blob_id = [0u8; BLOB_ID_SIZE];
+        let mut columns = vec![0u16; 8];
+        let seed = b"seed";
+        let result = nomos_da_sample_columns(
+            blob_id.as_ptr(),
+            seed.as_ptr(),
+            seed.len(),
+            4,
+            8,
+            columns.as_mut_ptr(),
+            ptr::null_mut(),
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorBadLength,
+            "Cannot sample more columns than exist"
+        );
+
+        let result = nomos_da_sample_columns(
+            blob_id.as_ptr(),
+            seed.as_ptr(),
+            seed.len(),
+            0,
+            0,
+            columns.as_mut_ptr(),
+            ptr::null_mut(),
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorDomainSizeMismatch,
+            "Zero columns should be rejected"
+        );
+
+        let result = nomos_da_sample_columns(
+            ptr::null(),
+            seed.as_ptr(),
+            seed.len(),
+            4,
+            2,
+            columns.as_mut_ptr(),
+            ptr::null_mut(),
+        );
+        assert_eq!(result, NomosDaResult::ErrorNullHandle);
+    }
+}
+
+// ============================================================================
+// Simulation Tests
+// ============================================================================
+
+fn simulation_config(adversarial_nodes: usize, behavior: AdversaryBehavior) -> SimulationConfig {
+    SimulationConfig {
+        column_count: 8,
+        payload_size: CHUNK_SIZE * 8,
+        node_count: 6,
+        columns_per_node: 3,
+        adversarial_nodes,
+        behavior,
+        light_clients: 20,
+        samples_per_client: 3,
+        seed: 42,
+    }
+}
+
+unsafe fn simulate(config: &SimulationConfig) -> SimulationReport {
+    let mut report = SimulationReport::default();
+    let result = nomos_da_simulate(config, &mut report);
+    assert_eq!(
+        result,
+        NomosDaResult::Success,
+        "Simulation should run ({:?})",
+        config
+    );
+    report
+}
+
+#[test]
+fn test_simulation_honest_network() {
+    unsafe {
+        let config = SimulationConfig {
+            columns_per_node: 8,
+            ..simulation_config(0, AdversaryBehavior::Withhold)
+        };
+        let report = simulate(&config);
+        assert_eq!(report.available_columns, 8, "Every column should be served");
+        assert_eq!(
+            report.detecting_clients, 0,
+            "No client should flag an available blob"
+        );
+        assert_eq!(report.detection_rate, 0.0);
+        assert!(report.reconstructed, "The payload should be reconstructed");
+        assert!(report.expected_confidence > 0.0 && report.expected_confidence <= 1.0);
+    }
+}
+
+#[test]
+fn test_simulation_fully_adversarial_network() {
+    unsafe {
+        for behavior in [AdversaryBehavior::Withhold, AdversaryBehavior::Corrupt] {
+            let report = simulate(&simulation_config(6, behavior));
+            assert_eq!(
+                report.available_columns, 0,
+                "No valid share should be served ({:?})",
+                behavior
+            );
+            assert_eq!(
+                report.detection_rate, 1.0,
+                "Every client should detect the blob as unavailable ({:?})",
+                behavior
+            );
+            assert!(
+                !report.reconstructed,
+                "Reconstruction should fail ({:?})",
+                behavior
+            );
+        }
+    }
+}
+
+#[test]
+fn test_simulation_partial_adversaries_are_consistent() {
+    unsafe {
+        for adversarial_nodes in 0..=6 {
+            let config = simulation_config(adversarial_nodes, AdversaryBehavior::Corrupt);
+            let report = simulate(&config);
+            assert_eq!(
+                simulate(&config),
+                report,
+                "Simulation should be deterministic for a seed"
+            );
+            assert_eq!(
+                report.reconstructed,
+                report.available_columns >= config.column_count / 2,
+                "Reconstruction should succeed exactly when half of the columns are served ({:?})",
+                report
+            );
+            if report.available_columns == config.column_count {
+                assert_eq!(
+                    report.detecting_clients, 0,
+                    "Clients should not flag a fully served blob"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_simulation_invalid_config() {
+    unsafe {
+        let mut report = SimulationReport::default();
+        let config = SimulationConfig {
+            adversarial_nodes: 7,
+            ..simulation_config(0, AdversaryBehavior::Withhold)
+        };
+        assert_eq!(
+            nomos_da_simulate(&config, &mut report),
+            NomosDaResult::ErrorInvalidInput
+        );
+
+        let config = SimulationConfig {
+            samples_per_client: 9,
+            ..simulation_config(0, AdversaryBehavior::Withhold)
+        };
+        assert_eq!(
+            nomos_da_simulate(&config, &mut report),
+            NomosDaResult::ErrorBadLength
+        );
+
+        assert_eq!(
+            nomos_da_simulate(ptr::null(), &mut report),
+            NomosDaResult::ErrorNullHandle
+        );
+    }
+}
+
+// ============================================================================
+// Shared Handle Tests
+// ============================================================================
+
+#[test]
+fn test_shared_handles_are_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<EncoderHandle>();
+    assert_send_sync::<VerifierHandle>();
+    assert_send_sync::<ShareHandle>();
+    assert_send_sync::<CommitmentsHandle>();
+}
+
+#[test]
+fn test_retain_release_handles() {
+    unsafe {
+        let encoder = nomos_da_encoder_new(4);
+        assert_eq!(
+            nomos_da_encoder_retain(encoder),
+            encoder,
+            "Retain should return the same encoder"
+        );
+        nomos_da_encoder_release(encoder);
+
+        // The encoder is still held by its original reference
+        let data = create_test_data(CHUNK_SIZE);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Encoder should outlive a released extra reference"
+        );
+
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        let result = nomos_da_encoded_data_get_share(out_handle, 2, &mut share_handle);
+        assert_eq!(result, NomosDaResult::Success);
+        let share_ref = nomos_da_share_retain(share_handle);
+        assert_eq!(
+            share_ref, share_handle,
+            "Retain should return the same share"
+        );
+        nomos_da_share_free(share_handle);
+        assert_eq!(
+            nomos_da_share_get_index(share_ref),
+            2,
+            "Share should stay alive while a reference remains"
+        );
+
+        let mut commitments_handle: *mut CommitmentsHandle = ptr::null_mut();
+        let result = nomos_da_share_get_commitments(share_ref, &mut commitments_handle);
+        assert_eq!(result, NomosDaResult::Success);
+        let commitments_ref = nomos_da_commitments_retain(commitments_handle);
+        nomos_da_commitments_release(commitments_handle);
+        assert!(
+            nomos_da_commitments_row_count(commitments_ref) > 0,
+            "Commitments should stay alive while a reference remains"
+        );
+
+        assert!(
+            nomos_da_encoder_retain(ptr::null_mut()).is_null(),
+            "Retaining a null encoder should return null"
+        );
+        assert!(
+            nomos_da_verifier_retain(ptr::null_mut()).is_null(),
+            "Retaining a null verifier should return null"
+        );
+        assert!(
+            nomos_da_share_retain(ptr::null_mut()).is_null(),
+            "Retaining a null share should return null"
+        );
+        assert!(
+            nomos_da_commitments_retain(ptr::null_mut()).is_null(),
+            "Retaining null commitments should return null"
+        );
+        nomos_da_verifier_release(ptr::null_mut());
+
+        nomos_da_commitments_release(commitments_ref);
+        nomos_da_share_release(share_ref);
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_verifier_shared_across_threads() {
+    unsafe {
+        let column_count = 8;
+        let encoder = nomos_da_encoder_new(column_count);
+        let data = create_test_data(CHUNK_SIZE * 4);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(result, NomosDaResult::Success);
+
+        let verifier = nomos_da_verifier_new();
+        assert!(!verifier.is_null(), "Verifier should be created");
+
+        // Raw pointers are not `Send`, so each thread receives its references as addresses
+        let workers: Vec<_> = (0..column_count)
+            .map(|i| {
+                let mut share_handle: *mut ShareHandle = ptr::null_mut();
+                let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
+                assert_eq!(
+                    result,
+                    NomosDaResult::Success,
+                    "Should get share (share_index: {})",
+                    i
+                );
+                let verifier_ref = nomos_da_verifier_retain(verifier) as usize;
+                let share_ref = share_handle as usize;
+                thread::spawn(move || {
+                    let verifier = verifier_ref as *mut VerifierHandle;
+                    let share = share_ref as *mut ShareHandle;
+                    let result = nomos_da_verifier_verify(verifier, share, column_count);
+                    nomos_da_share_release(share);
+                    nomos_da_verifier_release(verifier);
+                    result
+                })
+            })
+            .collect();
+        // The threads now hold the only references they need
+        nomos_da_verifier_release(verifier);
+
+        for (i, worker) in workers.into_iter().enumerate() {
+            let result = worker.join().expect("Verification thread should not panic");
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Share verification should succeed on its thread (share_index: {})",
+                i
+            );
+        }
+
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+// ============================================================================
+// Streaming Encoding Tests
+// ============================================================================
+
+unsafe fn encoded_blob_id(handle: *mut EncodedDataHandle) -> [u8; BLOB_ID_SIZE] {
+    let mut blob_id = [0u8; BLOB_ID_SIZE];
+    assert_eq!(
+        nomos_da_encoded_data_blob_id(handle, blob_id.as_mut_ptr()),
+        NomosDaResult::Success
+    );
+    blob_id
+}
+
+#[test]
+fn test_session_matches_one_shot_encoding() {
+    unsafe {
+        let column_count = 8;
+        let encoder = nomos_da_encoder_new(column_count);
+        let data = create_test_data(CHUNK_SIZE * 12);
+
+        let mut one_shot: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut one_shot);
+        assert_eq!(result, NomosDaResult::Success);
+
+        for expected_len in [0, data.len()] {
+            let mut session: *mut EncoderSessionHandle = ptr::null_mut();
+            let result = nomos_da_encoder_session_begin(encoder, expected_len, &mut session);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Session should begin (expected_len: {})",
+                expected_len
+            );
+
+            // Slices deliberately split chunks across calls
+            for piece in data.chunks(CHUNK_SIZE * 2 + 5) {
+                let result = nomos_da_encoder_session_push(session, piece.as_ptr(), piece.len());
+                assert_eq!(
+                    result,
+                    NomosDaResult::Success,
+                    "Push should succeed (expected_len: {}, piece_len: {})",
+                    expected_len,
+                    piece.len()
+                );
+            }
+            assert_eq!(nomos_da_encoder_session_received(session), data.len());
+
+            let mut streamed: *mut EncodedDataHandle = ptr::null_mut();
+            let result = nomos_da_encoder_session_finish(session, &mut streamed);
+            assert_eq!(
+                result,
+                NomosDaResult::Success,
+                "Finish should succeed (expected_len: {})",
+                expected_len
+            );
+            assert_eq!(
+                encoded_blob_id(streamed),
+                encoded_blob_id(one_shot),
+                "Streamed encoding should match one-shot encoding (expected_len: {})",
+                expected_len
+            );
+            assert_eq!(
+                (*streamed).data.data,
+                (*one_shot).data.data,
+                "Encoded data should match (expected_len: {})",
+                expected_len
+            );
+
+            let result = nomos_da_encoder_session_finish(session, &mut streamed);
+            assert_eq!(
+                result,
+                NomosDaResult::ErrorInvalidInput,
+                "A session can only be finished once"
+            );
+            let result = nomos_da_encoder_session_push(session, data.as_ptr(), CHUNK_SIZE);
+            assert_eq!(
+                result,
+                NomosDaResult::ErrorInvalidInput,
+                "A finished session should reject data"
+            );
+
+            nomos_da_encoded_data_free(streamed);
+            nomos_da_encoder_session_free(session);
+        }
+
+        nomos_da_encoded_data_free(one_shot);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+#[test]
+fn test_session_outlives_encoder_reference() {
+    unsafe {
+        let encoder = nomos_da_encoder_new(4);
+        let mut session: *mut EncoderSessionHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoder_session_begin(encoder, 0, &mut session),
+            NomosDaResult::Success
+        );
+        nomos_da_encoder_free(encoder);
+
+        let data = create_test_data(CHUNK_SIZE * 2);
+        assert_eq!(
+            nomos_da_encoder_session_push(session, data.as_ptr(), data.len()),
+            NomosDaResult::Success
+        );
+        let mut encoded: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_session_finish(session, &mut encoded);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Session should keep its encoder alive"
+        );
+
+        nomos_da_encoded_data_free(encoded);
+        nomos_da_encoder_session_free(session);
+    }
+}
+
+#[test]
+fn test_session_alignment_and_length_checks() {
+    unsafe {
+        let encoder = nomos_da_encoder_new(4);
+        let data = create_test_data(CHUNK_SIZE * 2);
+        let mut session: *mut EncoderSessionHandle = ptr::null_mut();
+        let mut encoded: *mut EncodedDataHandle = ptr::null_mut();
+
+        let result = nomos_da_encoder_session_begin(encoder, CHUNK_SIZE + 1, &mut session);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorBadLength,
+            "Unaligned expected length should be rejected"
+        );
+        assert!(session.is_null());
+
+        // Partial chunk left at the end
+        assert_eq!(
+            nomos_da_encoder_session_begin(encoder, 0, &mut session),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            nomos_da_encoder_session_push(session, data.as_ptr(), CHUNK_SIZE + 3),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            nomos_da_encoder_session_finish(session, &mut encoded),
+            NomosDaResult::ErrorBadLength,
+            "Partial final chunk should be rejected"
+        );
+        assert!(encoded.is_null());
+        nomos_da_encoder_session_free(session);
+
+        // Nothing pushed
+        assert_eq!(
+            nomos_da_encoder_session_begin(encoder, 0, &mut session),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            nomos_da_encoder_session_push(session, ptr::null(), 0),
+            NomosDaResult::Success,
+            "Empty push should be accepted"
+        );
+        assert_eq!(
+            nomos_da_encoder_session_finish(session, &mut encoded),
+            NomosDaResult::ErrorBadLength,
+            "Empty session should be rejected"
+        );
+        nomos_da_encoder_session_free(session);
+
+        // Beyond and short of the expected length
+        assert_eq!(
+            nomos_da_encoder_session_begin(encoder, CHUNK_SIZE, &mut session),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            nomos_da_encoder_session_push(session, data.as_ptr(), data.len()),
+            NomosDaResult::ErrorBadLength,
+            "Data beyond the expected length should be rejected"
+        );
+        assert_eq!(
+            nomos_da_encoder_session_received(session),
+            0,
+            "Rejected data should not be kept"
+        );
+        assert_eq!(
+            nomos_da_encoder_session_push(session, data.as_ptr(), 10),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            nomos_da_encoder_session_push(session, data.as_ptr(), CHUNK_SIZE - 9),
+            NomosDaResult::ErrorBadLength,
+            "A push overrunning the expected length by one byte should be rejected"
+        );
+        assert_eq!(
+            nomos_da_encoder_session_received(session),
+            10,
+            "The overrunning push should not be kept"
+        );
+        assert_eq!(
+            nomos_da_encoder_session_finish(session, &mut encoded),
+            NomosDaResult::ErrorBadLength,
+            "Data short of the expected length should be rejected"
+        );
+        nomos_da_encoder_session_free(session);
+
+        assert_eq!(
+            nomos_da_encoder_session_begin(ptr::null_mut(), 0, &mut session),
+            NomosDaResult::ErrorNullHandle
+        );
+        assert_eq!(
+            nomos_da_encoder_session_begin(encoder, 0, ptr::null_mut()),
+            NomosDaResult::ErrorNullHandle
+        );
+        assert_eq!(
+            nomos_da_encoder_session_push(ptr::null_mut(), data.as_ptr(), data.len()),
+            NomosDaResult::ErrorNullHandle
+        );
+        assert_eq!(
+            nomos_da_encoder_session_finish(ptr::null_mut(), &mut encoded),
+            NomosDaResult::ErrorNullHandle
+        );
+        nomos_da_encoder_session_free(ptr::null_mut());
+
         nomos_da_encoder_free(encoder);
     }
 }
 
+// ============================================================================
+// Multi-Blob Splitting Tests
+// ============================================================================
+
+/// Reconstructs the padded data of a blob from its original columns only
+unsafe fn reconstruct_blob(blob: *mut EncodedDataHandle, column_count: usize) -> Vec<u8> {
+    let mut shares: Vec<*mut ShareHandle> = Vec::new();
+    for i in 0..column_count / 2 {
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_encoded_data_get_share(blob, i, &mut share_handle),
+            NomosDaResult::Success
+        );
+        shares.push(share_handle);
+    }
+    let mut out_data: *mut u8 = ptr::null_mut();
+    let mut out_len = 0;
+    let result = nomos_da_reconstruct_with_missing_data(
+        shares.as_ptr(),
+        shares.len(),
+        column_count,
+        &mut out_data,
+        &mut out_len,
+    );
+    assert_eq!(
+        result,
+        NomosDaResult::Success,
+        "Blob should reconstruct from half of its columns"
+    );
+    let data = std::slice::from_raw_parts(out_data, out_len).to_vec();
+    nomos_da_reconstruct_free(out_data, out_len);
+    for share in shares {
+        nomos_da_share_free(share);
+    }
+    data
+}
+
 #[test]
-fn test_reconstruct_different_data_sizes() {
+fn test_split_payload_pieces() {
+    let max_blob_size = CHUNK_SIZE * 4;
+    let capacity = max_blob_size - LENGTH_HEADER_SIZE;
+    let payload = create_test_data(capacity * 2 + 1);
+
+    let pieces = split_payload(&payload, max_blob_size).unwrap();
+    assert_eq!(
+        pieces.iter().map(|piece| piece.len()).collect::<Vec<_>>(),
+        vec![capacity, capacity, 1]
+    );
+    assert!(
+        pieces.iter().all(|piece| pad(piece).len() <= max_blob_size),
+        "Every padded piece should fit the limit"
+    );
+    assert_eq!(pieces.concat(), payload);
+
+    assert_eq!(
+        split_payload(&[], max_blob_size).unwrap(),
+        vec![&[][..]],
+        "Empty payload should take one blob"
+    );
+    assert_eq!(
+        split_payload(&payload, CHUNK_SIZE + 1),
+        Err(SplitError::InvalidMaxBlobSize(CHUNK_SIZE + 1))
+    );
+    assert_eq!(
+        split_payload(&payload, 0),
+        Err(SplitError::InvalidMaxBlobSize(0))
+    );
+
+    let entry = BlobManifestEntry::new([7u8; BLOB_ID_SIZE], 1);
+    assert_eq!(entry.payload_len, 1);
+    assert_eq!(entry.padding as usize, CHUNK_SIZE - LENGTH_HEADER_SIZE - 1);
+}
+
+#[test]
+fn test_encode_split_and_reassemble() {
     unsafe {
         let column_count = 4;
+        let max_blob_size = CHUNK_SIZE * 6;
         let encoder = nomos_da_encoder_new(column_count);
-        assert!(!encoder.is_null(), "Encoder should be created (column_count: {}, chunk_size: {})", column_count, CHUNK_SIZE);
+        let payload = create_test_data(max_blob_size * 3 + 17);
 
-        for data_size in [3 * CHUNK_SIZE, 2 * CHUNK_SIZE, CHUNK_SIZE] {
-            let original_data = create_test_data(data_size);
-            let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-            let result = nomos_da_encoder_encode(
-                encoder,
-                original_data.as_ptr(),
-                original_data.len(),
-                &mut out_handle,
-            );
-            assert_eq!(result, NomosDaResult::Success, "Encoding should succeed (data_size: {}, column_count: {}, chunk_size: {})", data_size, column_count, CHUNK_SIZE);
+        let mut blob_count = 0;
+        assert_eq!(
+            nomos_da_split_blob_count(payload.len(), max_blob_size, &mut blob_count),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            blob_count, 4,
+            "Payload should need four blobs once headers are added"
+        );
 
-            let share_count = nomos_da_encoded_data_get_share_count(out_handle);
-            let original_share_count = column_count / 2;
-            let mut share_handles: Vec<*mut ShareHandle> = Vec::with_capacity(original_share_count);
-            for i in 0..original_share_count {
-                let mut share_handle: *mut ShareHandle = ptr::null_mut();
-                let result = nomos_da_encoded_data_get_share(out_handle, i, &mut share_handle);
-                assert_eq!(result, NomosDaResult::Success, "Should successfully get share (share_index: {}, data_size: {}, chunk_size: {})", i, data_size, CHUNK_SIZE);
-                share_handles.push(share_handle);
-            }
+        let mut blobs: Vec<*mut EncodedDataHandle> = vec![ptr::null_mut(); blob_count];
+        let mut manifest: *mut BlobManifestHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode_split(
+            encoder,
+            payload.as_ptr(),
+            payload.len(),
+            max_blob_size,
+            blobs.as_mut_ptr(),
+            blob_count,
+            &mut manifest,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Split encoding should succeed"
+        );
+        assert_eq!(nomos_da_manifest_blob_count(manifest), blob_count);
+        assert_eq!(
+            nomos_da_manifest_payload_len(manifest),
+            payload.len() as u64
+        );
 
-            let mut reconstructed_data: *mut u8 = ptr::null_mut();
-            let mut reconstructed_len: usize = 0;
-            let reconstruct_result = nomos_da_reconstruct(
-                share_handles.as_ptr(),
-                share_handles.len(),
-                &mut reconstructed_data,
-                &mut reconstructed_len,
+        let mut total_len = 0;
+        for (i, &blob) in blobs.iter().enumerate() {
+            let mut entry = BlobManifestEntry::new([0u8; BLOB_ID_SIZE], 0);
+            assert_eq!(
+                nomos_da_manifest_entry(manifest, i, &mut entry),
+                NomosDaResult::Success
+            );
+            assert_eq!(
+                entry.blob_id,
+                encoded_blob_id(blob),
+                "Manifest should list blob ids in order (blob: {})",
+                i
+            );
+            let encoded_len = LENGTH_HEADER_SIZE as u64 + entry.payload_len + entry.padding;
+            assert_eq!(
+                encoded_len as usize,
+                (*blob).data.data.len(),
+                "Lengths and padding should add up to the encoded size (blob: {})",
+                i
             );
-            assert_eq!(reconstruct_result, NomosDaResult::Success, "Reconstruction should succeed (data_size: {}, share_count: {}, chunk_size: {})", data_size, share_count, CHUNK_SIZE);
+            assert!(
+                encoded_len as usize <= max_blob_size,
+                "Blob should respect the limit (blob: {})",
+                i
+            );
+            total_len += entry.payload_len;
+        }
+        assert_eq!(total_len, payload.len() as u64);
+        let mut entry = BlobManifestEntry::new([0u8; BLOB_ID_SIZE], 0);
+        assert_eq!(
+            nomos_da_manifest_entry(manifest, blob_count, &mut entry),
+            NomosDaResult::ErrorIndexOutOfRange
+        );
 
-            // Calculate expected reconstructed size (padded to row boundaries)
-            // Row size = (column_count / 2) * chunk_size
-            let row_size = (column_count / 2) * CHUNK_SIZE;
-            let num_rows = (original_data.len() + row_size - 1) / row_size; // Ceiling division
-            let expected_reconstructed_len = num_rows * row_size;
-            
-            assert_eq!(reconstructed_len, expected_reconstructed_len, "Reconstructed length should match expected padded size (data_size: {}, reconstructed_len: {}, expected_len: {}, row_size: {}, num_rows: {}, chunk_size: {})", data_size, reconstructed_len, expected_reconstructed_len, row_size, num_rows, CHUNK_SIZE);
-            
-            let reconstructed_slice = std::slice::from_raw_parts(reconstructed_data, reconstructed_len);
-            // Check that the original data matches the beginning of reconstructed data
-            assert_eq!(&reconstructed_slice[..original_data.len()], original_data.as_slice(), "Reconstructed data prefix should match original (data_size: {}, reconstructed_len: {}, original_len: {}, chunk_size: {})", data_size, reconstructed_len, original_data.len(), CHUNK_SIZE);
-            // Check that the padding is zeros
-            if reconstructed_len > original_data.len() {
-                let padding = &reconstructed_slice[original_data.len()..];
-                assert!(padding.iter().all(|&b| b == 0), "Padding should be zeros (data_size: {}, reconstructed_len: {}, original_len: {}, chunk_size: {})", data_size, reconstructed_len, original_data.len(), CHUNK_SIZE);
-            }
+        // The manifest survives a round trip through bytes
+        let mut manifest_bytes: *mut u8 = ptr::null_mut();
+        let mut manifest_len = 0;
+        assert_eq!(
+            nomos_da_manifest_to_bytes(manifest, &mut manifest_bytes, &mut manifest_len),
+            NomosDaResult::Success
+        );
+        let mut decoded: *mut BlobManifestHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_manifest_from_bytes(manifest_bytes, manifest_len, &mut decoded),
+            NomosDaResult::Success
+        );
+        assert_eq!(
+            (*decoded).manifest,
+            (*manifest).manifest,
+            "Decoded manifest should match the original"
+        );
+        nomos_da_bytes_free(manifest_bytes, manifest_len);
 
-            for share_handle in share_handles {
-                nomos_da_share_free(share_handle);
-            }
-            nomos_da_reconstruct_free(reconstructed_data, reconstructed_len);
-            nomos_da_encoded_data_free(out_handle);
-        }
+        let reconstructed: Vec<Vec<u8>> = blobs
+            .iter()
+            .map(|&blob| reconstruct_blob(blob, column_count))
+            .collect();
+        let blob_ptrs: Vec<*const u8> = reconstructed.iter().map(|blob| blob.as_ptr()).collect();
+        let blob_lens: Vec<usize> = reconstructed.iter().map(Vec::len).collect();
+        let mut out_data: *mut u8 = ptr::null_mut();
+        let mut out_len = 0;
+        let result = nomos_da_manifest_reassemble(
+            decoded,
+            blob_ptrs.as_ptr(),
+            blob_lens.as_ptr(),
+            blob_count,
+            &mut out_data,
+            &mut out_len,
+        );
+        assert_eq!(result, NomosDaResult::Success, "Reassembly should succeed");
+        assert_eq!(
+            std::slice::from_raw_parts(out_data, out_len),
+            &payload[..],
+            "Reassembled payload should match the original"
+        );
+        nomos_da_bytes_free(out_data, out_len);
 
+        // A blob whose length disagrees with the manifest, or a missing blob, is rejected
+        let swapped: Vec<*const u8> = vec![blob_ptrs[3], blob_ptrs[1], blob_ptrs[2], blob_ptrs[0]];
+        let swapped_lens: Vec<usize> = vec![blob_lens[3], blob_lens[1], blob_lens[2], blob_lens[0]];
+        let result = nomos_da_manifest_reassemble(
+            decoded,
+            swapped.as_ptr(),
+            swapped_lens.as_ptr(),
+            blob_count,
+            &mut out_data,
+            &mut out_len,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorInvalidInput,
+            "Short last blob in first position should be rejected"
+        );
+        let result = nomos_da_manifest_reassemble(
+            decoded,
+            blob_ptrs.as_ptr(),
+            blob_lens.as_ptr(),
+            blob_count - 1,
+            &mut out_data,
+            &mut out_len,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorBadLength,
+            "Missing blob should be rejected"
+        );
+
+        for blob in blobs {
+            nomos_da_encoded_data_free(blob);
+        }
+        nomos_da_manifest_free(decoded);
+        nomos_da_manifest_free(manifest);
         nomos_da_encoder_free(encoder);
     }
 }
 
 #[test]
-fn test_reconstruct_null_handles() {
+fn test_encode_split_invalid_input() {
     unsafe {
-        let mut data: *mut u8 = ptr::null_mut();
-        let mut len: usize = 0;
+        let encoder = nomos_da_encoder_new(4);
+        let payload = create_test_data(CHUNK_SIZE * 10);
+        let mut blobs: Vec<*mut EncodedDataHandle> = vec![ptr::null_mut(); 8];
+        let mut manifest: *mut BlobManifestHandle = ptr::null_mut();
 
-        let result_null_shares = nomos_da_reconstruct(ptr::null(), 4, &mut data, &mut len);
-        assert_eq!(result_null_shares, NomosDaResult::ErrorInvalidInput, "Reconstruction should fail with null shares array");
+        let mut blob_count = 0;
+        assert_eq!(
+            nomos_da_split_blob_count(payload.len(), CHUNK_SIZE + 1, &mut blob_count),
+            NomosDaResult::ErrorInvalidInput,
+            "Unaligned limit should be rejected"
+        );
+        let result = nomos_da_encoder_encode_split(
+            encoder,
+            payload.as_ptr(),
+            payload.len(),
+            CHUNK_SIZE * 4,
+            blobs.as_mut_ptr(),
+            1,
+            &mut manifest,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorBadLength,
+            "Output array of the wrong size should be rejected"
+        );
+        assert!(
+            manifest.is_null() && blobs.iter().all(|blob| blob.is_null()),
+            "Nothing should be written on failure"
+        );
 
-        let result_null_output = nomos_da_reconstruct(ptr::null(), 4, ptr::null_mut(), &mut len);
-        assert_eq!(result_null_output, NomosDaResult::ErrorInvalidInput, "Reconstruction should fail with null output data pointer");
+        assert_eq!(
+            nomos_da_split_blob_count(0, CHUNK_SIZE, &mut blob_count),
+            NomosDaResult::Success
+        );
+        assert_eq!(blob_count, 1);
+        let result = nomos_da_encoder_encode_split(
+            encoder,
+            ptr::null(),
+            0,
+            CHUNK_SIZE,
+            blobs.as_mut_ptr(),
+            1,
+            &mut manifest,
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Empty payload should encode into one blob"
+        );
+        assert_eq!(nomos_da_manifest_payload_len(manifest), 0);
 
-        let result_null_len = nomos_da_reconstruct(ptr::null(), 4, &mut data, ptr::null_mut());
-        assert_eq!(result_null_len, NomosDaResult::ErrorInvalidInput, "Reconstruction should fail with null length pointer");
+        let garbage = [0xffu8; 16];
+        let mut decoded: *mut BlobManifestHandle = ptr::null_mut();
+        assert_eq!(
+            nomos_da_manifest_from_bytes(garbage.as_ptr(), garbage.len(), &mut decoded),
+            NomosDaResult::ErrorDeserialization
+        );
+        assert_eq!(
+            nomos_da_encoder_encode_split(
+                ptr::null_mut(),
+                payload.as_ptr(),
+                payload.len(),
+                CHUNK_SIZE,
+                blobs.as_mut_ptr(),
+                1,
+                &mut manifest
+            ),
+            NomosDaResult::ErrorNullHandle
+        );
+        assert_eq!(nomos_da_manifest_blob_count(ptr::null_mut()), 0);
 
-        let result_zero_count = nomos_da_reconstruct(ptr::null(), 0, &mut data, &mut len);
-        assert_eq!(result_zero_count, NomosDaResult::ErrorInvalidInput, "Reconstruction should fail with zero share count");
+        nomos_da_encoded_data_free(blobs[0]);
+        nomos_da_manifest_free(manifest);
+        nomos_da_encoder_free(encoder);
     }
 }
 
+#[test]
+fn test_manifest_with_forged_lengths() {
+    unsafe {
+        let encoder = nomos_da_encoder_new(4);
+        let max_blob_size = CHUNK_SIZE * 4;
+        let payload = create_test_data(max_blob_size + 9);
+        let mut blobs: Vec<*mut EncodedDataHandle> = vec![ptr::null_mut(); 2];
+        let mut manifest: *mut BlobManifestHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode_split(
+            encoder,
+            payload.as_ptr(),
+            payload.len(),
+            max_blob_size,
+            blobs.as_mut_ptr(),
+            blobs.len(),
+            &mut manifest,
+        );
+        assert_eq!(result, NomosDaResult::Success);
+        let original = (*manifest).manifest.clone();
+
+        let mut forged_total = original.clone();
+        forged_total.payload_len = u64::MAX;
+        let mut forged_entry = original.clone();
+        forged_entry.blobs[0].payload_len = u64::MAX / 2;
+        forged_entry.payload_len = forged_entry
+            .blobs
+            .iter()
+            .map(|entry| entry.payload_len)
+            .sum();
+        let mut overflowing = original.clone();
+        overflowing.max_blob_size = u64::MAX / CHUNK_SIZE as u64 * CHUNK_SIZE as u64;
+        overflowing.blobs[0].payload_len = u64::MAX / 2 + 1;
+        overflowing.blobs[1].payload_len = u64::MAX / 2 + 1;
+        overflowing.payload_len = u64::MAX;
+
+        let reconstructed: Vec<Vec<u8>> = blobs
+            .iter()
+            .map(|&blob| reconstruct_blob(blob, 4))
+            .collect();
+        let blob_slices: Vec<&[u8]> = reconstructed.iter().map(Vec::as_slice).collect();
+        for forged in [forged_total, forged_entry, overflowing] {
+            let bytes = wire_serialize(&forged).expect("Forged manifest should serialize");
+            let mut decoded: *mut BlobManifestHandle = ptr::null_mut();
+            let result = nomos_da_manifest_from_bytes(bytes.as_ptr(), bytes.len(), &mut decoded);
+            assert_eq!(
+                result,
+                NomosDaResult::ErrorInvalidInput,
+                "Forged lengths should be rejected on decode (payload_len: {})",
+                forged.payload_len
+            );
+            assert!(
+                decoded.is_null(),
+                "No manifest should be returned for forged lengths"
+            );
+            assert!(
+                forged.reassemble(&blob_slices).is_err(),
+                "Reassembly should refuse forged lengths (payload_len: {})",
+                forged.payload_len
+            );
+        }
+        assert_eq!(
+            original.reassemble(&blob_slices).as_deref(),
+            Ok(&payload[..]),
+            "The genuine manifest should still reassemble"
+        );
+
+        for blob in blobs {
+            nomos_da_encoded_data_free(blob);
+        }
+        nomos_da_manifest_free(manifest);
+        nomos_da_encoder_free(encoder);
+    }
+}
 
 // ============================================================================
 // Error Handling Tests
@@ -689,14 +4850,17 @@ fn test_encode_null_encoder_handle() {
     unsafe {
         let data = create_test_data(CHUNK_SIZE);
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-        let result = nomos_da_encoder_encode(
-            ptr::null_mut(),
-            data.as_ptr(),
-            data.len(),
-            &mut out_handle,
+        let result =
+            nomos_da_encoder_encode(ptr::null_mut(), data.as_ptr(), data.len(), &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null encoder handle"
+        );
+        assert!(
+            out_handle.is_null(),
+            "Output handle should be null on failure"
         );
-        assert_eq!(result, NomosDaResult::ErrorInvalidInput, "Should fail with null encoder handle");
-        assert!(out_handle.is_null(), "Output handle should be null on failure");
     }
 }
 
@@ -706,14 +4870,16 @@ fn test_encode_null_data_pointer() {
         let encoder = nomos_da_encoder_new(4);
         assert!(!encoder.is_null());
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
-        let result = nomos_da_encoder_encode(
-            encoder,
-            ptr::null(),
-            10,
-            &mut out_handle,
+        let result = nomos_da_encoder_encode(encoder, ptr::null(), 10, &mut out_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null data pointer"
+        );
+        assert!(
+            out_handle.is_null(),
+            "Output handle should be null on failure"
         );
-        assert_eq!(result, NomosDaResult::ErrorInvalidInput, "Should fail with null data pointer");
-        assert!(out_handle.is_null(), "Output handle should be null on failure");
         nomos_da_encoder_free(encoder);
     }
 }
@@ -724,13 +4890,12 @@ fn test_encode_null_output_handle() {
         let encoder = nomos_da_encoder_new(4);
         assert!(!encoder.is_null());
         let data = create_test_data(CHUNK_SIZE);
-        let result = nomos_da_encoder_encode(
-            encoder,
-            data.as_ptr(),
-            data.len(),
-            ptr::null_mut(),
+        let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), ptr::null_mut());
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null output handle"
         );
-        assert_eq!(result, NomosDaResult::ErrorInvalidInput, "Should fail with null output handle");
         nomos_da_encoder_free(encoder);
     }
 }
@@ -741,12 +4906,13 @@ fn test_get_data_null_handle() {
     unsafe {
         let mut out_data = vec![0u8; 100];
         let mut out_len = out_data.len();
-        let result = nomos_da_encoded_data_get_data(
-            ptr::null_mut(),
-            out_data.as_mut_ptr(),
-            &mut out_len,
+        let result =
+            nomos_da_encoded_data_get_data(ptr::null_mut(), out_data.as_mut_ptr(), &mut out_len);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null handle"
         );
-        assert_eq!(result, NomosDaResult::ErrorInvalidInput, "Should fail with null handle");
     }
 }
 
@@ -758,15 +4924,15 @@ fn test_get_data_null_out_data() {
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
         let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
         assert_eq!(result, NomosDaResult::Success);
-        
+
         let mut out_len = 100;
-        let result = nomos_da_encoded_data_get_data(
-            out_handle,
-            ptr::null_mut(),
-            &mut out_len,
+        let result = nomos_da_encoded_data_get_data(out_handle, ptr::null_mut(), &mut out_len);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null out_data"
         );
-        assert_eq!(result, NomosDaResult::ErrorInvalidInput, "Should fail with null out_data");
-        
+
         nomos_da_encoded_data_free(out_handle);
         nomos_da_encoder_free(encoder);
     }
@@ -780,15 +4946,16 @@ fn test_get_data_null_out_len() {
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
         let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
         assert_eq!(result, NomosDaResult::Success);
-        
+
         let mut out_data = vec![0u8; 100];
-        let result = nomos_da_encoded_data_get_data(
-            out_handle,
-            out_data.as_mut_ptr(),
-            ptr::null_mut(),
+        let result =
+            nomos_da_encoded_data_get_data(out_handle, out_data.as_mut_ptr(), ptr::null_mut());
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null out_len"
         );
-        assert_eq!(result, NomosDaResult::ErrorInvalidInput, "Should fail with null out_len");
-        
+
         nomos_da_encoded_data_free(out_handle);
         nomos_da_encoder_free(encoder);
     }
@@ -802,17 +4969,18 @@ fn test_get_data_buffer_too_small() {
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
         let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
         assert_eq!(result, NomosDaResult::Success);
-        
+
         let mut out_data = vec![0u8; 1];
         let mut out_len = out_data.len();
-        let result = nomos_da_encoded_data_get_data(
-            out_handle,
-            out_data.as_mut_ptr(),
-            &mut out_len,
+        let result =
+            nomos_da_encoded_data_get_data(out_handle, out_data.as_mut_ptr(), &mut out_len);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorBadLength,
+            "Should fail with buffer too small"
         );
-        assert_eq!(result, NomosDaResult::ErrorInvalidInput, "Should fail with buffer too small");
         assert!(out_len > 1, "out_len should be updated to required size");
-        
+
         nomos_da_encoded_data_free(out_handle);
         nomos_da_encoder_free(encoder);
     }
@@ -823,13 +4991,16 @@ fn test_get_data_buffer_too_small() {
 fn test_get_share_null_handle() {
     unsafe {
         let mut share_handle: *mut ShareHandle = ptr::null_mut();
-        let result = nomos_da_encoded_data_get_share(
-            ptr::null_mut(),
-            0,
-            &mut share_handle,
+        let result = nomos_da_encoded_data_get_share(ptr::null_mut(), 0, &mut share_handle);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null handle"
+        );
+        assert!(
+            share_handle.is_null(),
+            "Share handle should be null on failure"
         );
-        assert_eq!(result, NomosDaResult::ErrorInvalidInput, "Should fail with null handle");
-        assert!(share_handle.is_null(), "Share handle should be null on failure");
     }
 }
 
@@ -841,15 +5012,218 @@ fn test_get_share_null_output_handle() {
         let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
         let result = nomos_da_encoder_encode(encoder, data.as_ptr(), data.len(), &mut out_handle);
         assert_eq!(result, NomosDaResult::Success);
-        
-        let result = nomos_da_encoded_data_get_share(
-            out_handle,
+
+        let result = nomos_da_encoded_data_get_share(out_handle, 0, ptr::null_mut());
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Should fail with null output handle"
+        );
+
+        nomos_da_encoded_data_free(out_handle);
+        nomos_da_encoder_free(encoder);
+    }
+}
+
+// Last Error Cases
+fn last_error_message() -> String {
+    unsafe {
+        let mut len = 0;
+        let _ = nomos_da_get_last_error_message(ptr::null_mut(), 0, &mut len);
+        let mut buffer = vec![0 as c_char; len + 1];
+        let result = nomos_da_get_last_error_message(buffer.as_mut_ptr(), buffer.len(), &mut len);
+        assert_eq!(
+            result,
+            NomosDaResult::Success,
+            "Message should fit a buffer of the reported length"
+        );
+        CStr::from_ptr(buffer.as_ptr())
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+}
+
+#[test]
+fn test_success_clears_last_error() {
+    unsafe {
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        let result = nomos_da_share_from_bytes([0xffu8; 16].as_ptr(), 16, &mut share_handle);
+        assert_eq!(result, NomosDaResult::ErrorDeserialization);
+        assert!(
+            !last_error_message().is_empty(),
+            "Failure should record a message"
+        );
+
+        let encoder = nomos_da_encoder_new(4);
+        assert!(!encoder.is_null());
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::Success,
+            "Successful call should clear the error code"
+        );
+        assert!(
+            last_error_message().is_empty(),
+            "Successful call should clear the message"
+        );
+        assert!(
+            nomos_da_get_last_error().is_null(),
+            "Successful call should leave no message to take"
+        );
+
+        // Releasing a handle between a failure and reading it keeps the error
+        let result = nomos_da_encoder_encode(encoder, ptr::null(), 0, ptr::null_mut());
+        assert_eq!(result, NomosDaResult::ErrorNullHandle);
+        nomos_da_encoder_free(encoder);
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::ErrorNullHandle,
+            "Free should not clear the error"
+        );
+    }
+}
+
+#[test]
+fn test_last_error_message_buffer() {
+    unsafe {
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        let result = nomos_da_share_from_bytes([0xffu8; 16].as_ptr(), 16, &mut share_handle);
+        assert_eq!(result, NomosDaResult::ErrorDeserialization);
+
+        let mut full_len = 0;
+        let result = nomos_da_get_last_error_message(ptr::null_mut(), 0, &mut full_len);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorBadLength,
+            "Empty buffer should report truncation"
+        );
+        assert!(full_len > 4, "Length should be reported without a buffer");
+
+        let mut small = [0x7f as c_char; 4];
+        let mut len = 0;
+        let result = nomos_da_get_last_error_message(small.as_mut_ptr(), small.len(), &mut len);
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorBadLength,
+            "Short buffer should report truncation"
+        );
+        assert_eq!(
+            len, full_len,
+            "Full length should be reported when truncating"
+        );
+        assert_eq!(small[3], 0, "Truncated message should be NUL-terminated");
+
+        let message = last_error_message();
+        assert_eq!(message.len(), full_len);
+        assert_eq!(
+            last_error_message(),
+            message,
+            "Reading the message should not clear it"
+        );
+
+        let result = nomos_da_get_last_error_message(ptr::null_mut(), 8, ptr::null_mut());
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Null buffer with a length should be rejected"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            NomosDaResult::ErrorDeserialization,
+            "Rejected read should keep the error"
+        );
+    }
+}
+
+#[test]
+fn test_with_error_variants() {
+    unsafe {
+        let mut error = NomosDaError {
+            code: NomosDaResult::ErrorInternal,
+            message: [0x7f as c_char; ERROR_MESSAGE_SIZE],
+        };
+
+        let garbage = [0xffu8; 64];
+        let verifier =
+            nomos_da_verifier_new_with_key_with_error(garbage.as_ptr(), garbage.len(), &mut error);
+        assert!(verifier.is_null(), "Malformed key should be rejected");
+        assert_eq!(
+            error.code,
+            NomosDaResult::ErrorDeserialization,
+            "Error struct should hold the failure code"
+        );
+        let message = CStr::from_ptr(error.message.as_ptr())
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(
+            !message.is_empty(),
+            "Error struct should hold the failure message"
+        );
+        assert_eq!(
+            nomos_da_get_last_error_code(),
+            error.code,
+            "Last error should match the error struct"
+        );
+
+        let mut share_handle: *mut ShareHandle = ptr::null_mut();
+        let result = nomos_da_share_from_bytes_with_error(
+            garbage.as_ptr(),
             0,
+            &mut share_handle,
+            &mut error,
+        );
+        assert_eq!(result, NomosDaResult::ErrorBadLength);
+        assert_eq!(
+            error.code, result,
+            "Error struct code should match the returned code"
+        );
+
+        let encoder = nomos_da_encoder_new(4);
+        let data = create_test_data(CHUNK_SIZE);
+        let mut out_handle: *mut EncodedDataHandle = ptr::null_mut();
+        let result = nomos_da_encoder_encode_with_error(
+            encoder,
+            data.as_ptr(),
+            data.len(),
+            &mut out_handle,
+            &mut error,
+        );
+        assert_eq!(result, NomosDaResult::Success);
+        assert_eq!(
+            error.code,
+            NomosDaResult::Success,
+            "Success should be reported in the error struct"
+        );
+        assert_eq!(error.message[0], 0, "Message should be empty on success");
+
+        let result = nomos_da_encoder_encode_with_error(
+            encoder,
+            data.as_ptr(),
+            data.len(),
             ptr::null_mut(),
+            ptr::null_mut(),
+        );
+        assert_eq!(
+            result,
+            NomosDaResult::ErrorNullHandle,
+            "Null error struct should be accepted"
         );
-        assert_eq!(result, NomosDaResult::ErrorInvalidInput, "Should fail with null output handle");
-        
+
         nomos_da_encoded_data_free(out_handle);
         nomos_da_encoder_free(encoder);
     }
 }
+
+#[test]
+fn test_header_declares_with_error_variants() {
+    let header = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/nomos_da_ffi.h"))
+        .expect("Build script should generate the header");
+    for name in WITH_ERROR_ENTRY_POINTS {
+        assert!(
+            header.contains(&format!(" *{}(", name)) || header.contains(&format!(" {}(", name)),
+            "Header should declare {}, add it to the trailer in cbindgen.toml",
+            name
+        );
+    }
+}
@@ -12,21 +12,84 @@
 
 import kzg_nomos_da/types
 
-const CHUNK_SIZE* = 31
+const
+  CHUNK_SIZE* = 31
+  COMMITMENT_SIZE* = 48
+  PROOF_SIZE* = 48
 
 proc nomos_da_init*(): cint {.importc: "nomos_da_init".}
 proc nomos_da_cleanup*() {.importc: "nomos_da_cleanup".}
 proc nomos_da_free_string*(s: cstring) {.importc: "nomos_da_free_string".}
 proc nomos_da_get_last_error*(): cstring {.importc: "nomos_da_get_last_error".}
 
+proc nomos_da_get_last_error_message*(
+  buffer: ptr char, bufferLen: csize_t, outLen: ptr csize_t
+): NomosDaResult {.importc: "nomos_da_get_last_error_message".}
+
 proc getLastError*(): string =
-  let errMsg = nomos_da_get_last_error()
-  if errMsg != nil:
-    let msg = $errMsg
-    nomos_da_free_string(errMsg)
-    msg
-  else:
-    ""
+  ## Message of the most recent FFI call on this thread, empty if it succeeded
+  var len: csize_t = 0
+  discard nomos_da_get_last_error_message(nil, 0, addr len)
+  if len == 0:
+    return ""
+  # Nim strings keep room for a NUL terminator past `len`, so Rust can write
+  # the message straight into the result without allocating on its side
+  result = newString(len)
+  if nomos_da_get_last_error_message(addr result[0], len + 1, addr len) != Success:
+    result = ""
+
+proc nomos_da_get_last_error_code*(): NomosDaResult {.
+  importc: "nomos_da_get_last_error_code"
+.}
+
+func message*(info: NomosDaErrorInfo): string =
+  ## Message of an error filled in by a `_with_error` entry point
+  $cast[cstring](unsafeAddr info.message[0])
+
+proc newNomosDaError*(code: NomosDaResult, msg: string): ref NomosDaError =
+  ## Build the exception subclass matching an FFI result code
+  result =
+    case code
+    of ErrorInternal, Success: (ref InternalError)()
+    of ErrorInvalidInput: (ref InvalidInputError)()
+    of ErrorAllocation: (ref AllocationError)()
+    of ErrorPanic: (ref PanicError)()
+    of ErrorNullHandle: (ref NullHandleError)()
+    of ErrorBadLength: (ref BadLengthError)()
+    of ErrorIndexOutOfRange: (ref IndexOutOfRangeError)()
+    of ErrorDomainSizeMismatch: (ref DomainSizeMismatchError)()
+    of ErrorCommitmentMismatch: (ref CommitmentMismatchError)()
+    of ErrorProofInvalid: (ref ProofInvalidError)()
+    of ErrorDeserialization: (ref DeserializationError)()
+    of ErrorInsufficientShares: (ref InsufficientSharesError)()
+    of ErrorInvalidPadding: (ref InvalidPaddingError)()
+    of ErrorRowCommitmentCount: (ref RowCommitmentCountError)()
+    of ErrorColumnLengthMismatch: (ref ColumnLengthMismatchError)()
+    of ErrorIo: (ref NomosIoError)()
+    of ErrorNotFound: (ref NotFoundError)()
+    of ErrorReencodingMismatch: (ref ReencodingMismatchError)()
+  result.code = code
+  result.msg = msg
+
+proc raiseError(
+    code: NomosDaResult, msg: string
+) {.noreturn, raises: [NomosDaError].} =
+  raise newNomosDaError(code, msg)
+
+proc raiseLastError(operation: string) {.noreturn, raises: [NomosDaError].} =
+  ## Raise the failure recorded by the most recent FFI call on this thread
+  let errMsg = getLastError()
+  raiseError(
+    nomos_da_get_last_error_code(),
+    operation & (if errMsg.len > 0: ": " & errMsg else: ""),
+  )
+
+proc raiseErrorInfo(
+    info: NomosDaErrorInfo, operation: string
+) {.noreturn, raises: [NomosDaError].} =
+  ## Raise the failure a `_with_error` entry point filled into `info`
+  let errMsg = info.message()
+  raiseError(info.code, operation & (if errMsg.len > 0: ": " & errMsg else: ""))
 
 proc checkResult*(
     result: NomosDaResult, operation: string = ""
@@ -37,24 +100,39 @@ proc checkResult*(
       (if operation.len > 0: operation & " failed: " else: "") &
       "nomos-da operation failed with code: " & $result &
       (if errMsg.len > 0: " (" & errMsg & ")" else: "")
-    raise newException(ValueError, msg)
+    raiseError(result, msg)
 
-proc nomos_da_encoder_new(
-  column_count: CSizeT
-): pointer {.importc: "nomos_da_encoder_new".}
+proc nomos_da_encoder_new_with_error(
+  column_count: CSizeT, out_error: ptr NomosDaErrorInfo
+): pointer {.importc: "nomos_da_encoder_new_with_error".}
 
-proc nomos_da_encoder_free(handle: pointer) {.importc: "nomos_da_encoder_free".}
 proc nomos_da_encoder_encode(
   encoder: pointer, data: ptr uint8, data_len: CSizeT, out_handle: ptr pointer
 ): NomosDaResult {.importc: "nomos_da_encoder_encode".}
 
-proc nomos_da_encoded_data_free(
-  handle: pointer
-) {.importc: "nomos_da_encoded_data_free".}
+proc nomos_da_encoder_encode_padded(
+  encoder: pointer, data: ptr uint8, data_len: CSizeT, out_handle: ptr pointer
+): NomosDaResult {.importc: "nomos_da_encoder_encode_padded".}
+
+proc nomos_da_encoder_session_begin(
+  encoder: pointer, expected_len: CSizeT, out_session: ptr pointer
+): NomosDaResult {.importc: "nomos_da_encoder_session_begin".}
+
+proc nomos_da_encoder_session_push(
+  session: pointer, data: ptr uint8, data_len: CSizeT
+): NomosDaResult {.importc: "nomos_da_encoder_session_push".}
+
+proc nomos_da_encoder_session_finish(
+  session: pointer, out_handle: ptr pointer
+): NomosDaResult {.importc: "nomos_da_encoder_session_finish".}
 
-proc nomos_da_encoded_data_get_data(
-  handle: pointer, out_data: ptr uint8, out_len: ptr CSizeT
-): NomosDaResult {.importc: "nomos_da_encoded_data_get_data".}
+proc nomos_da_encoder_session_received(
+  session: pointer
+): CSizeT {.importc: "nomos_da_encoder_session_received".}
+
+proc nomos_da_encoded_data_blob_id(
+  handle: pointer, out_blob_id: ptr uint8
+): NomosDaResult {.importc: "nomos_da_encoded_data_blob_id".}
 
 proc nomos_da_encoded_data_get_share_count(
   handle: pointer
@@ -64,13 +142,101 @@ proc nomos_da_encoded_data_get_share(
   handle: pointer, index: CSizeT, out_share_handle: ptr pointer
 ): NomosDaResult {.importc: "nomos_da_encoded_data_get_share".}
 
+proc nomos_da_encoded_data_get_row_count(
+  handle: pointer
+): CSizeT {.importc: "nomos_da_encoded_data_get_row_count".}
+
+proc nomos_da_encoded_data_get_column_count(
+  handle: pointer
+): CSizeT {.importc: "nomos_da_encoded_data_get_column_count".}
+
+proc nomos_da_encoded_data_get_column(
+  handle: pointer, column: CSizeT, out_data: ptr ptr uint8, out_len: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_encoded_data_get_column".}
+
+proc nomos_da_encoded_data_get_chunk(
+  handle: pointer,
+  row: CSizeT,
+  column: CSizeT,
+  out_data: ptr ptr uint8,
+  out_len: ptr CSizeT,
+): NomosDaResult {.importc: "nomos_da_encoded_data_get_chunk".}
+
+proc nomos_da_encoded_data_get_row_commitment(
+  handle: pointer, row: CSizeT, out_commitment: ptr uint8
+): NomosDaResult {.importc: "nomos_da_encoded_data_get_row_commitment".}
+
+proc nomos_da_encoded_data_get_column_proof(
+  handle: pointer, column: CSizeT, out_proof: ptr uint8
+): NomosDaResult {.importc: "nomos_da_encoded_data_get_column_proof".}
+
+proc nomos_da_encoded_data_data_view(
+  handle: pointer, out_data: ptr ptr uint8, out_len: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_encoded_data_data_view".}
+
+proc nomos_da_encoded_data_column_view(
+  handle: pointer, column: CSizeT, out_data: ptr ptr uint8, out_len: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_encoded_data_column_view".}
+
+proc nomos_da_encoded_data_proof_view(
+  handle: pointer, column: CSizeT, out_proof: ptr ptr uint8
+): NomosDaResult {.importc: "nomos_da_encoded_data_proof_view".}
+
+proc nomos_da_encoder_new_with_options(
+  options: ptr EncoderOptions
+): pointer {.importc: "nomos_da_encoder_new_with_options".}
+
+proc nomos_da_encoder_has_cache(
+  handle: pointer
+): bool {.importc: "nomos_da_encoder_has_cache".}
+
+proc nomos_da_encoder_thread_count(
+  handle: pointer
+): CSizeT {.importc: "nomos_da_encoder_thread_count".}
+
+proc nomos_da_encoder_new_with_params(
+  column_count: CSizeT, params_data: ptr uint8, params_len: CSizeT
+): pointer {.importc: "nomos_da_encoder_new_with_params".}
+
+proc nomos_da_encoder_new_with_params_file(
+  column_count: CSizeT, path: cstring
+): pointer {.importc: "nomos_da_encoder_new_with_params_file".}
+
+proc nomos_da_encoder_export_params(
+  handle: pointer, out_data: ptr ptr uint8, out_len: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_encoder_export_params".}
+
 proc nomos_da_verifier_new(): pointer {.importc: "nomos_da_verifier_new".}
-proc nomos_da_verifier_free(handle: pointer) {.importc: "nomos_da_verifier_free".}
+proc nomos_da_verifier_new_with_key(
+  key_data: ptr uint8, key_len: CSizeT
+): pointer {.importc: "nomos_da_verifier_new_with_key".}
+
+proc nomos_da_verifier_new_with_key_file(
+  path: cstring
+): pointer {.importc: "nomos_da_verifier_new_with_key_file".}
+
+proc nomos_da_verifier_export_key(
+  handle: pointer, out_data: ptr ptr uint8, out_len: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_verifier_export_key".}
 proc nomos_da_verifier_verify(
   verifier: pointer, share_handle: pointer, rows_domain_size: CSizeT
-): bool {.importc: "nomos_da_verifier_verify".}
+): NomosDaResult {.importc: "nomos_da_verifier_verify".}
+
+proc nomos_da_verifier_verify_batch(
+  verifier: pointer,
+  shares: ptr pointer,
+  share_count: CSizeT,
+  rows_domain_size: CSizeT,
+  out_results: ptr NomosDaResult,
+): NomosDaResult {.importc: "nomos_da_verifier_verify_batch".}
+
+proc nomos_da_verifier_verify_light(
+  verifier: pointer,
+  light_share_handle: pointer,
+  commitments_handle: pointer,
+  rows_domain_size: CSizeT,
+): NomosDaResult {.importc: "nomos_da_verifier_verify_light".}
 
-proc nomos_da_share_free(handle: pointer) {.importc: "nomos_da_share_free".}
 proc nomos_da_share_get_index(
   share_handle: pointer
 ): uint16 {.importc: "nomos_da_share_get_index".}
@@ -79,169 +245,1147 @@ proc nomos_da_share_get_commitments(
   share_handle: pointer, out_commitments_handle: ptr pointer
 ): NomosDaResult {.importc: "nomos_da_share_get_commitments".}
 
-proc nomos_da_commitments_free(handle: pointer) {.importc: "nomos_da_commitments_free".}
+proc nomos_da_share_to_bytes(
+  share_handle: pointer, out_data: ptr ptr uint8, out_len: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_share_to_bytes".}
+
+proc nomos_da_share_from_bytes_with_error(
+  data: ptr uint8,
+  data_len: CSizeT,
+  out_share_handle: ptr pointer,
+  out_error: ptr NomosDaErrorInfo
+): NomosDaResult {.importc: "nomos_da_share_from_bytes_with_error".}
+
+proc nomos_da_light_share_from_share(
+  share_handle: pointer, out_light_share_handle: ptr pointer
+): NomosDaResult {.importc: "nomos_da_light_share_from_share".}
+
+proc nomos_da_light_share_to_bytes(
+  light_share_handle: pointer, out_data: ptr ptr uint8, out_len: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_light_share_to_bytes".}
+
+proc nomos_da_light_share_from_bytes(
+  data: ptr uint8, data_len: CSizeT, out_light_share_handle: ptr pointer
+): NomosDaResult {.importc: "nomos_da_light_share_from_bytes".}
+
+proc nomos_da_light_share_get_index(
+  light_share_handle: pointer
+): uint16 {.importc: "nomos_da_light_share_get_index".}
+
+proc nomos_da_bytes_free(data: ptr uint8, len: CSizeT) {.importc: "nomos_da_bytes_free".}
+
+proc nomos_da_commitments_row_count(
+  handle: pointer
+): CSizeT {.importc: "nomos_da_commitments_row_count".}
+
+proc nomos_da_commitments_to_bytes(
+  handle: pointer, out_data: ptr ptr uint8, out_len: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_commitments_to_bytes".}
+
+proc nomos_da_commitments_from_bytes(
+  data: ptr uint8, data_len: CSizeT, out_commitments_handle: ptr pointer
+): NomosDaResult {.importc: "nomos_da_commitments_from_bytes".}
+
+proc nomos_da_commitments_blob_id(
+  handle: pointer, out_blob_id: ptr uint8
+): NomosDaResult {.importc: "nomos_da_commitments_blob_id".}
+
+proc nomos_da_share_blob_id(
+  share_handle: pointer, out_blob_id: ptr uint8
+): NomosDaResult {.importc: "nomos_da_share_blob_id".}
+
+proc nomos_da_commitments_equal(
+  lhs: pointer, rhs: pointer
+): bool {.importc: "nomos_da_commitments_equal".}
+
 proc nomos_da_reconstruct(
   shares: ptr pointer, share_count: CSizeT, out_data: ptr ptr uint8, out_len: ptr CSizeT
 ): NomosDaResult {.importc: "nomos_da_reconstruct".}
 
+proc nomos_da_reconstruct_with_missing_data(
+  shares: ptr pointer,
+  share_count: CSizeT,
+  column_count: CSizeT,
+  out_data: ptr ptr uint8,
+  out_len: ptr CSizeT,
+): NomosDaResult {.importc: "nomos_da_reconstruct_with_missing_data".}
+
+proc nomos_da_reconstruct_padded(
+  shares: ptr pointer,
+  share_count: CSizeT,
+  column_count: CSizeT,
+  out_data: ptr ptr uint8,
+  out_len: ptr CSizeT,
+): NomosDaResult {.importc: "nomos_da_reconstruct_padded".}
+
 proc nomos_da_reconstruct_free(
   data: ptr uint8, len: CSizeT
 ) {.importc: "nomos_da_reconstruct_free".}
 
+proc nomos_da_reconstruct_and_verify(
+  encoder: pointer,
+  shares: ptr pointer,
+  share_count: CSizeT,
+  column_count: CSizeT,
+  out_data: ptr ptr uint8,
+  out_len: ptr CSizeT,
+): NomosDaResult {.importc: "nomos_da_reconstruct_and_verify".}
+
+proc nomos_da_encoded_data_from_shares(
+  shares: ptr pointer, share_count: CSizeT, column_count: CSizeT, out_handle: ptr pointer
+): NomosDaResult {.importc: "nomos_da_encoded_data_from_shares".}
+
+proc nomos_da_sample_columns(
+  blob_id: ptr uint8,
+  seed: ptr uint8,
+  seed_len: CSizeT,
+  column_count: CSizeT,
+  sample_count: CSizeT,
+  out_columns: ptr uint16,
+  out_confidence: ptr cdouble,
+): NomosDaResult {.importc: "nomos_da_sample_columns".}
+
+proc nomos_da_sampling_confidence(
+  column_count: CSizeT, sample_count: CSizeT, out_confidence: ptr cdouble
+): NomosDaResult {.importc: "nomos_da_sampling_confidence".}
+
+proc nomos_da_simulate(
+  config: ptr SimulationConfig, out_report: ptr SimulationReport
+): NomosDaResult {.importc: "nomos_da_simulate".}
+
+proc nomos_da_split_blob_count(
+  data_len: CSizeT, max_blob_size: CSizeT, out_count: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_split_blob_count".}
+
+proc nomos_da_encoder_encode_split(
+  encoder: pointer,
+  data: ptr uint8,
+  data_len: CSizeT,
+  max_blob_size: CSizeT,
+  out_blobs: ptr pointer,
+  blob_count: CSizeT,
+  out_manifest: ptr pointer,
+): NomosDaResult {.importc: "nomos_da_encoder_encode_split".}
+
+proc nomos_da_manifest_blob_count(
+  handle: pointer
+): CSizeT {.importc: "nomos_da_manifest_blob_count".}
+
+proc nomos_da_manifest_payload_len(
+  handle: pointer
+): uint64 {.importc: "nomos_da_manifest_payload_len".}
+
+proc nomos_da_manifest_entry(
+  handle: pointer, index: CSizeT, out_entry: ptr BlobManifestEntry
+): NomosDaResult {.importc: "nomos_da_manifest_entry".}
+
+proc nomos_da_manifest_to_bytes(
+  handle: pointer, out_data: ptr ptr uint8, out_len: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_manifest_to_bytes".}
+
+proc nomos_da_manifest_from_bytes(
+  data: ptr uint8, data_len: CSizeT, out_manifest: ptr pointer
+): NomosDaResult {.importc: "nomos_da_manifest_from_bytes".}
+
+proc nomos_da_manifest_reassemble(
+  handle: pointer,
+  blobs: ptr ptr uint8,
+  blob_lens: ptr CSizeT,
+  blob_count: CSizeT,
+  out_data: ptr ptr uint8,
+  out_len: ptr CSizeT,
+): NomosDaResult {.importc: "nomos_da_manifest_reassemble".}
+
+proc nomos_da_store_open(path: cstring): pointer {.importc: "nomos_da_store_open".}
+
+proc nomos_da_store_put(
+  store: pointer, share_handle: pointer
+): NomosDaResult {.importc: "nomos_da_store_put".}
+
+proc nomos_da_store_get(
+  store: pointer, blob_id: ptr uint8, column: uint16, out_share_handle: ptr pointer
+): NomosDaResult {.importc: "nomos_da_store_get".}
+
+proc nomos_da_store_list_columns(
+  store: pointer, blob_id: ptr uint8, out_columns: ptr ptr uint16, out_count: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_store_list_columns".}
+
+proc nomos_da_store_columns_free(
+  columns: ptr uint16, count: CSizeT
+) {.importc: "nomos_da_store_columns_free".}
+
+proc nomos_da_store_prune(
+  store: pointer, max_age_secs: uint64, out_removed: ptr CSizeT
+): NomosDaResult {.importc: "nomos_da_store_prune".}
+
+proc takeBytes(data: ptr uint8, len: CSizeT): seq[byte] =
+  ## Copy a Rust-owned buffer into a Nim seq and release the Rust side
+  if data == nil:
+    return @[]
+  result = newSeq[byte](int(len))
+  if len > 0:
+    copyMem(addr result[0], data, int(len))
+  nomos_da_bytes_free(data, len)
+
 proc newEncoder*(columnCount: int): EncoderHandle {.raises: [ValueError].} =
   if columnCount <= 0:
-    raise newException(ValueError, "columnCount must be greater than 0")
-  let handle = nomos_da_encoder_new(csize_t(columnCount))
+    raiseError(ErrorInvalidInput, "columnCount must be greater than 0")
+  var info: NomosDaErrorInfo
+  let handle = nomos_da_encoder_new_with_error(csize_t(columnCount), addr info)
+  if handle == nil:
+    raiseErrorInfo(info, "Failed to create encoder")
+  EncoderHandle(raw: handle)
+
+proc newEncoder*(
+    columnCount: int, params: openArray[byte]
+): EncoderHandle {.raises: [ValueError].} =
+  ## Create an encoder from serialized global parameters (trusted setup), for
+  ## example bytes previously returned by `exportParams`
+  if columnCount <= 0:
+    raiseError(ErrorInvalidInput, "columnCount must be greater than 0")
+  if params.len == 0:
+    raiseError(ErrorBadLength, "Parameters must not be empty")
+  let handle = nomos_da_encoder_new_with_params(
+    csize_t(columnCount), unsafeAddr(params[0]), csize_t(params.len)
+  )
+  if handle == nil:
+    raiseLastError("Failed to create encoder")
+  EncoderHandle(raw: handle)
+
+proc newEncoder*(
+    columnCount: int, withCache: bool, threadCount = 0
+): EncoderHandle {.raises: [ValueError].} =
+  ## Create an encoder that optionally precomputes the trusted setup cache and
+  ## encodes on its own pool of `threadCount` threads (0 shares the global pool)
+  if columnCount <= 0:
+    raiseError(ErrorInvalidInput, "columnCount must be greater than 0")
+  if threadCount < 0:
+    raiseError(ErrorInvalidInput, "threadCount must not be negative")
+  var options = EncoderOptions(
+    columnCount: csize_t(columnCount),
+    withCache: withCache,
+    threadCount: csize_t(threadCount),
+  )
+  let handle = nomos_da_encoder_new_with_options(addr options)
+  if handle == nil:
+    raiseLastError("Failed to create encoder")
+  EncoderHandle(raw: handle)
+
+func hasCache*(encoder: EncoderHandle): bool =
+  ## Whether the encoder precomputed the trusted setup cache
+  encoder.raw != nil and nomos_da_encoder_has_cache(encoder.raw)
+
+func getThreadCount*(encoder: EncoderHandle): int =
+  ## Threads encoding fans out to: the encoder's own pool, or the global one
+  if encoder.raw == nil:
+    0
+  else:
+    int(nomos_da_encoder_thread_count(encoder.raw))
+
+proc newEncoderFromParamsFile*(
+    columnCount: int, path: string
+): EncoderHandle {.raises: [ValueError].} =
+  ## Create an encoder from global parameters stored in a file
+  if columnCount <= 0:
+    raiseError(ErrorInvalidInput, "columnCount must be greater than 0")
+  let handle = nomos_da_encoder_new_with_params_file(csize_t(columnCount), path.cstring)
   if handle == nil:
-    raise newException(ValueError, "Failed to create encoder: " & getLastError())
-  EncoderHandle(handle)
+    raiseLastError("Failed to create encoder")
+  EncoderHandle(raw: handle)
 
-proc freeEncoder*(encoder: EncoderHandle) =
-  if encoder.pointer != nil:
-    nomos_da_encoder_free(encoder.pointer)
+proc exportParams*(encoder: EncoderHandle): seq[byte] {.raises: [ValueError].} =
+  ## Serialized global parameters the encoder was built with
+  if encoder.raw == nil:
+    raiseError(ErrorNullHandle, "Encoder handle is null")
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  if nomos_da_encoder_export_params(encoder.raw, addr outData, addr outLen) != Success:
+    raiseLastError("Failed to export parameters")
+  takeBytes(outData, outLen)
+
+proc freeEncoder*(encoder: var EncoderHandle) =
+  ## Release the handle before it goes out of scope; safe to call repeatedly
+  encoder = default(EncoderHandle)
 
 proc encode*(
     encoder: EncoderHandle, data: openArray[byte]
 ): EncodedDataHandle {.raises: [ValueError].} =
-  if encoder.pointer == nil:
-    raise newException(ValueError, "Encoder handle is null")
+  if encoder.raw == nil:
+    raiseError(ErrorNullHandle, "Encoder handle is null")
   if data.len == 0:
-    raise newException(ValueError, "Data length must be greater than 0")
+    raiseError(ErrorBadLength, "Data length must be greater than 0")
   if data.len mod CHUNK_SIZE != 0:
-    raise newException(
-      ValueError,
+    raiseError(
+      ErrorBadLength,
       "Data length (" & $data.len & ") must be a multiple of chunk size (" & $CHUNK_SIZE &
         ")",
     )
   var outHandle: pointer = nil
   let encodeResult = nomos_da_encoder_encode(
-    encoder.pointer, unsafeAddr(data[0]), csize_t(data.len), addr outHandle
+    encoder.raw, unsafeAddr(data[0]), csize_t(data.len), addr outHandle
   )
   if encodeResult != Success:
-    raise newException(ValueError, "Encoding failed: " & getLastError())
+    raiseLastError("Encoding failed")
   if outHandle == nil:
-    raise newException(ValueError, "Encoding succeeded but output handle is null")
-  EncodedDataHandle(outHandle)
+    raiseError(ErrorInternal, "Encoding succeeded but output handle is null")
+  EncodedDataHandle(raw: outHandle)
 
-proc freeEncodedData*(encoded: EncodedDataHandle) =
-  if encoded.pointer != nil:
-    nomos_da_encoded_data_free(encoded.pointer)
+proc encodePadded*(
+    encoder: EncoderHandle, data: openArray[byte]
+): EncodedDataHandle {.raises: [ValueError].} =
+  ## Encode a payload of any length. The payload is framed with its length and
+  ## zero-padded to a chunk boundary; `reconstructPadded` returns it byte-exact
+  if encoder.raw == nil:
+    raiseError(ErrorNullHandle, "Encoder handle is null")
+  var outHandle: pointer = nil
+  let dataPtr =
+    if data.len > 0:
+      unsafeAddr(data[0])
+    else:
+      nil
+  let encodeResult = nomos_da_encoder_encode_padded(
+    encoder.raw, dataPtr, csize_t(data.len), addr outHandle
+  )
+  if encodeResult != Success:
+    raiseLastError("Encoding failed")
+  if outHandle == nil:
+    raiseError(ErrorInternal, "Encoding succeeded but output handle is null")
+  EncodedDataHandle(raw: outHandle)
 
-proc getData*(encoded: EncodedDataHandle): seq[byte] {.raises: [ValueError].} =
-  if encoded.pointer == nil:
-    raise newException(ValueError, "Encoded data handle is null")
-  var outLen: CSizeT = 0
-  var dummy: uint8 = 0
-  let result1 = nomos_da_encoded_data_get_data(encoded.pointer, addr dummy, addr outLen)
-  if result1 != ErrorInvalidInput:
-    raise newException(ValueError, "Failed to get data size: " & getLastError())
-  if outLen == 0:
-    return @[]
-  var output = newSeq[byte](int(outLen))
-  var actualLen = outLen
-  let result2 =
-    nomos_da_encoded_data_get_data(encoded.pointer, addr output[0], addr actualLen)
-  if result2 != Success:
-    raise newException(ValueError, "Failed to get data: " & getLastError())
-  if int(actualLen) < output.len:
-    output.setLen(int(actualLen))
-  output
+proc beginEncoding*(
+    encoder: EncoderHandle, expectedLen = 0
+): EncoderSessionHandle {.raises: [ValueError].} =
+  ## Start encoding data that arrives in pieces. `expectedLen` is the total
+  ## length if known (a multiple of `CHUNK_SIZE`), 0 otherwise. The session keeps
+  ## the encoder alive until it is freed
+  if encoder.raw == nil:
+    raiseError(ErrorNullHandle, "Encoder handle is null")
+  if expectedLen < 0:
+    raiseError(ErrorBadLength, "expectedLen must not be negative")
+  var outSession: pointer = nil
+  if nomos_da_encoder_session_begin(encoder.raw, csize_t(expectedLen), addr outSession) !=
+      Success:
+    raiseLastError("Failed to begin encoding session")
+  EncoderSessionHandle(raw: outSession)
+
+proc push*(
+    session: EncoderSessionHandle, data: openArray[byte]
+) {.raises: [ValueError].} =
+  ## Append a piece of the data; pieces may split chunks, only the total has to
+  ## be a multiple of `CHUNK_SIZE`. A piece that would overrun the expected
+  ## length raises `BadLengthError` here and is not appended
+  if session.raw == nil:
+    raiseError(ErrorNullHandle, "Session handle is null")
+  if data.len == 0:
+    return
+  if nomos_da_encoder_session_push(session.raw, unsafeAddr(data[0]), csize_t(data.len)) !=
+      Success:
+    raiseLastError("Failed to push data to encoding session")
+
+proc received*(session: EncoderSessionHandle): int {.raises: [ValueError].} =
+  ## Bytes pushed to the session so far
+  if session.raw == nil:
+    raiseError(ErrorNullHandle, "Session handle is null")
+  int(nomos_da_encoder_session_received(session.raw))
+
+proc finish*(session: EncoderSessionHandle): EncodedDataHandle {.raises: [ValueError].} =
+  ## Encode everything pushed so far; the result equals `encode` over the
+  ## concatenated pieces. The session accepts nothing afterwards
+  if session.raw == nil:
+    raiseError(ErrorNullHandle, "Session handle is null")
+  var outHandle: pointer = nil
+  if nomos_da_encoder_session_finish(session.raw, addr outHandle) != Success:
+    raiseLastError("Encoding failed")
+  EncodedDataHandle(raw: outHandle)
+
+proc freeEncodedData*(encoded: var EncodedDataHandle) =
+  ## Release the handle before it goes out of scope; safe to call repeatedly
+  encoded = default(EncodedDataHandle)
+
+proc blobId*(encoded: EncodedDataHandle): BlobId {.raises: [ValueError].} =
+  ## Blob id of the encoded data, as computed by logos-blockchain
+  if encoded.raw == nil:
+    raiseError(ErrorNullHandle, "Encoded data handle is null")
+  if nomos_da_encoded_data_blob_id(encoded.raw, addr result[0]) != Success:
+    raiseLastError("Failed to compute blob id")
 
 func getShareCount*(encoded: EncodedDataHandle): int =
-  if encoded.pointer == nil:
+  if encoded.raw == nil:
+    0
+  else:
+    int(nomos_da_encoded_data_get_share_count(encoded.raw))
+
+func getRowCount*(encoded: EncodedDataHandle): int =
+  if encoded.raw == nil:
     0
   else:
-    int(nomos_da_encoded_data_get_share_count(encoded.pointer))
+    int(nomos_da_encoded_data_get_row_count(encoded.raw))
+
+func getColumnCount*(encoded: EncodedDataHandle): int =
+  ## Number of columns of the Reed-Solomon extended matrix
+  if encoded.raw == nil:
+    0
+  else:
+    int(nomos_da_encoded_data_get_column_count(encoded.raw))
+
+proc getColumn*(
+    encoded: EncodedDataHandle, column: int
+): seq[byte] {.raises: [ValueError].} =
+  ## Bytes of one extended column, chunk by chunk from the first row to the last
+  if encoded.raw == nil:
+    raiseError(ErrorNullHandle, "Encoded data handle is null")
+  if column < 0:
+    raiseError(ErrorIndexOutOfRange, "Column index must be non-negative")
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  let columnResult = nomos_da_encoded_data_get_column(
+    encoded.raw, csize_t(column), addr outData, addr outLen
+  )
+  if columnResult != Success:
+    raiseLastError("Failed to get column")
+  takeBytes(outData, outLen)
+
+proc getChunk*(
+    encoded: EncodedDataHandle, row, column: int
+): seq[byte] {.raises: [ValueError].} =
+  ## Bytes of the chunk at (row, column) of the extended matrix
+  if encoded.raw == nil:
+    raiseError(ErrorNullHandle, "Encoded data handle is null")
+  if row < 0 or column < 0:
+    raiseError(ErrorIndexOutOfRange, "Row and column indices must be non-negative")
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  let chunkResult = nomos_da_encoded_data_get_chunk(
+    encoded.raw, csize_t(row), csize_t(column), addr outData, addr outLen
+  )
+  if chunkResult != Success:
+    raiseLastError("Failed to get chunk")
+  takeBytes(outData, outLen)
+
+proc getRowCommitment*(
+    encoded: EncodedDataHandle, row: int
+): Commitment {.raises: [ValueError].} =
+  ## Compressed G1 commitment of one row
+  if encoded.raw == nil:
+    raiseError(ErrorNullHandle, "Encoded data handle is null")
+  if row < 0:
+    raiseError(ErrorIndexOutOfRange, "Row index must be non-negative")
+  let commitmentResult = nomos_da_encoded_data_get_row_commitment(
+    encoded.raw, csize_t(row), addr result[0]
+  )
+  if commitmentResult != Success:
+    raiseLastError("Failed to get row commitment")
+
+proc getColumnProof*(
+    encoded: EncodedDataHandle, column: int
+): Proof {.raises: [ValueError].} =
+  ## Compressed G1 combined proof of one extended column
+  if encoded.raw == nil:
+    raiseError(ErrorNullHandle, "Encoded data handle is null")
+  if column < 0:
+    raiseError(ErrorIndexOutOfRange, "Column index must be non-negative")
+  let proofResult = nomos_da_encoded_data_get_column_proof(
+    encoded.raw, csize_t(column), addr result[0]
+  )
+  if proofResult != Success:
+    raiseLastError("Failed to get column proof")
+
+proc dataView*(encoded: EncodedDataHandle): ByteView {.raises: [ValueError].} =
+  ## Original data borrowed from the handle without copying; use
+  ## `toOpenArray` to pass it on, valid while `encoded` is alive
+  if encoded.raw == nil:
+    raiseError(ErrorNullHandle, "Encoded data handle is null")
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  if nomos_da_encoded_data_data_view(encoded.raw, addr outData, addr outLen) != Success:
+    raiseLastError("Failed to get data view")
+  ByteView(data: cast[ptr UncheckedArray[byte]](outData), len: int(outLen))
+
+proc getData*(encoded: EncodedDataHandle): seq[byte] {.raises: [ValueError].} =
+  ## Copy of the original data, which may be empty
+  dataView(encoded).toBytes
+
+proc columnView*(
+    encoded: EncodedDataHandle, column: int
+): ByteView {.raises: [ValueError].} =
+  ## Extended column borrowed from the handle, laid out like `getColumn`. The
+  ## column is made contiguous once; later views of it do not copy.
+  if encoded.raw == nil:
+    raiseError(ErrorNullHandle, "Encoded data handle is null")
+  if column < 0:
+    raiseError(ErrorIndexOutOfRange, "Column index must be non-negative")
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  let viewResult = nomos_da_encoded_data_column_view(
+    encoded.raw, csize_t(column), addr outData, addr outLen
+  )
+  if viewResult != Success:
+    raiseLastError("Failed to get column view")
+  ByteView(data: cast[ptr UncheckedArray[byte]](outData), len: int(outLen))
+
+proc proofView*(
+    encoded: EncodedDataHandle, column: int
+): ByteView {.raises: [ValueError].} =
+  ## Compressed combined proof of `column` borrowed from the handle
+  if encoded.raw == nil:
+    raiseError(ErrorNullHandle, "Encoded data handle is null")
+  if column < 0:
+    raiseError(ErrorIndexOutOfRange, "Column index must be non-negative")
+  var outProof: ptr uint8 = nil
+  let viewResult =
+    nomos_da_encoded_data_proof_view(encoded.raw, csize_t(column), addr outProof)
+  if viewResult != Success:
+    raiseLastError("Failed to get proof view")
+  ByteView(data: cast[ptr UncheckedArray[byte]](outProof), len: PROOF_SIZE)
 
 proc getShare*(
     encoded: EncodedDataHandle, index: int
 ): ShareHandle {.raises: [ValueError].} =
-  if encoded.pointer == nil:
-    raise newException(ValueError, "Encoded data handle is null")
+  if encoded.raw == nil:
+    raiseError(ErrorNullHandle, "Encoded data handle is null")
   if index < 0:
-    raise newException(ValueError, "Share index must be non-negative")
+    raiseError(ErrorIndexOutOfRange, "Share index must be non-negative")
   var outShareHandle: pointer = nil
   let shareResult = nomos_da_encoded_data_get_share(
-    encoded.pointer, csize_t(index), addr outShareHandle
+    encoded.raw, csize_t(index), addr outShareHandle
   )
   if shareResult != Success:
-    raise newException(ValueError, "Failed to get share: " & getLastError())
+    raiseLastError("Failed to get share")
   if outShareHandle == nil:
-    raise newException(ValueError, "Share handle is null")
-  ShareHandle(outShareHandle)
+    raiseError(ErrorNullHandle, "Share handle is null")
+  ShareHandle(raw: outShareHandle)
 
-proc freeShare*(share: ShareHandle) =
-  if share.pointer != nil:
-    nomos_da_share_free(share.pointer)
+proc freeShare*(share: var ShareHandle) =
+  ## Release the handle before it goes out of scope; safe to call repeatedly
+  share = default(ShareHandle)
 
 func getShareIndex*(share: ShareHandle): int =
-  if share.pointer == nil:
+  if share.raw == nil:
     0
   else:
-    int(nomos_da_share_get_index(share.pointer))
+    int(nomos_da_share_get_index(share.raw))
+
+proc blobId*(share: ShareHandle): BlobId {.raises: [ValueError].} =
+  ## Blob id of the blob the share belongs to
+  if share.raw == nil:
+    raiseError(ErrorNullHandle, "Share handle is null")
+  if nomos_da_share_blob_id(share.raw, addr result[0]) != Success:
+    raiseLastError("Failed to compute blob id")
 
 proc getCommitments*(share: ShareHandle): CommitmentsHandle {.raises: [ValueError].} =
-  if share.pointer == nil:
-    raise newException(ValueError, "Share handle is null")
+  if share.raw == nil:
+    raiseError(ErrorNullHandle, "Share handle is null")
   var outCommitmentsHandle: pointer = nil
   let commitmentsResult =
-    nomos_da_share_get_commitments(share.pointer, addr outCommitmentsHandle)
+    nomos_da_share_get_commitments(share.raw, addr outCommitmentsHandle)
   if commitmentsResult != Success:
-    raise newException(ValueError, "Failed to get commitments: " & getLastError())
+    raiseLastError("Failed to get commitments")
   if outCommitmentsHandle == nil:
-    raise newException(ValueError, "Commitments handle is null")
-  CommitmentsHandle(outCommitmentsHandle)
+    raiseError(ErrorNullHandle, "Commitments handle is null")
+  CommitmentsHandle(raw: outCommitmentsHandle)
 
-proc freeCommitments*(commitments: CommitmentsHandle) =
-  if commitments.pointer != nil:
-    nomos_da_commitments_free(commitments.pointer)
+proc freeCommitments*(commitments: var CommitmentsHandle) =
+  ## Release the handle before it goes out of scope; safe to call repeatedly
+  commitments = default(CommitmentsHandle)
+
+func getRowCount*(commitments: CommitmentsHandle): int =
+  if commitments.raw == nil:
+    0
+  else:
+    int(nomos_da_commitments_row_count(commitments.raw))
+
+proc commitmentsToBytes*(
+    commitments: CommitmentsHandle
+): seq[byte] {.raises: [ValueError].} =
+  ## Export the row commitments as consecutive compressed 48-byte G1 points
+  if commitments.raw == nil:
+    raiseError(ErrorNullHandle, "Commitments handle is null")
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  let exportResult =
+    nomos_da_commitments_to_bytes(commitments.raw, addr outData, addr outLen)
+  if exportResult != Success:
+    raiseLastError("Commitments serialization failed")
+  takeBytes(outData, outLen)
+
+proc bytesToCommitments*(
+    data: openArray[byte]
+): CommitmentsHandle {.raises: [ValueError].} =
+  ## Rebuild commitments from consecutive compressed 48-byte G1 points
+  if data.len == 0 or data.len mod COMMITMENT_SIZE != 0:
+    raiseError(
+      ErrorBadLength,
+      "Data length (" & $data.len & ") must be a non-zero multiple of commitment size (" &
+        $COMMITMENT_SIZE & ")",
+    )
+  var outCommitmentsHandle: pointer = nil
+  let importResult = nomos_da_commitments_from_bytes(
+    unsafeAddr(data[0]), csize_t(data.len), addr outCommitmentsHandle
+  )
+  if importResult != Success:
+    raiseLastError("Commitments deserialization failed")
+  if outCommitmentsHandle == nil:
+    raiseError(ErrorNullHandle, "Commitments handle is null")
+  CommitmentsHandle(raw: outCommitmentsHandle)
+
+proc blobId*(commitments: CommitmentsHandle): BlobId {.raises: [ValueError].} =
+  ## Blob id derived from the row commitments
+  if commitments.raw == nil:
+    raiseError(ErrorNullHandle, "Commitments handle is null")
+  if nomos_da_commitments_blob_id(commitments.raw, addr result[0]) != Success:
+    raiseLastError("Failed to compute blob id")
+
+proc commitmentsEqual*(
+    lhs, rhs: CommitmentsHandle
+): bool {.raises: [ValueError].} =
+  if lhs.raw == nil or rhs.raw == nil:
+    raiseError(ErrorNullHandle, "Commitments handle is null")
+  nomos_da_commitments_equal(lhs.raw, rhs.raw)
+
+proc toLightShare*(share: ShareHandle): LightShareHandle {.raises: [ValueError].} =
+  ## Split the column and proof out of a share; pair it with `getCommitments`
+  ## to hold both halves independently
+  if share.raw == nil:
+    raiseError(ErrorNullHandle, "Share handle is null")
+  var outLightShareHandle: pointer = nil
+  let lightShareResult =
+    nomos_da_light_share_from_share(share.raw, addr outLightShareHandle)
+  if lightShareResult != Success:
+    raiseLastError("Failed to get light share")
+  if outLightShareHandle == nil:
+    raiseError(ErrorNullHandle, "Light share handle is null")
+  LightShareHandle(raw: outLightShareHandle)
+
+proc freeLightShare*(lightShare: var LightShareHandle) =
+  ## Release the handle before it goes out of scope; safe to call repeatedly
+  lightShare = default(LightShareHandle)
+
+func getShareIndex*(lightShare: LightShareHandle): int =
+  if lightShare.raw == nil:
+    0
+  else:
+    int(nomos_da_light_share_get_index(lightShare.raw))
+
+proc lightShareToBytes*(
+    lightShare: LightShareHandle
+): seq[byte] {.raises: [ValueError].} =
+  ## Serialize a light share using the bincode wire format of logos-blockchain nodes
+  if lightShare.raw == nil:
+    raiseError(ErrorNullHandle, "Light share handle is null")
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  let serializeResult =
+    nomos_da_light_share_to_bytes(lightShare.raw, addr outData, addr outLen)
+  if serializeResult != Success:
+    raiseLastError("Light share serialization failed")
+  takeBytes(outData, outLen)
+
+proc bytesToLightShare*(
+    data: openArray[byte]
+): LightShareHandle {.raises: [ValueError].} =
+  ## Deserialize a light share
+  if data.len == 0:
+    raiseError(ErrorBadLength, "Light share bytes must not be empty")
+  var outLightShareHandle: pointer = nil
+  let deserializeResult = nomos_da_light_share_from_bytes(
+    unsafeAddr(data[0]), csize_t(data.len), addr outLightShareHandle
+  )
+  if deserializeResult != Success:
+    raiseLastError("Light share deserialization failed")
+  if outLightShareHandle == nil:
+    raiseError(ErrorNullHandle, "Light share handle is null")
+  LightShareHandle(raw: outLightShareHandle)
 
 proc newVerifier*(): VerifierHandle {.raises: [ValueError].} =
   let handle = nomos_da_verifier_new()
   if handle == nil:
-    raise newException(ValueError, "Failed to create verifier: " & getLastError())
-  VerifierHandle(handle)
+    raiseLastError("Failed to create verifier")
+  VerifierHandle(raw: handle)
+
+proc newVerifier*(verificationKey: openArray[byte]): VerifierHandle {.raises: [ValueError].} =
+  ## Create a verifier from a serialized verification key, for example bytes
+  ## previously returned by `exportVerificationKey`
+  if verificationKey.len == 0:
+    raiseError(ErrorBadLength, "Verification key must not be empty")
+  let handle = nomos_da_verifier_new_with_key(
+    unsafeAddr(verificationKey[0]), csize_t(verificationKey.len)
+  )
+  if handle == nil:
+    raiseLastError("Failed to create verifier")
+  VerifierHandle(raw: handle)
+
+proc newVerifierFromKeyFile*(path: string): VerifierHandle {.raises: [ValueError].} =
+  ## Create a verifier from a verification key stored in a file
+  let handle = nomos_da_verifier_new_with_key_file(path.cstring)
+  if handle == nil:
+    raiseLastError("Failed to create verifier")
+  VerifierHandle(raw: handle)
 
-proc freeVerifier*(verifier: VerifierHandle) =
-  if verifier.pointer != nil:
-    nomos_da_verifier_free(verifier.pointer)
+proc exportVerificationKey*(verifier: VerifierHandle): seq[byte] {.raises: [ValueError].} =
+  ## Serialized verification key the verifier was built with
+  if verifier.raw == nil:
+    raiseError(ErrorNullHandle, "Verifier handle is null")
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  if nomos_da_verifier_export_key(verifier.raw, addr outData, addr outLen) != Success:
+    raiseLastError("Failed to export verification key")
+  takeBytes(outData, outLen)
+
+proc freeVerifier*(verifier: var VerifierHandle) =
+  ## Release the handle before it goes out of scope; safe to call repeatedly
+  verifier = default(VerifierHandle)
+
+proc verifyWithReason*(
+    verifier: VerifierHandle, share: ShareHandle, rowsDomainSize: int
+): NomosDaResult {.raises: [ValueError].} =
+  ## Verify a share, returning `Success` or the reason it was rejected: a bad
+  ## domain size, an out of range index, a malformed column, a row commitment
+  ## count mismatch or a failed proof check
+  if verifier.raw == nil:
+    raiseError(ErrorNullHandle, "Verifier handle is null")
+  if share.raw == nil:
+    raiseError(ErrorNullHandle, "Share handle is null")
+  if rowsDomainSize <= 0:
+    raiseError(ErrorDomainSizeMismatch, "Rows domain size must be greater than 0")
+  nomos_da_verifier_verify(verifier.raw, share.raw, csize_t(rowsDomainSize))
 
 proc verify*(
     verifier: VerifierHandle, share: ShareHandle, rowsDomainSize: int
 ): bool {.raises: [ValueError].} =
-  if verifier.pointer == nil:
-    raise newException(ValueError, "Verifier handle is null")
-  if share.pointer == nil:
-    raise newException(ValueError, "Share handle is null")
+  verifyWithReason(verifier, share, rowsDomainSize) == Success
+
+proc verifyAllWithReason*(
+    verifier: VerifierHandle, shares: openArray[ShareHandle], rowsDomainSize: int
+): seq[NomosDaResult] {.raises: [ValueError].} =
+  ## Verify many shares in a single call; verification runs in parallel on the
+  ## Rust side. Each entry is `Success` or the reason that share was rejected;
+  ## null share handles are reported as `ErrorNullHandle`
+  if verifier.raw == nil:
+    raiseError(ErrorNullHandle, "Verifier handle is null")
+  if rowsDomainSize <= 0:
+    raiseError(ErrorDomainSizeMismatch, "Rows domain size must be greater than 0")
+  if shares.len == 0:
+    return @[]
+  var sharePtrs = newSeq[pointer](shares.len)
+  for i, share in shares:
+    sharePtrs[i] = share.raw
+  result = newSeq[NomosDaResult](shares.len)
+  let verifyResult = nomos_da_verifier_verify_batch(
+    verifier.raw,
+    addr sharePtrs[0],
+    csize_t(shares.len),
+    csize_t(rowsDomainSize),
+    addr result[0],
+  )
+  if verifyResult != Success:
+    raiseLastError("Batch verification failed")
+
+proc verifyAll*(
+    verifier: VerifierHandle, shares: openArray[ShareHandle], rowsDomainSize: int
+): seq[bool] {.raises: [ValueError].} =
+  ## Verify many shares in a single call; null share handles are reported as
+  ## invalid
+  for code in verifyAllWithReason(verifier, shares, rowsDomainSize):
+    result.add(code == Success)
+
+proc verifyWithReason*(
+    verifier: VerifierHandle,
+    lightShare: LightShareHandle,
+    commitments: CommitmentsHandle,
+    rowsDomainSize: int,
+): NomosDaResult {.raises: [ValueError].} =
+  ## Verify a light share against row commitments received separately,
+  ## returning `Success` or the reason it was rejected
+  if verifier.raw == nil:
+    raiseError(ErrorNullHandle, "Verifier handle is null")
+  if lightShare.raw == nil:
+    raiseError(ErrorNullHandle, "Light share handle is null")
+  if commitments.raw == nil:
+    raiseError(ErrorNullHandle, "Commitments handle is null")
   if rowsDomainSize <= 0:
-    raise newException(ValueError, "Rows domain size must be greater than 0")
-  nomos_da_verifier_verify(verifier.pointer, share.pointer, csize_t(rowsDomainSize))
+    raiseError(ErrorDomainSizeMismatch, "Rows domain size must be greater than 0")
+  nomos_da_verifier_verify_light(
+    verifier.raw, lightShare.raw, commitments.raw, csize_t(rowsDomainSize)
+  )
+
+proc verify*(
+    verifier: VerifierHandle,
+    lightShare: LightShareHandle,
+    commitments: CommitmentsHandle,
+    rowsDomainSize: int,
+): bool {.raises: [ValueError].} =
+  ## Verify a light share against row commitments received separately
+  verifyWithReason(verifier, lightShare, commitments, rowsDomainSize) == Success
 
 proc reconstruct*(shares: openArray[ShareHandle]): seq[byte] {.raises: [ValueError].} =
   if shares.len == 0:
-    raise newException(ValueError, "Share count must be greater than 0")
+    raiseError(ErrorBadLength, "Share count must be greater than 0")
   for i, share in shares:
-    if share.pointer == nil:
-      raise newException(ValueError, "Share handle at index " & $i & " is null")
+    if share.raw == nil:
+      raiseError(ErrorNullHandle, "Share handle at index " & $i & " is null")
   var sharePtrs = newSeq[pointer](shares.len)
   for i, share in shares:
-    sharePtrs[i] = share.pointer
+    sharePtrs[i] = share.raw
   var outData: ptr uint8 = nil
   var outLen: CSizeT = 0
   let reconstructResult = nomos_da_reconstruct(
     addr sharePtrs[0], csize_t(shares.len), addr outData, addr outLen
   )
   if reconstructResult != Success:
-    raise newException(ValueError, "Reconstruction failed: " & getLastError())
+    raiseLastError("Reconstruction failed")
   if outData == nil:
-    raise newException(ValueError, "Reconstruction succeeded but output data is null")
+    raiseError(ErrorInternal, "Reconstruction succeeded but output data is null")
   if outLen == 0:
     nomos_da_reconstruct_free(outData, outLen)
-    raise newException(ValueError, "Reconstructed data length is 0")
+    raiseError(ErrorInternal, "Reconstructed data length is 0")
   result = newSeq[byte](int(outLen))
   copyMem(addr result[0], outData, int(outLen))
   nomos_da_reconstruct_free(outData, outLen)
 
+proc reconstructWithMissingData*(
+    shares: openArray[ShareHandle], columnCount: int
+): seq[byte] {.raises: [ValueError].} =
+  ## Reconstruct the original data from any subset of shares holding at least
+  ## `columnCount div 2` distinct indices, recovering missing columns through
+  ## Reed-Solomon decoding
+  if shares.len == 0:
+    raiseError(ErrorBadLength, "Share count must be greater than 0")
+  if columnCount <= 0:
+    raiseError(ErrorInvalidInput, "columnCount must be greater than 0")
+  var sharePtrs = newSeq[pointer](shares.len)
+  for i, share in shares:
+    if share.raw == nil:
+      raiseError(ErrorNullHandle, "Share handle at index " & $i & " is null")
+    sharePtrs[i] = share.raw
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  let reconstructResult = nomos_da_reconstruct_with_missing_data(
+    addr sharePtrs[0], csize_t(shares.len), csize_t(columnCount), addr outData,
+    addr outLen,
+  )
+  if reconstructResult != Success:
+    raiseLastError("Reconstruction failed")
+  if outData == nil:
+    raiseError(ErrorInternal, "Reconstruction succeeded but output data is null")
+  result = newSeq[byte](int(outLen))
+  if outLen > 0:
+    copyMem(addr result[0], outData, int(outLen))
+  nomos_da_reconstruct_free(outData, outLen)
+
+proc reconstructAndVerify*(
+    encoder: EncoderHandle, shares: openArray[ShareHandle], columnCount: int
+): seq[byte] {.raises: [ValueError].} =
+  ## Like `reconstructWithMissingData`, then re-encode the result with `encoder`
+  ## and check it against the row commitments of the shares. Raises
+  ## `ReencodingMismatchError` when the shares commit to different data, or
+  ## when `encoder` uses other parameters than the blob was encoded with, and
+  ## `InvalidInputError` when `columnCount` is not the encoder's column count.
+  if encoder.raw == nil:
+    raiseError(ErrorNullHandle, "Encoder handle is null")
+  if shares.len == 0:
+    raiseError(ErrorBadLength, "Share count must be greater than 0")
+  if columnCount <= 0:
+    raiseError(ErrorInvalidInput, "columnCount must be greater than 0")
+  var sharePtrs = newSeq[pointer](shares.len)
+  for i, share in shares:
+    if share.raw == nil:
+      raiseError(ErrorNullHandle, "Share handle at index " & $i & " is null")
+    sharePtrs[i] = share.raw
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  let reconstructResult = nomos_da_reconstruct_and_verify(
+    encoder.raw,
+    addr sharePtrs[0], csize_t(shares.len), csize_t(columnCount), addr outData,
+    addr outLen,
+  )
+  if reconstructResult != Success:
+    raiseLastError("Verified reconstruction failed")
+  result = newSeq[byte](int(outLen))
+  if outLen > 0:
+    copyMem(addr result[0], outData, int(outLen))
+  nomos_da_reconstruct_free(outData, outLen)
+
+proc encodedDataFromShares*(
+    shares: openArray[ShareHandle], columnCount: int
+): EncodedDataHandle {.raises: [ValueError].} =
+  ## Rebuild the encoded data of a blob from one share per column, in any
+  ## order, so shares and proofs can be served again from it
+  if shares.len == 0:
+    raiseError(ErrorBadLength, "Share count must be greater than 0")
+  if columnCount <= 0:
+    raiseError(ErrorInvalidInput, "columnCount must be greater than 0")
+  var sharePtrs = newSeq[pointer](shares.len)
+  for i, share in shares:
+    if share.raw == nil:
+      raiseError(ErrorNullHandle, "Share handle at index " & $i & " is null")
+    sharePtrs[i] = share.raw
+  var outHandle: pointer = nil
+  let assembleResult = nomos_da_encoded_data_from_shares(
+    addr sharePtrs[0], csize_t(shares.len), csize_t(columnCount), addr outHandle
+  )
+  if assembleResult != Success:
+    raiseLastError("Share assembly failed")
+  EncodedDataHandle(raw: outHandle)
+
+proc reconstructPadded*(
+    shares: openArray[ShareHandle], columnCount: int
+): seq[byte] {.raises: [ValueError].} =
+  ## Reconstruct a payload encoded with `encodePadded` from any sufficient subset
+  ## of shares, stripping the padding so the result matches the original input
+  if shares.len == 0:
+    raiseError(ErrorBadLength, "Share count must be greater than 0")
+  if columnCount <= 0:
+    raiseError(ErrorInvalidInput, "columnCount must be greater than 0")
+  var sharePtrs = newSeq[pointer](shares.len)
+  for i, share in shares:
+    if share.raw == nil:
+      raiseError(ErrorNullHandle, "Share handle at index " & $i & " is null")
+    sharePtrs[i] = share.raw
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  let reconstructResult = nomos_da_reconstruct_padded(
+    addr sharePtrs[0], csize_t(shares.len), csize_t(columnCount), addr outData,
+    addr outLen,
+  )
+  if reconstructResult != Success:
+    raiseLastError("Reconstruction failed")
+  result = newSeq[byte](int(outLen))
+  if outLen > 0:
+    copyMem(addr result[0], outData, int(outLen))
+  nomos_da_reconstruct_free(outData, outLen)
+
+# ============================================================================
+# Sampling
+# ============================================================================
+
+proc selectSampleColumns*(
+    blobId: BlobId, seed: openArray[byte], columnCount, sampleCount: int
+): SamplingPlan {.raises: [ValueError].} =
+  ## Deterministically choose `sampleCount` distinct columns of a blob for the
+  ## node identified by `seed`; the same inputs always give the same plan.
+  ## Columns are drawn the way a logos-blockchain node picks the subnetworks it
+  ## samples, with the generator seeded from the blob id and `seed`
+  if columnCount <= 0:
+    raiseError(ErrorDomainSizeMismatch, "columnCount must be greater than 0")
+  if sampleCount < 0:
+    raiseError(ErrorBadLength, "sampleCount must not be negative")
+  var columns = newSeq[uint16](sampleCount)
+  var confidence: cdouble = 0
+  let sampleResult = nomos_da_sample_columns(
+    unsafeAddr(blobId[0]),
+    (if seed.len > 0: unsafeAddr(seed[0]) else: nil),
+    csize_t(seed.len),
+    csize_t(columnCount),
+    csize_t(sampleCount),
+    (if sampleCount > 0: addr columns[0] else: nil),
+    addr confidence,
+  )
+  if sampleResult != Success:
+    raiseLastError("Sample selection failed")
+  result.columns = newSeq[int](sampleCount)
+  for i, column in columns:
+    result.columns[i] = int(column)
+  result.confidence = float(confidence)
+
+proc samplingConfidence*(
+    columnCount, sampleCount: int
+): float {.raises: [ValueError].} =
+  ## Probability that `sampleCount` distinct samples detect a blob withheld
+  ## just enough to be unrecoverable
+  if columnCount <= 0:
+    raiseError(ErrorDomainSizeMismatch, "columnCount must be greater than 0")
+  if sampleCount < 0:
+    raiseError(ErrorBadLength, "sampleCount must not be negative")
+  var confidence: cdouble = 0
+  let confidenceResult = nomos_da_sampling_confidence(
+    csize_t(columnCount), csize_t(sampleCount), addr confidence
+  )
+  if confidenceResult != Success:
+    raiseLastError("Invalid sampling parameters")
+  float(confidence)
+
+proc simulate*(config: SimulationConfig): SimulationReport {.raises: [ValueError].} =
+  ## Run an in-process sampling simulation: storage nodes hold random columns,
+  ## adversarial nodes withhold or corrupt theirs and light clients sample
+  ## `samplesPerClient` columns each. The same config always gives the same report.
+  var config = config
+  if nomos_da_simulate(addr config, addr result) != Success:
+    raiseLastError("Simulation failed")
+
+# ============================================================================
+# Multi-Blob Splitting
+# ============================================================================
+
+proc encodeSplit*(
+    encoder: EncoderHandle, payload: openArray[byte], maxBlobSize: int
+): SplitPayload {.raises: [ValueError].} =
+  ## Split a payload of any length into blobs of at most `maxBlobSize` encoded
+  ## bytes (a multiple of `CHUNK_SIZE`) and encode each one. The manifest lists
+  ## the blobs in payload order and is what `reassemble` needs afterwards
+  if encoder.raw == nil:
+    raiseError(ErrorNullHandle, "Encoder handle is null")
+  if maxBlobSize <= 0:
+    raiseError(ErrorInvalidInput, "maxBlobSize must be greater than 0")
+  var blobCount: CSizeT = 0
+  if nomos_da_split_blob_count(csize_t(payload.len), csize_t(maxBlobSize), addr blobCount) !=
+      Success:
+    raiseLastError("Invalid blob size limit")
+
+  var rawBlobs = newSeq[pointer](int(blobCount))
+  var outManifest: pointer = nil
+  let dataPtr =
+    if payload.len > 0:
+      unsafeAddr(payload[0])
+    else:
+      nil
+  let encodeResult = nomos_da_encoder_encode_split(
+    encoder.raw,
+    dataPtr,
+    csize_t(payload.len),
+    csize_t(maxBlobSize),
+    addr rawBlobs[0],
+    blobCount,
+    addr outManifest,
+  )
+  if encodeResult != Success:
+    raiseLastError("Split encoding failed")
+  result.manifest = BlobManifestHandle(raw: outManifest)
+  for raw in rawBlobs:
+    result.blobs.add(EncodedDataHandle(raw: raw))
+
+proc payloadLen*(manifest: BlobManifestHandle): int {.raises: [ValueError].} =
+  ## Length of the whole payload the manifest describes
+  if manifest.raw == nil:
+    raiseError(ErrorNullHandle, "Manifest handle is null")
+  int(nomos_da_manifest_payload_len(manifest.raw))
+
+proc entries*(manifest: BlobManifestHandle): seq[BlobManifestEntry] {.raises: [ValueError].} =
+  ## Blob ids, payload lengths and padding of the blobs, in payload order
+  if manifest.raw == nil:
+    raiseError(ErrorNullHandle, "Manifest handle is null")
+  let count = int(nomos_da_manifest_blob_count(manifest.raw))
+  result = newSeq[BlobManifestEntry](count)
+  for i in 0 ..< count:
+    if nomos_da_manifest_entry(manifest.raw, csize_t(i), addr result[i]) != Success:
+      raiseLastError("Failed to read manifest entry " & $i)
+
+proc manifestToBytes*(manifest: BlobManifestHandle): seq[byte] {.raises: [ValueError].} =
+  if manifest.raw == nil:
+    raiseError(ErrorNullHandle, "Manifest handle is null")
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  if nomos_da_manifest_to_bytes(manifest.raw, addr outData, addr outLen) != Success:
+    raiseLastError("Manifest serialization failed")
+  takeBytes(outData, outLen)
+
+proc bytesToManifest*(data: openArray[byte]): BlobManifestHandle {.raises: [ValueError].} =
+  if data.len == 0:
+    raiseError(ErrorBadLength, "Manifest bytes must not be empty")
+  var outManifest: pointer = nil
+  if nomos_da_manifest_from_bytes(unsafeAddr(data[0]), csize_t(data.len), addr outManifest) !=
+      Success:
+    raiseLastError("Manifest deserialization failed")
+  BlobManifestHandle(raw: outManifest)
+
+proc reassemble*(
+    manifest: BlobManifestHandle, blobs: openArray[seq[byte]]
+): seq[byte] {.raises: [ValueError].} =
+  ## Rebuild the payload from the reconstructed data of its blobs (as returned
+  ## by `reconstruct`), given in manifest order
+  if manifest.raw == nil:
+    raiseError(ErrorNullHandle, "Manifest handle is null")
+  var blobPtrs = newSeq[ptr uint8](blobs.len)
+  var blobLens = newSeq[CSizeT](blobs.len)
+  for i, blob in blobs:
+    if blob.len > 0:
+      blobPtrs[i] = unsafeAddr(blob[0])
+    blobLens[i] = csize_t(blob.len)
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  let reassembleResult = nomos_da_manifest_reassemble(
+    manifest.raw,
+    (if blobs.len > 0: addr blobPtrs[0] else: nil),
+    (if blobs.len > 0: addr blobLens[0] else: nil),
+    csize_t(blobs.len),
+    addr outData,
+    addr outLen,
+  )
+  if reassembleResult != Success:
+    raiseLastError("Reassembly failed")
+  takeBytes(outData, outLen)
+
+# ============================================================================
+# Share Store
+# ============================================================================
+
+proc openShareStore*(path: string): ShareStoreHandle {.raises: [ValueError].} =
+  ## Open the on-disk share store in directory `path`, creating it if needed.
+  ## Shares put into the store survive restarts until they are pruned.
+  let handle = nomos_da_store_open(path.cstring)
+  if handle == nil:
+    raiseLastError("Failed to open share store")
+  ShareStoreHandle(raw: handle)
+
+proc freeShareStore*(store: var ShareStoreHandle) =
+  ## Release the handle before it goes out of scope; safe to call repeatedly
+  store = default(ShareStoreHandle)
+
+proc put*(store: ShareStoreHandle, share: ShareHandle) {.raises: [ValueError].} =
+  ## Persist a share and its row commitments, replacing any stored copy
+  if store.raw == nil:
+    raiseError(ErrorNullHandle, "Share store handle is null")
+  if share.raw == nil:
+    raiseError(ErrorNullHandle, "Share handle is null")
+  if nomos_da_store_put(store.raw, share.raw) != Success:
+    raiseLastError("Failed to store share")
+
+proc get*(
+    store: ShareStoreHandle, blobId: BlobId, column: int
+): ShareHandle {.raises: [ValueError].} =
+  ## Load a stored share; raises `NotFoundError` when the column is not held
+  if store.raw == nil:
+    raiseError(ErrorNullHandle, "Share store handle is null")
+  if column < 0 or column > int(high(uint16)):
+    raiseError(ErrorIndexOutOfRange, "Column " & $column & " is out of range")
+  var outShareHandle: pointer = nil
+  let getResult = nomos_da_store_get(
+    store.raw, unsafeAddr(blobId[0]), uint16(column), addr outShareHandle
+  )
+  if getResult != Success:
+    raiseLastError("Failed to load share")
+  ShareHandle(raw: outShareHandle)
+
+proc columns*(store: ShareStoreHandle, blobId: BlobId): seq[int] {.raises: [ValueError].} =
+  ## Column indices held for a blob, in ascending order
+  if store.raw == nil:
+    raiseError(ErrorNullHandle, "Share store handle is null")
+  var outColumns: ptr uint16 = nil
+  var outCount: CSizeT = 0
+  let listResult = nomos_da_store_list_columns(
+    store.raw, unsafeAddr(blobId[0]), addr outColumns, addr outCount
+  )
+  if listResult != Success:
+    raiseLastError("Failed to list stored columns")
+  if outColumns == nil:
+    return @[]
+  let listed = cast[ptr UncheckedArray[uint16]](outColumns)
+  result = newSeq[int](int(outCount))
+  for i in 0 ..< int(outCount):
+    result[i] = int(listed[i])
+  nomos_da_store_columns_free(outColumns, outCount)
+
+proc prune*(store: ShareStoreHandle, maxAgeSeconds: int): int {.raises: [ValueError].} =
+  ## Remove every share stored more than `maxAgeSeconds` ago, returning how
+  ## many were removed
+  if store.raw == nil:
+    raiseError(ErrorNullHandle, "Share store handle is null")
+  if maxAgeSeconds < 0:
+    raiseError(ErrorInvalidInput, "maxAgeSeconds must not be negative")
+  var removed: CSizeT = 0
+  if nomos_da_store_prune(store.raw, uint64(maxAgeSeconds), addr removed) != Success:
+    raiseLastError("Failed to prune share store")
+  int(removed)
+
 # ============================================================================
 # Serialization Support (using nim-bincode)
 # ============================================================================
@@ -253,12 +1397,6 @@ import stew/endians2
 type BincodeError* = bc.BincodeError
 
 type
-  SerializableShare* = object
-    ## Serializable representation of a share
-    index*: uint16
-    # Note: Share data itself is opaque and cannot be serialized directly
-    # To serialize share data, you would need additional FFI functions
-
   SerializableEncodedData* = object
     ## Serializable representation of encoded data metadata
     data*: seq[byte]
@@ -275,22 +1413,37 @@ proc deserializeUint16*(data: openArray[byte]): uint16 {.raises: [BincodeError].
     raise newException(BincodeError, "Cannot deserialize uint16: insufficient data")
   fromBytesLE(uint16, bytes)
 
-proc shareToBytes*(share: ShareHandle): seq[byte] {.raises: [ValueError, BincodeError].} =
-  ## Serialize share metadata to bytes
-  if share.pointer == nil:
-    raise newException(ValueError, "Share handle is null")
-  let index = uint16(getShareIndex(share))
-  serializeUint16(index)
+proc shareToBytes*(share: ShareHandle): seq[byte] {.raises: [ValueError].} =
+  ## Serialize a full share (column, combined column proof and row commitments)
+  ## using the bincode wire format of logos-blockchain nodes
+  if share.raw == nil:
+    raiseError(ErrorNullHandle, "Share handle is null")
+  var outData: ptr uint8 = nil
+  var outLen: CSizeT = 0
+  let serializeResult = nomos_da_share_to_bytes(share.raw, addr outData, addr outLen)
+  if serializeResult != Success:
+    raiseLastError("Share serialization failed")
+  takeBytes(outData, outLen)
 
-proc bytesToShare*(data: seq[byte]): SerializableShare {.raises: [BincodeError].} =
-  ## Deserialize share metadata from bytes
-  let index = deserializeUint16(data)
-  SerializableShare(index: index)
+proc bytesToShare*(data: openArray[byte]): ShareHandle {.raises: [ValueError].} =
+  ## Deserialize a share produced by `shareToBytes` or by a logos-blockchain node
+  if data.len == 0:
+    raiseError(ErrorBadLength, "Share bytes must not be empty")
+  var outShareHandle: pointer = nil
+  var info: NomosDaErrorInfo
+  let deserializeResult = nomos_da_share_from_bytes_with_error(
+    unsafeAddr(data[0]), csize_t(data.len), addr outShareHandle, addr info
+  )
+  if deserializeResult != Success:
+    raiseErrorInfo(info, "Share deserialization failed")
+  if outShareHandle == nil:
+    raiseError(ErrorNullHandle, "Share handle is null")
+  ShareHandle(raw: outShareHandle)
 
 proc encodedDataToBytes*(encoded: EncodedDataHandle): seq[byte] {.raises: [ValueError, BincodeError].} =
   ## Serialize encoded data to bytes
-  if encoded.pointer == nil:
-    raise newException(ValueError, "Encoded data handle is null")
+  if encoded.raw == nil:
+    raiseError(ErrorNullHandle, "Encoded data handle is null")
   let data = getData(encoded)
   let shareCount = uint32(getShareCount(encoded))
   # Create a structure with raw bytes for length/count, then serialize the whole thing
@@ -351,15 +1504,11 @@ when isMainModule:
   echo "Chunk size: ", CHUNK_SIZE, " bytes"
   try:
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
     var testData = newSeq[byte](CHUNK_SIZE * 2)
     for i in 0 ..< testData.len:
       testData[i] = byte((i mod 256))
     echo "Encoding ", testData.len, " bytes of data..."
     let encoded = encode(encoder, testData)
-    defer:
-      freeEncodedData(encoded)
     echo "Encoded successfully!"
     echo "Number of shares: ", getShareCount(encoded)
     let retrievedData = getData(encoded)
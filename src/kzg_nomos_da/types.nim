@@ -1,17 +1,249 @@
+{.push raises: [], gcsafe.}
+
+const ERROR_MESSAGE_SIZE* = 256
+  ## Capacity of `NomosDaErrorInfo.message`, including the NUL terminator
+
 type
-  NomosDaResult* = enum
-    Success = 0
-    ErrorInvalidInput = -1
-    ErrorInternal = -2
+  NomosDaResult* {.size: sizeof(cint).} = enum
+    ErrorReencodingMismatch = -18
+    ErrorNotFound = -17
+    ErrorIo = -16
+    ErrorColumnLengthMismatch = -15
+    ErrorRowCommitmentCount = -14
+    ErrorInvalidPadding = -13
+    ErrorInsufficientShares = -12
+    ErrorDeserialization = -11
+    ErrorProofInvalid = -10
+    ErrorCommitmentMismatch = -9
+    ErrorDomainSizeMismatch = -8
+    ErrorIndexOutOfRange = -7
+    ErrorBadLength = -6
+    ErrorNullHandle = -5
+    ErrorPanic = -4
     ErrorAllocation = -3
+    ErrorInternal = -2
+    ErrorInvalidInput = -1
+    Success = 0
+
+  NomosDaError* = object of ValueError
+    ## Base of every error raised by the wrapper; `code` is the FFI result code
+    code*: NomosDaResult
+
+  NomosDaErrorInfo* = object
+    ## Mirrors the Rust `NomosDaError` filled in by the `_with_error` entry
+    ## points; named apart from the exception above
+    code*: NomosDaResult
+    message*: array[ERROR_MESSAGE_SIZE, char]
+      ## NUL-terminated, truncated to fit; empty when the call succeeded
+
+  InvalidInputError* = object of NomosDaError
+  InternalError* = object of NomosDaError
+  AllocationError* = object of NomosDaError
+  PanicError* = object of NomosDaError
+  NullHandleError* = object of NomosDaError
+  BadLengthError* = object of NomosDaError
+  IndexOutOfRangeError* = object of NomosDaError
+  DomainSizeMismatchError* = object of NomosDaError
+  CommitmentMismatchError* = object of NomosDaError
+  ProofInvalidError* = object of NomosDaError
+  DeserializationError* = object of NomosDaError
+  InsufficientSharesError* = object of NomosDaError
+  InvalidPaddingError* = object of NomosDaError
+  RowCommitmentCountError* = object of NomosDaError
+  ColumnLengthMismatchError* = object of NomosDaError
+  NomosIoError* = object of NomosDaError
+  NotFoundError* = object of NomosDaError
+  ReencodingMismatchError* = object of NomosDaError
+
+  # Handles own the Rust object behind `raw` and release it when they go out of
+  # scope. `raw` may be passed to FFI calls but must never be freed by hand.
+  # Encoders, verifiers, shares and commitments are reference-counted on the Rust
+  # side: copying one takes another reference to the same object, and copies may
+  # be sent to and used from other threads concurrently. Encoded data, encoder
+  # sessions, blob manifests and share stores cannot be copied; light shares are
+  # deep-copied on the Rust side.
+  EncoderHandle* = object
+    raw*: pointer
 
-  NomosDaError* = object
-    code*: int32
-    message*: string
+  EncodedDataHandle* = object
+    raw*: pointer
+
+  EncoderSessionHandle* = object
+    raw*: pointer
+
+  VerifierHandle* = object
+    raw*: pointer
+
+  ShareHandle* = object
+    raw*: pointer
+
+  LightShareHandle* = object
+    raw*: pointer
+
+  CommitmentsHandle* = object
+    raw*: pointer
+
+  ShareStoreHandle* = object
+    raw*: pointer
+
+  BlobManifestHandle* = object
+    raw*: pointer
 
-  EncoderHandle* = distinct pointer
-  EncodedDataHandle* = distinct pointer
-  VerifierHandle* = distinct pointer
-  ShareHandle* = distinct pointer
-  CommitmentsHandle* = distinct pointer
   CSizeT* = csize_t
+
+  ByteView* = object
+    ## Bytes borrowed from a handle without copying. Only valid while the
+    ## handle it came from is alive; copy with `toBytes` to keep the data longer.
+    data*: ptr UncheckedArray[byte]
+    len*: int
+
+  EncoderOptions* = object
+    ## Mirrors `NomosDaEncoderOptions`
+    columnCount*: csize_t
+    withCache*: bool
+      ## Precompute the trusted setup cache once, speeding up every encode
+    threadCount*: csize_t
+      ## Threads used for encoding; 0 shares the global rayon pool
+  AdversaryBehavior* {.size: sizeof(cint).} = enum
+    Withhold = 0 ## Adversarial nodes never answer
+    Corrupt = 1 ## Adversarial nodes answer with a tampered column
+
+  SimulationConfig* = object
+    ## Mirrors the Rust `SimulationConfig`
+    columnCount*: csize_t
+    payloadSize*: csize_t
+    nodeCount*: csize_t
+    columnsPerNode*: csize_t
+      ## Distinct columns held by each storage node
+    adversarialNodes*: csize_t
+    behavior*: AdversaryBehavior
+    lightClients*: csize_t
+    samplesPerClient*: csize_t
+    seed*: uint64
+
+  SimulationReport* = object
+    ## Mirrors the Rust `SimulationReport`
+    availableColumns*: csize_t
+      ## Columns for which at least one node serves a valid share
+    detectingClients*: csize_t
+    detectionRate*: cdouble
+    expectedConfidence*: cdouble
+      ## Detection probability predicted for a minimal withholding attack
+    reconstructed*: bool
+
+  BlobId* = array[32, byte]
+  BlobManifestEntry* = object
+    ## Mirrors the Rust `BlobManifestEntry`
+    blobId*: BlobId
+    payloadLen*: uint64
+      ## Payload bytes carried by the blob
+    padding*: uint64
+      ## Zero bytes appended after the payload to reach a chunk boundary
+
+  SplitPayload* = object
+    blobs*: seq[EncodedDataHandle]
+      ## One encoded blob per piece, in payload order
+    manifest*: BlobManifestHandle
+  SamplingPlan* = object
+    columns*: seq[int]
+      ## Distinct column indices to sample, ascending
+    confidence*: float
+      ## Probability that the sample detects an unrecoverable blob
+  Commitment* = array[48, byte]
+  Proof* = array[48, byte]
+
+proc nomos_da_encoder_release(handle: pointer) {.importc: "nomos_da_encoder_release".}
+proc nomos_da_encoder_retain(
+  handle: pointer
+): pointer {.importc: "nomos_da_encoder_retain".}
+proc nomos_da_encoder_session_free(
+  handle: pointer
+) {.importc: "nomos_da_encoder_session_free".}
+
+proc nomos_da_encoded_data_free(
+  handle: pointer
+) {.importc: "nomos_da_encoded_data_free".}
+
+proc nomos_da_verifier_release(handle: pointer) {.importc: "nomos_da_verifier_release".}
+proc nomos_da_verifier_retain(
+  handle: pointer
+): pointer {.importc: "nomos_da_verifier_retain".}
+
+proc nomos_da_share_release(handle: pointer) {.importc: "nomos_da_share_release".}
+proc nomos_da_share_retain(handle: pointer): pointer {.importc: "nomos_da_share_retain".}
+proc nomos_da_light_share_free(
+  handle: pointer
+) {.importc: "nomos_da_light_share_free".}
+
+proc nomos_da_light_share_clone(
+  handle: pointer
+): pointer {.importc: "nomos_da_light_share_clone".}
+
+proc nomos_da_commitments_release(
+  handle: pointer
+) {.importc: "nomos_da_commitments_release".}
+
+proc nomos_da_commitments_retain(
+  handle: pointer
+): pointer {.importc: "nomos_da_commitments_retain".}
+
+proc nomos_da_store_free(handle: pointer) {.importc: "nomos_da_store_free".}
+proc nomos_da_manifest_free(handle: pointer) {.importc: "nomos_da_manifest_free".}
+
+template ownedHandle(T: typedesc, freeImpl: untyped) =
+  proc `=destroy`*(handle: T) =
+    if handle.raw != nil:
+      freeImpl(handle.raw)
+
+  proc `=sink`*(dest: var T, source: T) =
+    `=destroy`(dest)
+    wasMoved(dest)
+    dest.raw = source.raw
+
+template uniqueHandle(T: typedesc, freeImpl: untyped) =
+  ownedHandle(T, freeImpl)
+
+  proc `=copy`*(dest: var T, source: T) {.error.}
+
+template clonableHandle(T: typedesc, freeImpl, cloneImpl: untyped) =
+  ownedHandle(T, freeImpl)
+
+  proc `=copy`*(dest: var T, source: T) =
+    if dest.raw == source.raw:
+      return
+    `=destroy`(dest)
+    wasMoved(dest)
+    if source.raw != nil:
+      dest.raw = cloneImpl(source.raw)
+
+template sharedHandle(T: typedesc, releaseImpl, retainImpl: untyped) =
+  # Rust reference counts are atomic, so copies may live on different threads
+  clonableHandle(T, releaseImpl, retainImpl)
+
+uniqueHandle(EncodedDataHandle, nomos_da_encoded_data_free)
+uniqueHandle(EncoderSessionHandle, nomos_da_encoder_session_free)
+uniqueHandle(ShareStoreHandle, nomos_da_store_free)
+uniqueHandle(BlobManifestHandle, nomos_da_manifest_free)
+sharedHandle(EncoderHandle, nomos_da_encoder_release, nomos_da_encoder_retain)
+sharedHandle(VerifierHandle, nomos_da_verifier_release, nomos_da_verifier_retain)
+sharedHandle(ShareHandle, nomos_da_share_release, nomos_da_share_retain)
+clonableHandle(LightShareHandle, nomos_da_light_share_free, nomos_da_light_share_clone)
+sharedHandle(CommitmentsHandle, nomos_da_commitments_release, nomos_da_commitments_retain)
+
+template toOpenArray*(view: ByteView): openArray[byte] =
+  view.data.toOpenArray(0, view.len - 1)
+
+func toBytes*(view: ByteView): seq[byte] =
+  result = newSeq[byte](view.len)
+  if view.len > 0:
+    copyMem(addr result[0], view.data, view.len)
+
+func isNil*(
+    handle:
+      EncoderHandle | EncodedDataHandle | EncoderSessionHandle | VerifierHandle |
+      ShareHandle | LightShareHandle | CommitmentsHandle | ShareStoreHandle |
+      BlobManifestHandle
+): bool =
+  handle.raw == nil
+
+{.pop.}
@@ -1,4 +1,5 @@
 import unittest
+import os
 import strutils
 import ../src/kzg_nomos_da
 import ../src/kzg_nomos_da/types
@@ -18,15 +19,15 @@ suite "nomos-da Encoder API Tests":
     check CHUNK_SIZE > 0
 
   test "encoder creation and destruction":
-    let encoder = newEncoder(columnCount = 4)
-    check encoder.pointer != nil
+    var encoder = newEncoder(columnCount = 4)
+    check encoder.raw != nil
     freeEncoder(encoder)
+    check encoder.isNil
 
   test "encoder creation with various column counts":
     for columnCount in [2, 4, 8, 16, 32]:
       let encoder = newEncoder(columnCount = columnCount)
-      check encoder.pointer != nil
-      freeEncoder(encoder)
+      check encoder.raw != nil
 
   test "encoder creation fails with invalid column count":
     expect ValueError:
@@ -37,47 +38,33 @@ suite "nomos-da Encoder API Tests":
 
   test "encode with single chunk":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
-    check encoded.pointer != nil
+    check encoded.raw != nil
     check getShareCount(encoded) == 4
 
   test "encode with multiple chunks":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE * 2)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
-    check encoded.pointer != nil
+    check encoded.raw != nil
     check getShareCount(encoded) == 4
 
   test "encode with large data":
     let encoder = newEncoder(columnCount = 8)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE * 10)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
-    check encoded.pointer != nil
+    check encoded.raw != nil
     check getShareCount(encoded) == 8
 
   test "encode fails with empty data":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data: seq[byte] = @[]
     expect ValueError:
@@ -85,91 +72,69 @@ suite "nomos-da Encoder API Tests":
 
   test "encode fails with data not multiple of chunk size":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(50) # Not a multiple of 31
     expect ValueError:
       discard encode(encoder, data)
 
   test "encode fails with null encoder":
-    let encoder = EncoderHandle(nil)
+    let encoder = EncoderHandle()
     let data = createTestData(CHUNK_SIZE)
     expect ValueError:
       discard encode(encoder, data)
 
   test "getData retrieves original data":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let originalData = createTestData(CHUNK_SIZE * 2)
     let encoded = encode(encoder, originalData)
-    defer:
-      freeEncodedData(encoded)
 
     let retrievedData = getData(encoded)
     check retrievedData == originalData
 
   test "getData with various data sizes":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     for size in [CHUNK_SIZE, CHUNK_SIZE * 2, CHUNK_SIZE * 5, CHUNK_SIZE * 10]:
       let originalData = createTestData(size)
       let encoded = encode(encoder, originalData)
-      defer:
-        freeEncodedData(encoded)
 
       let retrievedData = getData(encoded)
       check retrievedData == originalData
 
   test "getData fails with null handle":
-    let encoded = EncodedDataHandle(nil)
+    let encoded = EncodedDataHandle()
     expect ValueError:
       discard getData(encoded)
 
   test "getShareCount returns correct value":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     check getShareCount(encoded) == 4
 
   test "getShareCount with different column counts":
     for columnCount in [2, 4, 8, 16]:
       let encoder = newEncoder(columnCount = columnCount)
-      defer:
-        freeEncoder(encoder)
 
       let data = createTestData(CHUNK_SIZE)
       let encoded = encode(encoder, data)
-      defer:
-        freeEncodedData(encoded)
 
       check getShareCount(encoded) == columnCount
 
   test "getShareCount returns 0 for null handle":
-    let encoded = EncodedDataHandle(nil)
+    let encoded = EncodedDataHandle()
     check getShareCount(encoded) == 0
 
   test "complete workflow: encode -> getData -> verify":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let originalData = createTestData(CHUNK_SIZE * 3)
     let encoded = encode(encoder, originalData)
-    defer:
-      freeEncodedData(encoded)
 
-    check encoded.pointer != nil
+    check encoded.raw != nil
     check getShareCount(encoded) == 4
 
     let retrievedData = getData(encoded)
@@ -177,23 +142,15 @@ suite "nomos-da Encoder API Tests":
 
   test "multiple encoders can coexist":
     let encoder1 = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder1)
 
     let encoder2 = newEncoder(columnCount = 8)
-    defer:
-      freeEncoder(encoder2)
 
     let data1 = createTestData(CHUNK_SIZE)
     let data2 = createTestData(CHUNK_SIZE * 2)
 
     let encoded1 = encode(encoder1, data1)
-    defer:
-      freeEncodedData(encoded1)
 
     let encoded2 = encode(encoder2, data2)
-    defer:
-      freeEncodedData(encoded2)
 
     check getShareCount(encoded1) == 4
     check getShareCount(encoded2) == 8
@@ -203,84 +160,62 @@ suite "nomos-da Encoder API Tests":
 
   test "encode with minimum data size (one chunk)":
     let encoder = newEncoder(columnCount = 2)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
-    check encoded.pointer != nil
+    check encoded.raw != nil
     check getShareCount(encoded) == 2
     check getData(encoded) == data
 
   test "encode with very large data":
     let encoder = newEncoder(columnCount = 16)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE * 100)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
-    check encoded.pointer != nil
+    check encoded.raw != nil
     check getShareCount(encoded) == 16
     check getData(encoded) == data
 
   test "encode with all zeros":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = newSeq[byte](CHUNK_SIZE * 2)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
-    check encoded.pointer != nil
+    check encoded.raw != nil
     let retrievedData = getData(encoded)
     check retrievedData == data
 
   test "encode with all 0xFF":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     var data = newSeq[byte](CHUNK_SIZE * 2)
     for i in 0 ..< data.len:
       data[i] = 0xFF
 
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
-    check encoded.pointer != nil
+    check encoded.raw != nil
     let retrievedData = getData(encoded)
     check retrievedData == data
 
   test "encode with pattern data":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     var data = newSeq[byte](CHUNK_SIZE * 2)
     for i in 0 ..< data.len:
       data[i] = byte(i mod 256)
 
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
-    check encoded.pointer != nil
+    check encoded.raw != nil
     let retrievedData = getData(encoded)
     check retrievedData == data
 
   test "error message is available after failed encode":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(50) # Not a multiple of chunk size
 
@@ -295,18 +230,250 @@ suite "nomos-da Encoder API Tests":
 
   test "getLastError returns empty string when no error":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
-    discard getLastError()
+    check getLastError() == ""
+
+  test "getLastError is cleared by the next successful call":
+    expect DeserializationError:
+      discard bytesToShare(@[0xff'u8])
+    check getLastError().len > 0
+    check nomos_da_get_last_error_code() != Success
+
+    let encoder = newEncoder(columnCount = 4)
+    check getLastError() == ""
+    check nomos_da_get_last_error_code() == Success
+
+  test "failures reported through NomosDaErrorInfo carry code and message":
+    let encoder = newEncoder(columnCount = 4)
+    check encode(encoder, createTestData(CHUNK_SIZE)).raw != nil
+
+    const operation = "Share deserialization failed: "
+    try:
+      discard bytesToShare(@[0xff'u8])
+      fail()
+    except DeserializationError as e:
+      check e.msg.startsWith(operation)
+      check e.msg.len > operation.len
+      check e.msg.len - operation.len < ERROR_MESSAGE_SIZE
+    check nomos_da_get_last_error_code() == ErrorDeserialization
 
 test "initialization and cleanup work":
   discard nomos_da_init()
   nomos_da_cleanup()
   discard nomos_da_init()
   nomos_da_cleanup()
+
+  test "row and column counts of encoded data":
+    let encoder = newEncoder(columnCount = 8)
+
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 12))
+
+    check getRowCount(encoded) == 3
+    check getColumnCount(encoded) == 8
+    check getColumnCount(encoded) == getShareCount(encoded)
+
+  test "getChunk and getColumn expose the extended matrix":
+    let columnCount = 4
+    let encoder = newEncoder(columnCount = columnCount)
+
+    let data = createTestData(CHUNK_SIZE * 6)
+    let encoded = encode(encoder, data)
+
+    let rowCount = getRowCount(encoded)
+    let chunksPerRow = columnCount div 2
+    for column in 0 ..< getColumnCount(encoded):
+      let columnBytes = getColumn(encoded, column)
+      var offset = 0
+      for row in 0 ..< rowCount:
+        let chunk = getChunk(encoded, row, column)
+        check columnBytes[offset ..< offset + chunk.len] == chunk
+        offset += chunk.len
+        if column < chunksPerRow:
+          # Original columns carry the payload chunks unchanged
+          let start = (row * chunksPerRow + column) * CHUNK_SIZE
+          check chunk[0 ..< CHUNK_SIZE] == data[start ..< start + CHUNK_SIZE]
+      check offset == columnBytes.len
+
+  test "getRowCommitment matches share commitments":
+    let encoder = newEncoder(columnCount = 4)
+
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 6))
+
+    let share = getShare(encoded, index = 0)
+
+    let commitments = getCommitments(share)
+
+    let commitmentBytes = commitmentsToBytes(commitments)
+    for row in 0 ..< getRowCount(encoded):
+      let commitment = getRowCommitment(encoded, row)
+      check @commitment == commitmentBytes[row * COMMITMENT_SIZE ..< (row + 1) * COMMITMENT_SIZE]
+
+  test "getColumnProof returns a distinct proof per column":
+    let encoder = newEncoder(columnCount = 4)
+
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+
+    var proofs: seq[Proof]
+    for column in 0 ..< getColumnCount(encoded):
+      let proof = getColumnProof(encoded, column)
+      check proof != default(Proof)
+      check proof notin proofs
+      proofs.add(proof)
+
+  test "borrowed views match the copying accessors":
+    let encoder = newEncoder(columnCount = 4)
+    let data = createTestData(CHUNK_SIZE * 6)
+    let encoded = encode(encoder, data)
+
+    let view = dataView(encoded)
+    check view.len == data.len
+    check @(view.toOpenArray) == data
+    for column in 0 ..< getColumnCount(encoded):
+      check toBytes(columnView(encoded, column)) == getColumn(encoded, column)
+      check columnView(encoded, column).data == columnView(encoded, column).data
+      check toBytes(proofView(encoded, column)) == @(getColumnProof(encoded, column))
+
+  test "borrowed views fail with out of range columns":
+    let encoder = newEncoder(columnCount = 4)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+    expect IndexOutOfRangeError:
+      discard columnView(encoded, 4)
+    expect IndexOutOfRangeError:
+      discard proofView(encoded, 4)
+    expect NullHandleError:
+      discard dataView(EncodedDataHandle())
+
+  test "encoded data accessors fail with out of range indices":
+    let encoder = newEncoder(columnCount = 4)
+
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 2))
+
+    let rowCount = getRowCount(encoded)
+    let columnCount = getColumnCount(encoded)
+    expect ValueError:
+      discard getColumn(encoded, columnCount)
+    expect ValueError:
+      discard getChunk(encoded, rowCount, 0)
+    expect ValueError:
+      discard getChunk(encoded, 0, columnCount)
+    expect ValueError:
+      discard getRowCommitment(encoded, rowCount)
+    expect ValueError:
+      discard getColumnProof(encoded, columnCount)
+
+  test "encoded data accessors with null handle":
+    let encoded = EncodedDataHandle()
+    check getRowCount(encoded) == 0
+    check getColumnCount(encoded) == 0
+    expect ValueError:
+      discard getColumn(encoded, 0)
+    expect ValueError:
+      discard getRowCommitment(encoded, 0)
+
+  test "encoder built from exported parameters encodes identically":
+    let encoder = newEncoder(columnCount = 4)
+    let params = exportParams(encoder)
+    check params.len > 0
+
+    let reloaded = newEncoder(columnCount = 4, params = params)
+    check exportParams(reloaded) == params
+
+    let path = getTempDir() / "nomos-da-test-params.bin"
+    writeFile(path, cast[string](params))
+    defer:
+      removeFile(path)
+    let fromFile = newEncoderFromParamsFile(columnCount = 4, path = path)
+
+    let data = createTestData(CHUNK_SIZE * 4)
+    let expected = blobId(encode(encoder, data))
+    check blobId(encode(reloaded, data)) == expected
+    check blobId(encode(fromFile, data)) == expected
+
+  test "encoder creation fails with invalid parameters":
+    expect DeserializationError:
+      discard newEncoder(columnCount = 4, params = @[byte(1), 2, 3])
+    expect BadLengthError:
+      discard newEncoder(columnCount = 4, params = newSeq[byte]())
+    expect NomosIoError:
+      discard newEncoderFromParamsFile(columnCount = 4, path = "/nonexistent/params.bin")
+
+  test "encoder with precomputed cache encodes identically":
+    let data = createTestData(CHUNK_SIZE * 8)
+    let plain = newEncoder(columnCount = 8)
+    check not hasCache(plain)
+    let expected = blobId(encode(plain, data))
+
+    let cached = newEncoder(columnCount = 8, withCache = true, threadCount = 2)
+    check hasCache(cached)
+    check getThreadCount(cached) == 2
+    check blobId(encode(cached, data)) == expected
+
+    let uncached = newEncoder(columnCount = 8, withCache = false)
+    check not hasCache(uncached)
+    check blobId(encode(uncached, data)) == expected
+
+  test "encoder with options fails with invalid arguments":
+    expect ValueError:
+      discard newEncoder(columnCount = 0, withCache = true)
+    expect ValueError:
+      discard newEncoder(columnCount = 4, withCache = true, threadCount = -1)
+
+  test "streaming session encodes like one-shot encoding":
+    let encoder = newEncoder(columnCount = 8)
+    let data = createTestData(CHUNK_SIZE * 12)
+    let expected = encode(encoder, data)
+
+    for expectedLen in [0, data.len]:
+      let session = beginEncoding(encoder, expectedLen)
+      var offset = 0
+      while offset < data.len:
+        # Pieces of 67 bytes split chunks across calls
+        let last = min(offset + 67, data.len)
+        session.push(data.toOpenArray(offset, last - 1))
+        offset = last
+      check session.received == data.len
+
+      let streamed = session.finish()
+      check blobId(streamed) == blobId(expected)
+      check getData(streamed) == getData(expected)
+      expect InvalidInputError:
+        discard session.finish()
+
+  test "streaming session rejects misaligned data":
+    let encoder = newEncoder(columnCount = 4)
+    let data = createTestData(CHUNK_SIZE * 2)
+
+    expect BadLengthError:
+      discard beginEncoding(encoder, expectedLen = CHUNK_SIZE + 1)
+
+    let partial = beginEncoding(encoder)
+    partial.push(data.toOpenArray(0, CHUNK_SIZE + 2))
+    expect BadLengthError:
+      discard partial.finish()
+
+    let bounded = beginEncoding(encoder, expectedLen = CHUNK_SIZE)
+    expect BadLengthError:
+      bounded.push(data)
+    check bounded.received == 0
+
+    expect BadLengthError:
+      discard beginEncoding(encoder).finish()
+
+  test "streaming session rejects an overrunning push":
+    let encoder = newEncoder(columnCount = 4)
+    let data = createTestData(CHUNK_SIZE * 2)
+    let session = beginEncoding(encoder, expectedLen = data.len)
+
+    session.push(data.toOpenArray(0, CHUNK_SIZE + 4))
+    expect BadLengthError:
+      session.push(data.toOpenArray(0, CHUNK_SIZE - 1))
+    check session.received == CHUNK_SIZE + 5
+
+    session.push(data.toOpenArray(CHUNK_SIZE + 5, data.len - 1))
+    expect BadLengthError:
+      session.push(data.toOpenArray(0, 0))
+    check session.received == data.len
+    check getData(session.finish()) == getData(encode(encoder, data))
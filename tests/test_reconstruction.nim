@@ -18,20 +18,13 @@ suite "nomos-da Reconstruction API Tests":
 
   test "reconstruct data from all shares":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let originalData = createTestData(CHUNK_SIZE * 4)
     let encoded = encode(encoder, originalData)
-    defer:
-      freeEncodedData(encoded)
     let shareCount = int(getShareCount(encoded) / 2)
     var shares = newSeq[ShareHandle](shareCount)
     for i in 0 ..< shareCount:
       shares[i] = getShare(encoded, index = i)
-    defer:
-      for share in shares:
-        freeShare(share)
 
     let reconstructedData = reconstruct(shares)
     check reconstructedData == originalData
@@ -39,43 +32,29 @@ suite "nomos-da Reconstruction API Tests":
   test "reconstruct data with different column counts":
     for columnCount in [2, 4, 8]:
       let encoder = newEncoder(columnCount = columnCount)
-      defer:
-        freeEncoder(encoder)
 
       let originalData = createTestData(CHUNK_SIZE * columnCount)
       let encoded = encode(encoder, originalData)
-      defer:
-        freeEncodedData(encoded)
 
       let shareCount = int(getShareCount(encoded) / 2)
       var shares = newSeq[ShareHandle](shareCount)
       for i in 0 ..< shareCount:
         shares[i] = getShare(encoded, index = i)
-      defer:
-        for share in shares:
-          freeShare(share)
 
       let reconstructedData = reconstruct(shares)
       check reconstructedData == originalData
 
   test "reconstruct data with various data sizes":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     for dataSize in [CHUNK_SIZE * 2, CHUNK_SIZE * 4, CHUNK_SIZE * 10]:
       let originalData = createTestData(dataSize)
       let encoded = encode(encoder, originalData)
-      defer:
-        freeEncodedData(encoded)
 
       let shareCount = int(getShareCount(encoded) / 2)
       var shares = newSeq[ShareHandle](shareCount)
       for i in 0 ..< shareCount:
         shares[i] = getShare(encoded, index = i)
-      defer:
-        for share in shares:
-          freeShare(share)
 
       let reconstructedData = reconstruct(shares)
       check reconstructedData == originalData
@@ -87,39 +66,194 @@ suite "nomos-da Reconstruction API Tests":
 
   test "reconstruct fails with null share":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let share = getShare(encoded, index = 0)
-    defer:
-      freeShare(share)
 
-    var shares = @[share, ShareHandle(nil)]
+    var shares = @[share, ShareHandle()]
     expect ValueError:
       discard reconstruct(shares)
 
   test "reconstruct with subset of shares":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let originalData = createTestData(CHUNK_SIZE * 2)
     let encoded = encode(encoder, originalData)
-    defer:
-      freeEncodedData(encoded)
 
     let shareCount = int(getShareCount(encoded) / 2)
     var shares = newSeq[ShareHandle](shareCount)
     for i in 0 ..< shareCount:
       shares[i] = getShare(encoded, index = i)
-    defer:
-      for share in shares:
-        freeShare(share)
 
     let reconstructedData = reconstruct(shares)
     check reconstructedData == originalData
+
+  test "reconstruct with missing original columns":
+    let columnCount = 8
+    let encoder = newEncoder(columnCount = columnCount)
+
+    let originalData = createTestData(CHUNK_SIZE * 8)
+    let encoded = encode(encoder, originalData)
+
+    # Drop the first half: only the Reed-Solomon extension columns remain
+    var shares: seq[ShareHandle]
+    for i in columnCount div 2 ..< columnCount:
+      shares.add(getShare(encoded, index = i))
+
+    check reconstructWithMissingData(shares, columnCount) == originalData
+
+  test "reconstruct with scattered subset of shares":
+    let columnCount = 8
+    let encoder = newEncoder(columnCount = columnCount)
+
+    let originalData = createTestData(CHUNK_SIZE * 12)
+    let encoded = encode(encoder, originalData)
+
+    var shares: seq[ShareHandle]
+    for i in [1, 3, 6, 7]:
+      shares.add(getShare(encoded, index = i))
+
+    let reconstructedData = reconstructWithMissingData(shares, columnCount)
+    check reconstructedData[0 ..< originalData.len] == originalData
+
+  test "reconstruct with missing data fails with too few shares":
+    let columnCount = 8
+    let encoder = newEncoder(columnCount = columnCount)
+
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+
+    # Duplicates do not count towards the required distinct indices
+    var shares: seq[ShareHandle]
+    for i in [0, 2, 5, 5]:
+      shares.add(getShare(encoded, index = i))
+
+    expect ValueError:
+      discard reconstructWithMissingData(shares, columnCount)
+
+  test "encodePadded and reconstructPadded roundtrip arbitrary lengths":
+    let columnCount = 4
+    let encoder = newEncoder(columnCount = columnCount)
+
+    for dataSize in [0, 1, CHUNK_SIZE - 8, CHUNK_SIZE - 1, CHUNK_SIZE, 100, 1000]:
+      let originalData = createTestData(dataSize)
+      let encoded = encodePadded(encoder, originalData)
+
+      check getData(encoded).len mod CHUNK_SIZE == 0
+
+      var shares: seq[ShareHandle]
+      for i in 0 ..< getShareCount(encoded):
+        shares.add(getShare(encoded, index = i))
+
+      check reconstructPadded(shares, columnCount) == originalData
+
+  test "reconstructPadded from extension columns only":
+    let columnCount = 8
+    let encoder = newEncoder(columnCount = columnCount)
+
+    let originalData = createTestData(333)
+    let encoded = encodePadded(encoder, originalData)
+
+    var shares: seq[ShareHandle]
+    for i in columnCount div 2 ..< columnCount:
+      shares.add(getShare(encoded, index = i))
+
+    check reconstructPadded(shares, columnCount) == originalData
+
+  test "reconstructPadded rejects unpadded encodings":
+    let columnCount = 4
+    let encoder = newEncoder(columnCount = columnCount)
+
+    # The first 8 bytes decode to a length far beyond the available data
+    var originalData = createTestData(CHUNK_SIZE * 2)
+    for i in 0 ..< 8:
+      originalData[i] = 0xff
+    let encoded = encode(encoder, originalData)
+
+    var shares: seq[ShareHandle]
+    for i in 0 ..< getShareCount(encoded):
+      shares.add(getShare(encoded, index = i))
+
+    expect ValueError:
+      discard reconstructPadded(shares, columnCount)
+
+  test "encodePadded fails with null encoder":
+    expect ValueError:
+      discard encodePadded(EncoderHandle(), createTestData(10))
+
+  test "encodedDataFromShares rebuilds the encoded data":
+    let columnCount = 8
+    let encoder = newEncoder(columnCount = columnCount)
+    let originalData = createTestData(CHUNK_SIZE * 8)
+    let encoded = encode(encoder, originalData)
+
+    var shares: seq[ShareHandle]
+    for i in countdown(columnCount - 1, 0):
+      shares.add(getShare(encoded, index = i))
+
+    let rebuilt = encodedDataFromShares(shares, columnCount)
+    check getData(rebuilt) == originalData
+    check rebuilt.blobId == encoded.blobId
+    check getShareCount(rebuilt) == columnCount
+    for i in 0 ..< columnCount:
+      check getColumn(rebuilt, i) == getColumn(encoded, i)
+      check getColumnProof(rebuilt, i) == getColumnProof(encoded, i)
+      check shareToBytes(getShare(rebuilt, i)) == shareToBytes(getShare(encoded, i))
+
+  test "encodedDataFromShares rejects incomplete or repeated shares":
+    let columnCount = 4
+    let encoder = newEncoder(columnCount = columnCount)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+
+    expect InsufficientSharesError:
+      discard encodedDataFromShares(
+        @[getShare(encoded, 0), getShare(encoded, 1), getShare(encoded, 2)], columnCount
+      )
+    expect InvalidInputError:
+      discard encodedDataFromShares(
+        @[
+          getShare(encoded, 0), getShare(encoded, 1), getShare(encoded, 1),
+          getShare(encoded, 3),
+        ],
+        columnCount,
+      )
+
+  test "reconstructAndVerify accepts honest shares":
+    let columnCount = 8
+    let encoder = newEncoder(columnCount = columnCount)
+    let originalData = createTestData(CHUNK_SIZE * 8)
+    let encoded = encode(encoder, originalData)
+
+    var shares: seq[ShareHandle]
+    for i in [0, 3, 5, 6]:
+      shares.add(getShare(encoded, index = i))
+
+    check reconstructAndVerify(encoder, shares, columnCount) == originalData
+
+  test "reconstructAndVerify rejects a column count the encoder was not built with":
+    let encoder = newEncoder(columnCount = 8)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 8))
+
+    var shares: seq[ShareHandle]
+    for i in [0, 3, 5, 6]:
+      shares.add(getShare(encoded, index = i))
+
+    expect InvalidInputError:
+      discard reconstructAndVerify(encoder, shares, columnCount = 16)
+
+  test "reconstructAndVerify fails with too few shares":
+    let columnCount = 8
+    let encoder = newEncoder(columnCount = columnCount)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 8))
+
+    expect InsufficientSharesError:
+      discard reconstructAndVerify(
+        encoder, @[getShare(encoded, 0), getShare(encoded, 1)], columnCount
+      )
+    expect BadLengthError:
+      discard reconstructAndVerify(encoder, newSeq[ShareHandle](), columnCount)
+    expect NullHandleError:
+      discard reconstructAndVerify(
+        EncoderHandle(), @[getShare(encoded, 0), getShare(encoded, 1)], columnCount
+      )
@@ -0,0 +1,111 @@
+import unittest
+import algorithm
+import ../src/kzg_nomos_da
+import ../src/kzg_nomos_da/types
+
+proc testBlobId(fill: byte): BlobId =
+  for i in 0 ..< result.len:
+    result[i] = fill
+
+suite "nomos-da Sampling Tests":
+  setup:
+    discard nomos_da_init()
+  teardown:
+    nomos_da_cleanup()
+
+  test "sample selection is deterministic":
+    let seed = @[byte 1, 2, 3, 4]
+    let plan = selectSampleColumns(testBlobId(7), seed, columnCount = 64, sampleCount = 16)
+    check plan == selectSampleColumns(testBlobId(7), seed, columnCount = 64, sampleCount = 16)
+    check plan.columns.len == 16
+
+  test "sample selection matches the pinned vectors":
+    var countingId: BlobId
+    for i in 0 ..< countingId.len:
+      countingId[i] = byte(i)
+    check selectSampleColumns(testBlobId(0), newSeq[byte](), columnCount = 16, sampleCount = 4).columns ==
+      @[0, 4, 7, 14]
+    check selectSampleColumns(
+      countingId, "node-1".toOpenArrayByte(0, 5), columnCount = 64, sampleCount = 8
+    ).columns == @[5, 7, 8, 11, 30, 40, 53, 58]
+
+  test "sampled columns are distinct, sorted and in range":
+    let plan = selectSampleColumns(testBlobId(1), @[byte 9], columnCount = 32, sampleCount = 20)
+    check plan.columns == plan.columns.sorted()
+    for i in 1 ..< plan.columns.len:
+      check plan.columns[i - 1] < plan.columns[i]
+    for column in plan.columns:
+      check column >= 0 and column < 32
+
+  test "different seeds and blobs sample different columns":
+    let plan = selectSampleColumns(testBlobId(1), @[byte 1], columnCount = 64, sampleCount = 8)
+    check plan.columns !=
+      selectSampleColumns(testBlobId(1), @[byte 2], columnCount = 64, sampleCount = 8).columns
+    check plan.columns !=
+      selectSampleColumns(testBlobId(2), @[byte 1], columnCount = 64, sampleCount = 8).columns
+
+  test "confidence grows with the sample count":
+    check samplingConfidence(columnCount = 4, sampleCount = 0) == 0.0
+    check abs(samplingConfidence(columnCount = 4, sampleCount = 1) - 0.75) < 1e-12
+    check samplingConfidence(columnCount = 4, sampleCount = 2) == 1.0
+    var previous = 0.0
+    for sampleCount in 1 .. 20:
+      let confidence = samplingConfidence(columnCount = 256, sampleCount = sampleCount)
+      check confidence > previous
+      previous = confidence
+
+  test "plan confidence matches samplingConfidence":
+    let plan = selectSampleColumns(testBlobId(3), @[], columnCount = 128, sampleCount = 12)
+    check plan.confidence == samplingConfidence(columnCount = 128, sampleCount = 12)
+
+  test "invalid sampling parameters raise":
+    expect BadLengthError:
+      discard selectSampleColumns(testBlobId(0), @[], columnCount = 4, sampleCount = 5)
+    expect DomainSizeMismatchError:
+      discard selectSampleColumns(testBlobId(0), @[], columnCount = 3, sampleCount = 1)
+    expect DomainSizeMismatchError:
+      discard samplingConfidence(columnCount = 0, sampleCount = 1)
+
+proc simulationConfig(
+    adversarialNodes: int, behavior: AdversaryBehavior
+): SimulationConfig =
+  SimulationConfig(
+    columnCount: 8,
+    payloadSize: csize_t(CHUNK_SIZE * 8),
+    nodeCount: 6,
+    columnsPerNode: 3,
+    adversarialNodes: csize_t(adversarialNodes),
+    behavior: behavior,
+    lightClients: 20,
+    samplesPerClient: 3,
+    seed: 42,
+  )
+
+suite "nomos-da Simulation Tests":
+  setup:
+    discard nomos_da_init()
+  teardown:
+    nomos_da_cleanup()
+
+  test "honest network serves and reconstructs the blob":
+    var config = simulationConfig(0, Withhold)
+    config.columnsPerNode = 8
+    let report = simulate(config)
+    check report.availableColumns == 8
+    check report.detectingClients == 0
+    check report.reconstructed
+
+  test "fully adversarial network is always detected":
+    for behavior in [Withhold, Corrupt]:
+      let report = simulate(simulationConfig(6, behavior))
+      check report.availableColumns == 0
+      check report.detectionRate == 1.0
+      check not report.reconstructed
+
+  test "simulation is deterministic for a seed":
+    let config = simulationConfig(3, Corrupt)
+    check simulate(config) == simulate(config)
+
+  test "invalid simulation config raises":
+    expect InvalidInputError:
+      discard simulate(simulationConfig(7, Withhold))
@@ -85,61 +85,69 @@ suite "nomos-da Serialization Tests":
 
   test "shareToBytes and bytesToShare roundtrip":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let share = getShare(encoded, index = 0)
-    defer:
-      freeShare(share)
 
     let originalIndex = getShareIndex(share)
     let serialized = shareToBytes(share)
     check serialized.len > 0
 
     let deserialized = bytesToShare(serialized)
-    check deserialized.index == uint16(originalIndex)
+    check getShareIndex(deserialized) == originalIndex
+    check shareToBytes(deserialized) == serialized
 
   test "shareToBytes with multiple shares":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE * 2)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let shareCount = getShareCount(encoded)
     for i in 0 ..< shareCount:
       let share = getShare(encoded, index = i)
-      defer:
-        freeShare(share)
 
       let originalIndex = getShareIndex(share)
       let serialized = shareToBytes(share)
       let deserialized = bytesToShare(serialized)
-      check deserialized.index == uint16(originalIndex)
-      check deserialized.index == uint16(i)
+      check getShareIndex(deserialized) == originalIndex
+      check getShareIndex(deserialized) == i
 
   test "shareToBytes fails with null handle":
-    let nullShare = ShareHandle(nil)
+    let nullShare = ShareHandle()
     expect ValueError:
       discard shareToBytes(nullShare)
 
+  test "bytesToShare fails with empty data":
+    let emptyData: seq[byte] = @[]
+    expect ValueError:
+      discard bytesToShare(emptyData)
+
+  test "bytesToShare fails with malformed data":
+    let malformedData = @[byte(0xff), 0xff, 0xff, 0xff, 0xff]
+    expect ValueError:
+      discard bytesToShare(malformedData)
+
+  test "deserialized share reconstructs original data":
+    let encoder = newEncoder(columnCount = 4)
+
+    let originalData = createTestData(CHUNK_SIZE * 4)
+    let encoded = encode(encoder, originalData)
+
+    var shares = newSeq[ShareHandle](2)
+    for i in 0 ..< 2:
+      let share = getShare(encoded, index = i)
+      shares[i] = bytesToShare(shareToBytes(share))
+
+    check reconstruct(shares) == originalData
+
   test "encodedDataToBytes and bytesToEncodedData roundtrip":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let originalData = createTestData(CHUNK_SIZE * 2)
     let encoded = encode(encoder, originalData)
-    defer:
-      freeEncodedData(encoded)
 
     let originalShareCount = getShareCount(encoded)
     let serialized = encodedDataToBytes(encoded)
@@ -151,14 +159,10 @@ suite "nomos-da Serialization Tests":
 
   test "encodedDataToBytes with various data sizes":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     for size in [CHUNK_SIZE, CHUNK_SIZE * 2, CHUNK_SIZE * 5]:
       let originalData = createTestData(size)
       let encoded = encode(encoder, originalData)
-      defer:
-        freeEncodedData(encoded)
 
       let originalShareCount = getShareCount(encoded)
       let serialized = encodedDataToBytes(encoded)
@@ -170,13 +174,9 @@ suite "nomos-da Serialization Tests":
   test "encodedDataToBytes with different column counts":
     for columnCount in [2, 4, 8]:
       let encoder = newEncoder(columnCount = columnCount)
-      defer:
-        freeEncoder(encoder)
 
       let originalData = createTestData(CHUNK_SIZE)
       let encoded = encode(encoder, originalData)
-      defer:
-        freeEncodedData(encoded)
 
       let originalShareCount = getShareCount(encoded)
       let serialized = encodedDataToBytes(encoded)
@@ -186,7 +186,7 @@ suite "nomos-da Serialization Tests":
       check deserialized.shareCount == uint32(originalShareCount)
 
   test "encodedDataToBytes fails with null handle":
-    let nullEncoded = EncodedDataHandle(nil)
+    let nullEncoded = EncodedDataHandle()
     expect ValueError:
       discard encodedDataToBytes(nullEncoded)
 
@@ -202,13 +202,9 @@ suite "nomos-da Serialization Tests":
 
   test "complete workflow: encode -> serialize -> deserialize -> verify":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let originalData = createTestData(CHUNK_SIZE * 3)
     let encoded = encode(encoder, originalData)
-    defer:
-      freeEncodedData(encoded)
 
     # Serialize encoded data
     let serialized = encodedDataToBytes(encoded)
@@ -221,13 +217,9 @@ suite "nomos-da Serialization Tests":
 
   test "serialize multiple shares and verify indices":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE * 2)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let shareCount = getShareCount(encoded)
     var serializedShares = newSeq[seq[byte]](shareCount)
@@ -235,14 +227,14 @@ suite "nomos-da Serialization Tests":
     # Serialize all shares
     for i in 0 ..< shareCount:
       let share = getShare(encoded, index = i)
-      defer:
-        freeShare(share)
       serializedShares[i] = shareToBytes(share)
 
     # Deserialize and verify
+    let verifier = newVerifier()
     for i in 0 ..< shareCount:
       let deserialized = bytesToShare(serializedShares[i])
-      check deserialized.index == uint16(i)
+      check getShareIndex(deserialized) == i
+      check verify(verifier, deserialized, rowsDomainSize = 4)
 
   test "serializeData preserves data integrity":
     let testCases = @[
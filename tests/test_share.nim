@@ -15,148 +15,130 @@ suite "nomos-da Share API Tests":
 
   test "getShare from encoded data":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let share = getShare(encoded, index = 0)
-    defer:
-      freeShare(share)
 
-    check share.pointer != nil
+    check share.raw != nil
 
   test "getShare from all shares":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE * 2)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let shareCount = getShareCount(encoded)
     for i in 0 ..< shareCount:
       let share = getShare(encoded, index = i)
-      defer:
-        freeShare(share)
 
-      check share.pointer != nil
+      check share.raw != nil
       check getShareIndex(share) == i
 
   test "getShare fails with invalid index":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let shareCount = getShareCount(encoded)
-    expect ValueError:
+    expect IndexOutOfRangeError:
       discard getShare(encoded, index = shareCount)
 
-    expect ValueError:
+    expect IndexOutOfRangeError:
       discard getShare(encoded, index = -1)
 
   test "getShare fails with null encoded data":
-    let encoded = EncodedDataHandle(nil)
-    expect ValueError:
+    let encoded = EncodedDataHandle()
+    expect NullHandleError:
       discard getShare(encoded, index = 0)
 
+  test "errors carry the FFI result code":
+    let encoder = newEncoder(columnCount = 4)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE))
+    try:
+      discard getShare(encoded, index = getShareCount(encoded))
+      fail()
+    except NomosDaError as e:
+      check e.code == ErrorIndexOutOfRange
+      check e of IndexOutOfRangeError
+      check e.msg.len > 0
+
   test "getShareIndex returns correct index":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     for i in 0 ..< 4:
       let share = getShare(encoded, index = i)
-      defer:
-        freeShare(share)
 
       check getShareIndex(share) == i
 
   test "getShareIndex returns 0 for null handle":
-    let share = ShareHandle(nil)
+    let share = ShareHandle()
     check getShareIndex(share) == 0
 
   test "freeShare is safe with null handle":
-    let share = ShareHandle(nil)
+    var share = ShareHandle()
+    freeShare(share)
     freeShare(share)
+    check share.isNil
+
+  test "copied share outlives the original":
+    let encoder = newEncoder(columnCount = 4)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE))
+
+    var original = getShare(encoded, index = 1)
+    let copy = original
+    check copy.raw == original.raw
+    check shareToBytes(copy) == shareToBytes(original)
+
+    freeShare(original)
+    check original.isNil
+    check getShareIndex(copy) == 1
 
   test "getShare with different column counts":
     for columnCount in [2, 4, 8, 16]:
       let encoder = newEncoder(columnCount = columnCount)
-      defer:
-        freeEncoder(encoder)
 
       let data = createTestData(CHUNK_SIZE)
       let encoded = encode(encoder, data)
-      defer:
-        freeEncodedData(encoded)
 
       for i in 0 ..< columnCount:
         let share = getShare(encoded, index = i)
-        defer:
-          freeShare(share)
 
-        check share.pointer != nil
+        check share.raw != nil
         check getShareIndex(share) == i
 
   test "getShare with various data sizes":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     for dataSize in [CHUNK_SIZE, CHUNK_SIZE * 2, CHUNK_SIZE * 4]:
       let data = createTestData(dataSize)
       let encoded = encode(encoder, data)
-      defer:
-        freeEncodedData(encoded)
 
       let share = getShare(encoded, index = 0)
-      defer:
-        freeShare(share)
 
-      check share.pointer != nil
+      check share.raw != nil
       check getShareIndex(share) == 0
 
   test "multiple shares can coexist":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let share1 = getShare(encoded, index = 0)
-    defer:
-      freeShare(share1)
 
     let share2 = getShare(encoded, index = 1)
-    defer:
-      freeShare(share2)
 
     let share3 = getShare(encoded, index = 2)
-    defer:
-      freeShare(share3)
 
-    check share1.pointer != nil
-    check share2.pointer != nil
-    check share3.pointer != nil
+    check share1.raw != nil
+    check share2.raw != nil
+    check share3.raw != nil
 
     check getShareIndex(share1) == 0
     check getShareIndex(share2) == 1
@@ -164,123 +146,183 @@ suite "nomos-da Share API Tests":
 
   test "getCommitments from share":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let share = getShare(encoded, index = 0)
-    defer:
-      freeShare(share)
 
     let commitments = getCommitments(share)
-    defer:
-      freeCommitments(commitments)
 
-    check commitments.pointer != nil
+    check commitments.raw != nil
 
   test "getCommitments from all shares":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE * 2)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let shareCount = getShareCount(encoded)
     for i in 0 ..< shareCount:
       let share = getShare(encoded, index = i)
-      defer:
-        freeShare(share)
 
       let commitments = getCommitments(share)
-      defer:
-        freeCommitments(commitments)
 
-      check commitments.pointer != nil
+      check commitments.raw != nil
       check getShareIndex(share) == i
 
   test "getCommitments fails with null share":
-    let share = ShareHandle(nil)
+    let share = ShareHandle()
     expect ValueError:
       discard getCommitments(share)
 
   test "getCommitments with different column counts":
     for columnCount in [2, 4, 8, 16]:
       let encoder = newEncoder(columnCount = columnCount)
-      defer:
-        freeEncoder(encoder)
 
       let data = createTestData(CHUNK_SIZE)
       let encoded = encode(encoder, data)
-      defer:
-        freeEncodedData(encoded)
 
       let share = getShare(encoded, index = 0)
-      defer:
-        freeShare(share)
 
       let commitments = getCommitments(share)
-      defer:
-        freeCommitments(commitments)
 
-      check commitments.pointer != nil
+      check commitments.raw != nil
 
   test "getCommitments with various data sizes":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     for dataSize in [CHUNK_SIZE, CHUNK_SIZE * 2, CHUNK_SIZE * 4]:
       let data = createTestData(dataSize)
       let encoded = encode(encoder, data)
-      defer:
-        freeEncodedData(encoded)
 
       let share = getShare(encoded, index = 0)
-      defer:
-        freeShare(share)
 
       let commitments = getCommitments(share)
-      defer:
-        freeCommitments(commitments)
 
-      check commitments.pointer != nil
+      check commitments.raw != nil
 
   test "freeCommitments is safe with null handle":
-    let commitments = CommitmentsHandle(nil)
+    var commitments = CommitmentsHandle()
     freeCommitments(commitments)
+    check commitments.isNil
 
   test "multiple commitments can coexist":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let share1 = getShare(encoded, index = 0)
-    defer:
-      freeShare(share1)
 
     let share2 = getShare(encoded, index = 1)
-    defer:
-      freeShare(share2)
 
     let commitments1 = getCommitments(share1)
-    defer:
-      freeCommitments(commitments1)
 
     let commitments2 = getCommitments(share2)
-    defer:
-      freeCommitments(commitments2)
 
-    check commitments1.pointer != nil
-    check commitments2.pointer != nil
+    check commitments1.raw != nil
+    check commitments2.raw != nil
+
+  test "commitments row count matches encoded rows":
+    let encoder = newEncoder(columnCount = 4)
+
+    for rows in [1, 2, 5]:
+      let encoded = encode(encoder, createTestData(CHUNK_SIZE * 2 * rows))
+
+      let share = getShare(encoded, index = 0)
+
+      let commitments = getCommitments(share)
+
+      check getRowCount(commitments) == rows
+
+  test "commitmentsToBytes and bytesToCommitments roundtrip":
+    let encoder = newEncoder(columnCount = 4)
+
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 6))
+
+    let share = getShare(encoded, index = 1)
+
+    let commitments = getCommitments(share)
+
+    let serialized = commitmentsToBytes(commitments)
+    check serialized.len == getRowCount(commitments) * COMMITMENT_SIZE
+
+    let restored = bytesToCommitments(serialized)
+
+    check getRowCount(restored) == getRowCount(commitments)
+    check commitmentsEqual(restored, commitments)
+    check commitmentsToBytes(restored) == serialized
+
+  test "commitments from different blobs are not equal":
+    let encoder = newEncoder(columnCount = 4)
+
+    let encoded1 = encode(encoder, createTestData(CHUNK_SIZE * 2))
+
+    var otherData = createTestData(CHUNK_SIZE * 2)
+    otherData[0] = otherData[0] xor 0xff
+    let encoded2 = encode(encoder, otherData)
+
+    let share1 = getShare(encoded1, index = 0)
+
+    let share2 = getShare(encoded2, index = 0)
+
+    let commitments1 = getCommitments(share1)
+
+    let commitments2 = getCommitments(share2)
+
+    check not commitmentsEqual(commitments1, commitments2)
+
+  test "bytesToCommitments fails with invalid length":
+    expect BadLengthError:
+      discard bytesToCommitments(newSeq[byte](COMMITMENT_SIZE - 1))
+
+    let emptyData: seq[byte] = @[]
+    expect BadLengthError:
+      discard bytesToCommitments(emptyData)
+
+  test "bytesToCommitments fails with invalid point":
+    var invalidPoint = newSeq[byte](COMMITMENT_SIZE)
+    for i in 0 ..< invalidPoint.len:
+      invalidPoint[i] = 0xff
+    expect DeserializationError:
+      discard bytesToCommitments(invalidPoint)
+
+  test "getRowCount returns 0 for null handle":
+    check getRowCount(CommitmentsHandle()) == 0
+
+  test "blobId is consistent across encoded data, shares and commitments":
+    let encoder = newEncoder(columnCount = 4)
+
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+
+    let expected = blobId(encoded)
+    check expected != default(BlobId)
+
+    for i in 0 ..< getShareCount(encoded):
+      let share = getShare(encoded, index = i)
+
+      let commitments = getCommitments(share)
+
+      check blobId(share) == expected
+      check blobId(commitments) == expected
+
+      let restored = bytesToCommitments(commitmentsToBytes(commitments))
+      check blobId(restored) == expected
+
+  test "blobId differs between blobs":
+    let encoder = newEncoder(columnCount = 4)
+
+    let encoded1 = encode(encoder, createTestData(CHUNK_SIZE * 2))
+
+    let encoded2 = encode(encoder, createTestData(CHUNK_SIZE * 4))
+
+    check blobId(encoded1) != blobId(encoded2)
+
+  test "blobId fails with null handles":
+    expect ValueError:
+      discard blobId(EncodedDataHandle())
+    expect ValueError:
+      discard blobId(ShareHandle())
+    expect ValueError:
+      discard blobId(CommitmentsHandle())
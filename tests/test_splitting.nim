@@ -0,0 +1,88 @@
+import unittest
+import ../src/kzg_nomos_da
+import ../src/kzg_nomos_da/types
+
+proc createTestData(size: int): seq[byte] =
+  result = newSeq[byte](size)
+  for i in 0 ..< size:
+    result[i] = byte((i + 1) mod 256)
+
+proc reconstructBlob(encoded: EncodedDataHandle): seq[byte] {.raises: [ValueError].} =
+  ## Padded data of a blob rebuilt from its original columns
+  var shares: seq[ShareHandle]
+  for i in 0 ..< getShareCount(encoded) div 2:
+    shares.add(getShare(encoded, index = i))
+  reconstruct(shares)
+
+suite "nomos-da Multi-Blob Splitting Tests":
+  setup:
+    discard nomos_da_init()
+  teardown:
+    nomos_da_cleanup()
+
+  const maxBlobSize = CHUNK_SIZE * 4
+  const capacity = maxBlobSize - 8
+
+  test "payload larger than one blob round-trips":
+    let encoder = newEncoder(columnCount = 4)
+    let payload = createTestData(capacity * 2 + 40)
+
+    let split = encodeSplit(encoder, payload, maxBlobSize)
+    check split.blobs.len == 3
+    check payloadLen(split.manifest) == payload.len
+
+    let entries = entries(split.manifest)
+    check entries.len == 3
+    for i, entry in entries:
+      check entry.blobId == blobId(split.blobs[i])
+      check int(8 + entry.payloadLen + entry.padding) <= maxBlobSize
+    check entries[0].payloadLen == uint64(capacity)
+    check entries[2].payloadLen == 40'u64
+
+    var blobs: seq[seq[byte]]
+    for blob in split.blobs:
+      blobs.add(reconstructBlob(blob))
+    check reassemble(split.manifest, blobs) == payload
+
+  test "manifest survives serialization":
+    let encoder = newEncoder(columnCount = 4)
+    let payload = createTestData(capacity + 50)
+    let split = encodeSplit(encoder, payload, maxBlobSize)
+
+    let decoded = bytesToManifest(manifestToBytes(split.manifest))
+    check entries(decoded) == entries(split.manifest)
+
+    var blobs: seq[seq[byte]]
+    for blob in split.blobs:
+      blobs.add(reconstructBlob(blob))
+    check reassemble(decoded, blobs) == payload
+
+  test "payload within the limit takes one blob":
+    let encoder = newEncoder(columnCount = 4)
+    let payload = createTestData(60)
+    let split = encodeSplit(encoder, payload, maxBlobSize)
+    check split.blobs.len == 1
+    check reassemble(split.manifest, @[reconstructBlob(split.blobs[0])]) == payload
+
+  test "reassemble rejects missing or misplaced blobs":
+    let encoder = newEncoder(columnCount = 4)
+    let payload = createTestData(capacity * 2 + 40)
+    let split = encodeSplit(encoder, payload, maxBlobSize)
+    var blobs: seq[seq[byte]]
+    for blob in split.blobs:
+      blobs.add(reconstructBlob(blob))
+
+    expect BadLengthError:
+      discard reassemble(split.manifest, blobs[0 .. 1])
+    expect InvalidInputError:
+      discard reassemble(split.manifest, @[blobs[2], blobs[1], blobs[0]])
+
+  test "invalid blob size limits are rejected":
+    let encoder = newEncoder(columnCount = 4)
+    let payload = createTestData(100)
+    expect InvalidInputError:
+      discard encodeSplit(encoder, payload, CHUNK_SIZE + 1)
+    expect InvalidInputError:
+      discard encodeSplit(encoder, payload, 0)
+    expect DeserializationError:
+      discard bytesToManifest(@[0xff'u8, 0xff, 0xff])
@@ -0,0 +1,90 @@
+import unittest
+import os
+import ../src/kzg_nomos_da
+import ../src/kzg_nomos_da/types
+
+proc createTestData(size: int): seq[byte] =
+  result = newSeq[byte](size)
+  for i in 0 ..< size:
+    result[i] = byte((i + 1) mod 256)
+
+proc freshStoreDir(name: string): string =
+  result = getTempDir() / ("nomos-da-test-store-" & name)
+  try:
+    removeDir(result)
+  except OSError:
+    discard
+
+suite "nomos-da Share Store Tests":
+  setup:
+    discard nomos_da_init()
+  teardown:
+    nomos_da_cleanup()
+
+  test "stored shares can be loaded back":
+    let dir = freshStoreDir("roundtrip")
+    let store = openShareStore(dir)
+    let encoder = newEncoder(columnCount = 4)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+    let share = getShare(encoded, index = 2)
+    store.put(share)
+
+    let loaded = store.get(encoded.blobId, 2)
+    check getShareIndex(loaded) == 2
+    check shareToBytes(loaded) == shareToBytes(share)
+    check commitmentsEqual(getCommitments(loaded), getCommitments(share))
+    removeDir(dir)
+
+  test "columns lists every stored column in order":
+    let dir = freshStoreDir("columns")
+    let store = openShareStore(dir)
+    let encoder = newEncoder(columnCount = 4)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+    check store.columns(encoded.blobId).len == 0
+    for index in [3, 0, 1]:
+      store.put(getShare(encoded, index))
+    check store.columns(encoded.blobId) == @[0, 1, 3]
+    removeDir(dir)
+
+  test "shares survive reopening the store":
+    let dir = freshStoreDir("reopen")
+    let encoder = newEncoder(columnCount = 4)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+    block:
+      let store = openShareStore(dir)
+      store.put(getShare(encoded, index = 1))
+    let reopened = openShareStore(dir)
+    check reopened.columns(encoded.blobId) == @[1]
+    check getShareIndex(reopened.get(encoded.blobId, 1)) == 1
+    removeDir(dir)
+
+  test "missing shares raise NotFoundError":
+    let dir = freshStoreDir("missing")
+    let store = openShareStore(dir)
+    var blobId: BlobId
+    expect NotFoundError:
+      discard store.get(blobId, 0)
+    removeDir(dir)
+
+  test "prune removes shares older than the retention window":
+    let dir = freshStoreDir("prune")
+    let store = openShareStore(dir)
+    let encoder = newEncoder(columnCount = 4)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+    store.put(getShare(encoded, index = 0))
+    store.put(getShare(encoded, index = 1))
+    check store.prune(maxAgeSeconds = 3600) == 0
+    check store.columns(encoded.blobId) == @[0, 1]
+    check store.prune(maxAgeSeconds = 0) == 2
+    check store.columns(encoded.blobId).len == 0
+    removeDir(dir)
+
+  test "store operations fail with null handles":
+    var store: ShareStoreHandle
+    var blobId: BlobId
+    expect NullHandleError:
+      store.put(ShareHandle())
+    expect NullHandleError:
+      discard store.columns(blobId)
+    expect NullHandleError:
+      discard store.prune(maxAgeSeconds = 0)
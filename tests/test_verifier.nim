@@ -1,12 +1,28 @@
 import unittest
+import os
 import ../src/kzg_nomos_da
 import ../src/kzg_nomos_da/types
 
+when NimMajor >= 2:
+  import std/typedthreads
+
 proc createTestData(size: int): seq[byte] =
   result = newSeq[byte](size)
   for i in 0 ..< size:
     result[i] = byte((i + 1) mod 256)
 
+type VerifyJob = object
+  verifier: VerifierHandle
+  share: ShareHandle
+  rowsDomainSize: int
+  valid: ptr bool
+
+proc verifyOnThread(job: VerifyJob) {.thread.} =
+  try:
+    job.valid[] = verify(job.verifier, job.share, job.rowsDomainSize)
+  except ValueError:
+    job.valid[] = false
+
 suite "nomos-da Verifier API Tests":
   setup:
     discard nomos_da_init()
@@ -14,52 +30,60 @@ suite "nomos-da Verifier API Tests":
     nomos_da_cleanup()
 
   test "verifier creation and destruction":
-    let verifier = newVerifier()
-    check verifier.pointer != nil
+    var verifier = newVerifier()
+    check verifier.raw != nil
     freeVerifier(verifier)
+    check verifier.isNil
 
   test "verify share":
     let columnCount = 4
     let encoder = newEncoder(columnCount = columnCount)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let share = getShare(encoded, index = 0)
-    defer:
-      freeShare(share)
 
     let verifier = newVerifier()
-    defer:
-      freeVerifier(verifier)
 
     let isValid = verify(verifier, share, rowsDomainSize = columnCount)
     check isValid
 
+  test "copies of one verifier verify concurrently":
+    let columnCount = 4
+    let encoder = newEncoder(columnCount = columnCount)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 2))
+
+    var verifier = newVerifier()
+    var results: array[4, bool]
+    var threads: array[4, Thread[VerifyJob]]
+    for i in 0 ..< columnCount:
+      let job = VerifyJob(
+        verifier: verifier,
+        share: getShare(encoded, index = i),
+        rowsDomainSize: columnCount,
+        valid: addr results[i],
+      )
+      createThread(threads[i], verifyOnThread, job)
+    # The threads keep their copies alive after the original is released
+    freeVerifier(verifier)
+    joinThreads(threads)
+
+    for i in 0 ..< columnCount:
+      check results[i]
+
   test "verify all shares from encoded data":
     let columnCount = 4
     let encoder = newEncoder(columnCount = columnCount)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE * 2)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let verifier = newVerifier()
-    defer:
-      freeVerifier(verifier)
 
     let shareCount = getShareCount(encoded)
     for i in 0 ..< shareCount:
       let share = getShare(encoded, index = i)
-      defer:
-        freeShare(share)
 
       let isValid = verify(verifier, share, rowsDomainSize = columnCount)
       check isValid
@@ -68,21 +92,13 @@ suite "nomos-da Verifier API Tests":
   test "verify with different column counts":
     for columnCount in [2, 4, 8, 16]:
       let encoder = newEncoder(columnCount = columnCount)
-      defer:
-        freeEncoder(encoder)
 
       let data = createTestData(CHUNK_SIZE)
       let encoded = encode(encoder, data)
-      defer:
-        freeEncodedData(encoded)
 
       let share = getShare(encoded, index = 0)
-      defer:
-        freeShare(share)
 
       let verifier = newVerifier()
-      defer:
-        freeVerifier(verifier)
 
       let isValid = verify(verifier, share, rowsDomainSize = columnCount)
       check isValid
@@ -90,70 +106,46 @@ suite "nomos-da Verifier API Tests":
   test "verify share with various data sizes":
     let columnCount = 4
     let encoder = newEncoder(columnCount = columnCount)
-    defer:
-      freeEncoder(encoder)
 
     for dataSize in [CHUNK_SIZE, CHUNK_SIZE * 2, CHUNK_SIZE * 4]:
       let data = createTestData(dataSize)
       let encoded = encode(encoder, data)
-      defer:
-        freeEncodedData(encoded)
 
       let share = getShare(encoded, index = 0)
-      defer:
-        freeShare(share)
 
       let verifier = newVerifier()
-      defer:
-        freeVerifier(verifier)
 
       let isValid = verify(verifier, share, rowsDomainSize = columnCount)
       check isValid
 
   test "verify fails with null verifier":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let share = getShare(encoded, index = 0)
-    defer:
-      freeShare(share)
 
-    let verifier = VerifierHandle(nil)
+    let verifier = VerifierHandle()
     expect ValueError:
       discard verify(verifier, share, rowsDomainSize = 4)
 
   test "verify fails with null share":
     let verifier = newVerifier()
-    defer:
-      freeVerifier(verifier)
 
-    let share = ShareHandle(nil)
+    let share = ShareHandle()
     expect ValueError:
       discard verify(verifier, share, rowsDomainSize = 4)
 
   test "verify fails with invalid rowDomainSize":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let share = getShare(encoded, index = 0)
-    defer:
-      freeShare(share)
 
     let verifier = newVerifier()
-    defer:
-      freeVerifier(verifier)
 
     expect ValueError:
       discard verify(verifier, share, rowsDomainSize = 0)
@@ -163,25 +155,15 @@ suite "nomos-da Verifier API Tests":
 
   test "multiple verifiers can coexist":
     let verifier1 = newVerifier()
-    defer:
-      freeVerifier(verifier1)
 
     let verifier2 = newVerifier()
-    defer:
-      freeVerifier(verifier2)
 
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let share = getShare(encoded, index = 0)
-    defer:
-      freeShare(share)
 
     let isValid1 = verify(verifier1, share, rowsDomainSize = 4)
     let isValid2 = verify(verifier2, share, rowsDomainSize = 4)
@@ -191,31 +173,19 @@ suite "nomos-da Verifier API Tests":
 
   test "verify shares from different encoded data":
     let encoder = newEncoder(columnCount = 4)
-    defer:
-      freeEncoder(encoder)
 
     let data1 = createTestData(CHUNK_SIZE)
     let data2 = createTestData(CHUNK_SIZE * 2)
 
     let encoded1 = encode(encoder, data1)
-    defer:
-      freeEncodedData(encoded1)
 
     let encoded2 = encode(encoder, data2)
-    defer:
-      freeEncodedData(encoded2)
 
     let share1 = getShare(encoded1, index = 0)
-    defer:
-      freeShare(share1)
 
     let share2 = getShare(encoded2, index = 0)
-    defer:
-      freeShare(share2)
 
     let verifier = newVerifier()
-    defer:
-      freeVerifier(verifier)
 
     let isValid1 = verify(verifier, share1, rowsDomainSize = 4)
     let isValid2 = verify(verifier, share2, rowsDomainSize = 4)
@@ -228,23 +198,182 @@ suite "nomos-da Verifier API Tests":
   test "verify with large data":
     let columnCount = 8
     let encoder = newEncoder(columnCount = columnCount)
-    defer:
-      freeEncoder(encoder)
 
     let data = createTestData(CHUNK_SIZE * 10)
     let encoded = encode(encoder, data)
-    defer:
-      freeEncodedData(encoded)
 
     let verifier = newVerifier()
-    defer:
-      freeVerifier(verifier)
 
     let shareCount = getShareCount(encoded)
     for i in 0 ..< shareCount:
       let share = getShare(encoded, index = i)
-      defer:
-        freeShare(share)
 
       let isValid = verify(verifier, share, rowsDomainSize = columnCount)
       check isValid
+
+  test "verify light share against separately held commitments":
+    let columnCount = 4
+    let encoder = newEncoder(columnCount = columnCount)
+
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+
+    let verifier = newVerifier()
+
+    for i in 0 ..< getShareCount(encoded):
+      let share = getShare(encoded, index = i)
+
+      # Ship both halves as bytes, as a sampling peer would receive them
+      let lightShareBytes = lightShareToBytes(toLightShare(share))
+      let commitmentsBytes = commitmentsToBytes(getCommitments(share))
+
+      let lightShare = bytesToLightShare(lightShareBytes)
+
+      let commitments = bytesToCommitments(commitmentsBytes)
+
+      check getShareIndex(lightShare) == i
+      check verify(verifier, lightShare, commitments, rowsDomainSize = columnCount)
+
+  test "verify light share fails with commitments of another blob":
+    let columnCount = 4
+    let encoder = newEncoder(columnCount = columnCount)
+
+    let encoded1 = encode(encoder, createTestData(CHUNK_SIZE * 2))
+
+    var otherData = createTestData(CHUNK_SIZE * 2)
+    otherData[0] = otherData[0] xor 0xff
+    let encoded2 = encode(encoder, otherData)
+
+    let share1 = getShare(encoded1, index = 0)
+
+    let share2 = getShare(encoded2, index = 0)
+
+    let lightShare = toLightShare(share1)
+
+    let foreignCommitments = getCommitments(share2)
+
+    let verifier = newVerifier()
+
+    check not verify(verifier, lightShare, foreignCommitments, rowsDomainSize = columnCount)
+    check verifyWithReason(
+      verifier, lightShare, foreignCommitments, rowsDomainSize = columnCount
+    ) == ErrorProofInvalid
+
+  test "verifyWithReason names the rejected property":
+    let columnCount = 4
+    let encoder = newEncoder(columnCount = columnCount)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+    let share = getShare(encoded, index = 3)
+    let verifier = newVerifier()
+
+    check verifyWithReason(verifier, share, rowsDomainSize = columnCount) == Success
+    check verifyWithReason(verifier, share, rowsDomainSize = 3) ==
+      ErrorDomainSizeMismatch
+    check verifyWithReason(verifier, share, rowsDomainSize = 2) == ErrorIndexOutOfRange
+
+    let largerEncoded = encode(encoder, createTestData(CHUNK_SIZE * 16))
+    let largerCommitments = getCommitments(getShare(largerEncoded, index = 0))
+    check verifyWithReason(
+      verifier, toLightShare(share), largerCommitments, rowsDomainSize = columnCount
+    ) == ErrorRowCommitmentCount
+
+  test "verify light share fails with null handles":
+    let verifier = newVerifier()
+
+    expect ValueError:
+      discard verify(
+        verifier, LightShareHandle(), CommitmentsHandle(), rowsDomainSize = 4
+      )
+
+  test "bytesToLightShare fails with malformed data":
+    expect ValueError:
+      discard bytesToLightShare(@[byte(1), 2, 3])
+
+  test "verifyAll checks every share":
+    let columnCount = 8
+    let encoder = newEncoder(columnCount = columnCount)
+
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 8))
+
+    var shares: seq[ShareHandle]
+    for i in 0 ..< getShareCount(encoded):
+      shares.add(getShare(encoded, index = i))
+
+    let verifier = newVerifier()
+
+    let results = verifyAll(verifier, shares, rowsDomainSize = columnCount)
+    check results.len == shares.len
+    for isValid in results:
+      check isValid
+
+  test "verifyAll reports invalid entries individually":
+    let columnCount = 4
+    let encoder = newEncoder(columnCount = columnCount)
+
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 2))
+
+    let share0 = getShare(encoded, index = 0)
+
+    let share1 = getShare(encoded, index = 1)
+
+    let verifier = newVerifier()
+
+    let results =
+      verifyAll(verifier, @[share0, ShareHandle(), share1], rowsDomainSize = columnCount)
+    check results == @[true, false, true]
+
+  test "verifyAllWithReason reports why each share was rejected":
+    let columnCount = 8
+    let encoder = newEncoder(columnCount = columnCount)
+
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 8))
+
+    let share0 = getShare(encoded, index = 0)
+
+    let share7 = getShare(encoded, index = 7)
+
+    let verifier = newVerifier()
+
+    check verifyAllWithReason(
+      verifier, @[share0, ShareHandle(), share7], rowsDomainSize = columnCount
+    ) == @[Success, ErrorNullHandle, Success]
+    check verifyAllWithReason(verifier, @[share0, share7], rowsDomainSize = 6) ==
+      @[ErrorDomainSizeMismatch, ErrorDomainSizeMismatch]
+    check verifyAllWithReason(verifier, @[share7], rowsDomainSize = 4) ==
+      @[ErrorIndexOutOfRange]
+
+  test "verifyAll with no shares returns empty":
+    let verifier = newVerifier()
+
+    let shares: seq[ShareHandle] = @[]
+    check verifyAll(verifier, shares, rowsDomainSize = 4).len == 0
+
+  test "verifyAll fails with null verifier":
+    let shares: seq[ShareHandle] = @[]
+    expect ValueError:
+      discard verifyAll(VerifierHandle(), shares, rowsDomainSize = 4)
+
+  test "verifier built from exported key verifies shares":
+    let columnCount = 4
+    let encoder = newEncoder(columnCount = columnCount)
+    let encoded = encode(encoder, createTestData(CHUNK_SIZE * 4))
+
+    let key = exportVerificationKey(newVerifier())
+    let reloaded = newVerifier(verificationKey = key)
+    check exportVerificationKey(reloaded) == key
+
+    let path = getTempDir() / "nomos-da-test-key.bin"
+    writeFile(path, cast[string](key))
+    defer:
+      removeFile(path)
+    let fromFile = newVerifierFromKeyFile(path)
+
+    for i in 0 ..< getShareCount(encoded):
+      let share = getShare(encoded, index = i)
+      check verify(reloaded, share, rowsDomainSize = columnCount)
+      check verify(fromFile, share, rowsDomainSize = columnCount)
+
+  test "verifier creation fails with invalid key":
+    expect DeserializationError:
+      discard newVerifier(verificationKey = @[byte(1), 2, 3])
+    expect NomosIoError:
+      discard newVerifierFromKeyFile("/nonexistent/key.bin")